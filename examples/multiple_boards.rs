@@ -0,0 +1,57 @@
+// 多棋盘示例 - 主对局旁边放一个分析用的小棋盘
+// Multiple boards example - a main game next to a smaller analysis board
+
+use bevy::prelude::*;
+use black_white_legends::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins.set(WindowPlugin {
+            primary_window: Some(Window {
+                title: "Multiple Go Boards Example".into(),
+                resolution: (1600.0, 900.0).into(),
+                ..default()
+            }),
+            ..default()
+        }))
+        .insert_resource(ClearColor(Color::srgb(0.4, 0.4, 0.4)))
+        .add_plugins(GoBoardPlugin {
+            initial_config: GoBoardConfig {
+                board_position: Vec2::new(-330.0, 0.0),
+                board_extent: 800.0,
+                ..default()
+            },
+            ..default()
+        })
+        .add_systems(Startup, setup)
+        .add_systems(Update, handle_mouse_click)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    // 分析棋盘：9 路，不显示坐标
+    commands.spawn(GoBoardBundle::new(GoBoardConfig {
+        board_size: BoardSize::Nine,
+        show_coordinates: false,
+        board_position: Vec2::new(450.0, 0.0),
+        board_extent: 500.0,
+        ..default()
+    }));
+}
+
+/// 插件按命中的棋盘换算交叉点，多个棋盘、任意位置都不需要自己计算
+fn handle_mouse_click(
+    mut intersection_clicks: EventReader<IntersectionClickEvent>,
+    mut select_events: EventWriter<SelectPointEvent>,
+) {
+    for click in intersection_clicks.read() {
+        if click.button == PointerButton::Primary {
+            select_events.write(SelectPointEvent {
+                board: click.board,
+                position: click.position,
+            });
+        }
+    }
+}
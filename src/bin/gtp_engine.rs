@@ -0,0 +1,50 @@
+// GTP 引擎 - 通过标准输入输出以 Go Text Protocol 对弈
+// GTP engine - speaks the Go Text Protocol over stdin/stdout
+//
+// 用法: gtp_engine [--seed <u64>] [--bot random|capture|atari|shape] [--superko]
+
+use black_white_legends::go_core::{
+    BotLevel, GtpEngine, HeuristicBot, MoveGenerator, RandomMoveGenerator,
+};
+use std::io;
+
+const USAGE: &str =
+    "usage: gtp_engine [--seed <u64>] [--bot random|capture|atari|shape] [--superko]";
+
+fn main() -> io::Result<()> {
+    let mut args = std::env::args().skip(1);
+    let mut seed = None;
+    let mut level = None;
+    let mut superko = false;
+    while let Some(arg) = args.next() {
+        let valid = match arg.as_str() {
+            "--seed" => {
+                seed = args.next().and_then(|value| value.parse::<u64>().ok());
+                seed.is_some()
+            }
+            "--bot" => {
+                level = args.next().as_deref().and_then(BotLevel::from_name);
+                level.is_some()
+            }
+            "--superko" => {
+                superko = true;
+                true
+            }
+            _ => false,
+        };
+        if !valid {
+            eprintln!("{}", USAGE);
+            std::process::exit(2);
+        }
+    }
+
+    // 入门棋手需要固定种子，未指定时用 0
+    let generator: Box<dyn MoveGenerator> = match (level, seed) {
+        (Some(level), seed) => Box::new(HeuristicBot::new(level, seed.unwrap_or(0))),
+        (None, Some(seed)) => Box::new(RandomMoveGenerator::new(seed)),
+        (None, None) => Box::new(RandomMoveGenerator::from_entropy()),
+    };
+
+    let mut engine = GtpEngine::new(generator).with_superko_rule(superko);
+    engine.run(io::stdin().lock(), io::stdout().lock())
+}
@@ -0,0 +1,171 @@
+// 自对弈 - 电脑棋手之间批量对局，导出 SGF 棋谱和训练样本
+// Self-play - batch bot-vs-bot games exporting SGF records and training samples
+//
+// 用法: self_play [--games <n>] [--size 9|13|19] [--komi <f32>] [--max-moves <n>]
+//                 [--black <bot>] [--white <bot>] [--playouts <n>] [--seed <u64>]
+//                 [--threads <n>] [--out <dir>]
+// 棋手: random|capture|atari|shape|mcts
+
+use black_white_legends::go_core::{
+    BoardSize, BotLevel, HeuristicBot, MctsBot, MctsConfig, MoveGenerator, SearchBudget,
+    SelfPlayConfig, SelfPlayGame, StoneColor, TrainingSamples,
+};
+use std::fs::{self, File};
+use std::io::{self, BufWriter};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::Instant;
+
+const USAGE: &str = "usage: self_play [--games <n>] [--size 9|13|19] [--komi <f32>] \
+[--max-moves <n>] [--black <bot>] [--white <bot>] [--playouts <n>] [--seed <u64>] \
+[--threads <n>] [--out <dir>]\nbots: random|capture|atari|shape|mcts";
+
+/// 命令行选择的棋手
+#[derive(Clone, Copy)]
+enum BotKind {
+    Heuristic(BotLevel),
+    Mcts,
+}
+
+impl BotKind {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "mcts" => Some(BotKind::Mcts),
+            _ => BotLevel::from_name(name).map(BotKind::Heuristic),
+        }
+    }
+
+    fn create(self, seed: u64, playouts: u32) -> Box<dyn MoveGenerator> {
+        match self {
+            BotKind::Heuristic(level) => Box::new(HeuristicBot::new(level, seed)),
+            BotKind::Mcts => Box::new(MctsBot::new(MctsConfig {
+                budget: SearchBudget::Playouts(playouts),
+                seed: Some(seed),
+                ..MctsConfig::default()
+            })),
+        }
+    }
+}
+
+struct Options {
+    games: usize,
+    config: SelfPlayConfig,
+    black: BotKind,
+    white: BotKind,
+    playouts: u32,
+    seed: u64,
+    threads: usize,
+    out: PathBuf,
+}
+
+impl Options {
+    fn from_args() -> Option<Self> {
+        let mut options = Options {
+            games: 10,
+            config: SelfPlayConfig::default(),
+            black: BotKind::Heuristic(BotLevel::Shape),
+            white: BotKind::Heuristic(BotLevel::Shape),
+            playouts: 200,
+            seed: 0,
+            threads: thread::available_parallelism().map_or(1, |n| n.get()),
+            out: PathBuf::from("selfplay"),
+        };
+        let mut max_moves = None;
+
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            let value = args.next()?;
+            match arg.as_str() {
+                "--games" => options.games = value.parse().ok()?,
+                "--size" => options.config.board_size = BoardSize::from_value(value.parse().ok()?)?,
+                "--komi" => options.config.komi = value.parse().ok()?,
+                "--max-moves" => max_moves = Some(value.parse().ok()?),
+                "--black" => options.black = BotKind::from_name(&value)?,
+                "--white" => options.white = BotKind::from_name(&value)?,
+                "--playouts" => options.playouts = value.parse().ok()?,
+                "--seed" => options.seed = value.parse().ok()?,
+                "--threads" => options.threads = value.parse::<usize>().ok()?.max(1),
+                "--out" => options.out = PathBuf::from(value),
+                _ => return None,
+            }
+        }
+
+        let size = options.config.board_size.get_value() as usize;
+        options.config.max_moves = max_moves.unwrap_or(size * size * 3);
+        Some(options)
+    }
+
+    /// 第 `index` 局双方的种子，固定种子时结果与线程数无关
+    fn game_seeds(&self, index: usize) -> (u64, u64) {
+        let base = self.seed.wrapping_add(index as u64 * 2);
+        (base, base.wrapping_add(1))
+    }
+}
+
+fn main() -> io::Result<()> {
+    let Some(options) = Options::from_args() else {
+        eprintln!("{}", USAGE);
+        std::process::exit(2);
+    };
+    fs::create_dir_all(&options.out)?;
+
+    let started = Instant::now();
+    let next_game = AtomicUsize::new(0);
+    let finished = Mutex::new(Vec::with_capacity(options.games));
+
+    thread::scope(|scope| {
+        for _ in 0..options.threads.min(options.games) {
+            scope.spawn(|| {
+                loop {
+                    let index = next_game.fetch_add(1, Ordering::Relaxed);
+                    if index >= options.games {
+                        break;
+                    }
+                    let (black_seed, white_seed) = options.game_seeds(index);
+                    let mut black = options.black.create(black_seed, options.playouts);
+                    let mut white = options.white.create(white_seed, options.playouts);
+                    let game = SelfPlayGame::play(&mut *black, &mut *white, &options.config);
+                    if let Ok(mut finished) = finished.lock() {
+                        finished.push((index, game));
+                    }
+                }
+            });
+        }
+    });
+
+    // 按对局编号输出，保证同一种子的输出相同
+    let mut games = finished
+        .into_inner()
+        .map_err(|_| io::Error::new(io::ErrorKind::Other, "self-play thread panicked"))?;
+    games.sort_by_key(|(index, _)| *index);
+
+    let mut samples = TrainingSamples::new(options.config.board_size);
+    let mut black_wins = 0;
+    let mut white_wins = 0;
+    for (index, game) in &games {
+        let path = options.out.join(format!("game_{:05}.sgf", index + 1));
+        fs::write(path, game.to_sgf_record().to_sgf())?;
+        game.export_samples(&mut samples);
+        match game.result.winner {
+            Some(StoneColor::Black) => black_wins += 1,
+            Some(StoneColor::White) => white_wins += 1,
+            None => {}
+        }
+    }
+    let npz = BufWriter::new(File::create(options.out.join("samples.npz"))?);
+    samples.write_npz(npz)?;
+
+    println!(
+        "{} games in {:.1}s: black {} / white {} / draw {}, {} samples -> {}",
+        games.len(),
+        started.elapsed().as_secs_f32(),
+        black_wins,
+        white_wins,
+        games.len() - black_wins - white_wins,
+        samples.len(),
+        options.out.display()
+    );
+    Ok(())
+}
@@ -0,0 +1,131 @@
+// 比赛 - 电脑棋手和外部 GTP 引擎之间的循环赛或挑战赛，输出 SGF 和交叉表
+// Tournament - round-robin or gauntlet between bots and external GTP engines, writing SGF and a crosstable
+//
+// 用法: tournament --entrant <name>=<spec> --entrant <name>=<spec> ...
+//                  [--format round-robin|gauntlet] [--games <n>] [--size 9|13|19] [--komi <f32>]
+//                  [--move-ms <ms>] [--max-moves <n>] [--concurrent <n>] [--out <dir>]
+// 参赛者: random|capture|atari|shape[:<seed>]、mcts[:<playouts>]、gtp:<命令行>
+
+use bevy::log::LogPlugin;
+use bevy::prelude::*;
+use black_white_legends::go_board_component::{Entrant, TournamentConfig};
+use black_white_legends::go_core::{BotLevel, MctsConfig, SearchBudget, TournamentFormat};
+use black_white_legends::prelude::*;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+const USAGE: &str = "usage: tournament --entrant <name>=<spec> --entrant <name>=<spec> ... \
+[--format round-robin|gauntlet] [--games <n>] [--size 9|13|19] [--komi <f32>] \
+[--move-ms <ms>] [--max-moves <n>] [--concurrent <n>] [--out <dir>]\n\
+specs: random|capture|atari|shape[:<seed>], mcts[:<playouts>], gtp:<command line>";
+
+/// 解析参赛者，如 `shape:3`、`mcts:2000`、`gtp:gnugo --mode gtp`
+fn parse_entrant(text: &str) -> Option<Entrant> {
+    let (name, spec) = text.split_once('=')?;
+    let (kind, argument) = match spec.split_once(':') {
+        Some((kind, argument)) => (kind, Some(argument)),
+        None => (spec, None),
+    };
+
+    let backend = match kind {
+        "gtp" => PlayerBackend::Gtp(EngineCommand::parse(argument?)?),
+        "mcts" => {
+            let mut config = MctsConfig::default();
+            if let Some(playouts) = argument {
+                config.budget = SearchBudget::Playouts(playouts.parse().ok()?);
+            }
+            PlayerBackend::Mcts(config)
+        }
+        _ => PlayerBackend::Heuristic {
+            level: BotLevel::from_name(kind)?,
+            seed: argument.map_or(Some(0), |seed| seed.parse().ok())?,
+        },
+    };
+    Some(Entrant {
+        name: name.to_string(),
+        backend,
+    })
+}
+
+fn parse_args() -> Option<(Vec<Entrant>, TournamentConfig, PathBuf)> {
+    let mut entrants = Vec::new();
+    let mut config = TournamentConfig::default();
+    let mut max_moves = None;
+    let mut out = PathBuf::from("tournament");
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let value = args.next()?;
+        match arg.as_str() {
+            "--entrant" => entrants.push(parse_entrant(&value)?),
+            "--format" => config.format = TournamentFormat::from_name(&value)?,
+            "--games" => config.games_per_pairing = value.parse().ok()?,
+            "--size" => config.board_size = BoardSize::from_value(value.parse().ok()?)?,
+            "--komi" => config.komi = value.parse().ok()?,
+            "--move-ms" => config.move_time = Some(Duration::from_millis(value.parse().ok()?)),
+            "--max-moves" => max_moves = Some(value.parse().ok()?),
+            "--concurrent" => config.concurrent_games = value.parse().ok()?,
+            "--out" => out = PathBuf::from(value),
+            _ => return None,
+        }
+    }
+
+    let size = config.board_size.get_value() as usize;
+    config.max_moves = max_moves.unwrap_or(size * size * 3);
+    (entrants.len() >= 2).then_some((entrants, config, out))
+}
+
+fn main() -> io::Result<()> {
+    let Some((entrants, config, out)) = parse_args() else {
+        eprintln!("{}", USAGE);
+        std::process::exit(2);
+    };
+    fs::create_dir_all(&out)?;
+
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        LogPlugin::default(),
+        GoBoardLogicPlugin,
+        ComputerPlayerPlugin,
+        TournamentPlugin,
+    ))
+    .insert_resource(Tournament::new(entrants, config));
+
+    let total = app.world().resource::<Tournament>().schedule.len();
+    let mut reported = 0;
+    while !app.world().resource::<Tournament>().is_finished() {
+        app.update();
+        std::thread::sleep(Duration::from_millis(1));
+
+        let tournament = app.world().resource::<Tournament>();
+        for game in &tournament.finished[reported..] {
+            println!(
+                "game {}/{}: {} (B) vs {} (W) {}",
+                game.index + 1,
+                total,
+                game.record.black_player,
+                game.record.white_player,
+                game.record.result.as_deref().unwrap_or("?")
+            );
+        }
+        reported = tournament.finished.len();
+    }
+
+    let tournament = app.world().resource::<Tournament>();
+    for game in &tournament.finished {
+        let path = out.join(format!(
+            "game_{:04}_{}_vs_{}.sgf",
+            game.index + 1,
+            game.record.black_player,
+            game.record.white_player
+        ));
+        fs::write(path, game.record.to_sgf())?;
+    }
+    let table = tournament.crosstable.to_table();
+    fs::write(out.join("crosstable.txt"), &table)?;
+    println!("\n{}", table);
+    Ok(())
+}
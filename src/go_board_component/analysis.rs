@@ -0,0 +1,363 @@
+use super::{
+    assets::BoardAssets,
+    components::*,
+    config::GoBoardConfig,
+    events::RedrawBoardEvent,
+    players::EngineCommand,
+    resources::{CurrentGoBoardConfig, GameHistory},
+    utils::{CoordinateUtils, RenderUtils},
+};
+use crate::go_core::{AnalysisQuery, AnalysisResponse, BoardSize};
+use bevy::prelude::*;
+use std::collections::HashSet;
+use std::io::{self, BufRead, BufReader, Write};
+use std::process::{Command, Stdio};
+use std::sync::{Mutex, mpsc};
+use std::thread;
+
+/// 最多显示的候选着法数
+const MAX_CANDIDATE_MOVES: usize = 8;
+
+/// 归属绝对值低于此值的点不画热力图
+const OWNERSHIP_THRESHOLD: f32 = 0.15;
+
+/// 当前局面的分析结果，挂在棋盘根节点上
+///
+/// 局面变化时移除，新结果到达后重新插入
+#[derive(Component)]
+pub struct BoardAnalysis(pub AnalysisResponse);
+
+/// 已发送给引擎的局面
+#[derive(PartialEq)]
+struct AnalyzedPosition {
+    board_size: BoardSize,
+    komi: f32,
+    moves: Vec<(StoneColor, Option<(i32, i32)>)>,
+}
+
+/// KataGo JSON 分析引擎
+///
+/// 单独生成一个实体，通过 `board` 指向要分析的棋盘根节点；
+/// 局面变化时发送新查询并终止旧查询，结果以 [`BoardAnalysis`] 挂到棋盘上
+///
+/// # 使用示例
+/// ```rust,ignore
+/// let command = EngineCommand::new("katago")
+///     .arg("analysis")
+///     .arg("-config")
+///     .arg("analysis.cfg")
+///     .arg("-model")
+///     .arg("model.bin.gz");
+/// commands.spawn(AnalysisEngine::spawn(board, &command)?);
+/// ```
+#[derive(Component)]
+pub struct AnalysisEngine {
+    pub board: Entity,
+    /// 每次查询的最大访问数，`None` 使用引擎配置
+    pub max_visits: Option<u32>,
+    queries: mpsc::Sender<String>,
+    responses: Mutex<mpsc::Receiver<Result<AnalysisResponse, String>>>,
+    next_id: u64,
+    /// 最近一次查询的编号和局面
+    current: Option<(String, AnalyzedPosition)>,
+    /// 引擎出错后不再查询
+    failed: bool,
+}
+
+impl AnalysisEngine {
+    /// 启动引擎进程，查询写入和结果读取各用一个后台线程
+    pub fn spawn(board: Entity, command: &EngineCommand) -> io::Result<Self> {
+        let mut child = Command::new(&command.program)
+            .args(&command.args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()?;
+        let mut stdin = child.stdin.take().expect("stdin is piped");
+        let stdout = BufReader::new(child.stdout.take().expect("stdout is piped"));
+
+        let (query_sender, query_receiver) = mpsc::channel::<String>();
+        let (response_sender, response_receiver) = mpsc::channel();
+
+        // 组件移除后通道关闭，关闭标准输入让引擎退出
+        thread::Builder::new()
+            .name(format!("analysis-writer-{}", command.program))
+            .spawn(move || {
+                for line in query_receiver {
+                    if writeln!(stdin, "{}", line)
+                        .and_then(|_| stdin.flush())
+                        .is_err()
+                    {
+                        break;
+                    }
+                }
+            })?;
+
+        thread::Builder::new()
+            .name(format!("analysis-reader-{}", command.program))
+            .spawn(move || {
+                for line in stdout.lines() {
+                    let reply = match line {
+                        Ok(line) => match AnalysisResponse::parse_line(&line) {
+                            Ok(Some(response)) => Ok(response),
+                            Ok(None) => continue,
+                            Err(error) => Err(error.to_string()),
+                        },
+                        Err(error) => Err(error.to_string()),
+                    };
+                    if response_sender.send(reply).is_err() {
+                        break;
+                    }
+                }
+                let _ = child.wait();
+            })?;
+
+        Ok(Self {
+            board,
+            max_visits: None,
+            queries: query_sender,
+            responses: Mutex::new(response_receiver),
+            next_id: 0,
+            current: None,
+            failed: false,
+        })
+    }
+
+    /// 设置每次查询的最大访问数
+    pub fn with_max_visits(mut self, max_visits: u32) -> Self {
+        self.max_visits = Some(max_visits);
+        self
+    }
+}
+
+/// 驱动分析引擎：局面变化时发送查询，把最新查询的结果挂到棋盘上
+pub fn drive_analysis_engines(
+    mut commands: Commands,
+    mut engines: Query<&mut AnalysisEngine>,
+    boards: Query<(&CurrentGoBoardConfig, &GameHistory), With<GoBoardRoot>>,
+) {
+    for mut engine in engines.iter_mut() {
+        let board = engine.board;
+        let Ok((config, history)) = boards.get(board) else {
+            continue;
+        };
+        if engine.failed {
+            continue;
+        }
+
+        // 收取结果，只保留最新查询的
+        let mut latest = None;
+        let mut failure = None;
+        if let Ok(responses) = engine.responses.get_mut() {
+            loop {
+                match responses.try_recv() {
+                    Ok(Ok(response)) => latest = Some(response),
+                    Ok(Err(message)) => failure = Some(message),
+                    Err(mpsc::TryRecvError::Empty) => break,
+                    Err(mpsc::TryRecvError::Disconnected) => {
+                        failure = Some("analysis engine stopped".to_string());
+                        break;
+                    }
+                }
+            }
+        }
+        if let Some(message) = failure {
+            error!("Analysis engine stopped: {}", message);
+            engine.failed = true;
+            continue;
+        }
+        if let Some(response) = latest
+            && engine
+                .current
+                .as_ref()
+                .is_some_and(|(id, _)| *id == response.id)
+        {
+            commands.entity(board).insert(BoardAnalysis(response));
+        }
+
+        // 局面变化时发送新查询
+        let position = AnalyzedPosition {
+            board_size: config.0.board_size,
+            komi: config.0.komi,
+            moves: history
+                .played_moves()
+                .iter()
+                .map(|played| (played.color, played.position))
+                .collect(),
+        };
+        if engine
+            .current
+            .as_ref()
+            .is_some_and(|(_, analyzed)| *analyzed == position)
+        {
+            continue;
+        }
+
+        engine.next_id += 1;
+        let id = engine.next_id.to_string();
+        let mut query = AnalysisQuery::new(
+            id.clone(),
+            position.board_size,
+            position.komi,
+            history.played_moves(),
+        );
+        query.max_visits = engine.max_visits;
+
+        let mut lines = Vec::new();
+        if let Some((previous_id, _)) = &engine.current {
+            lines.push(AnalysisQuery::terminate_line(
+                &format!("{}-terminate", previous_id),
+                previous_id,
+            ));
+        }
+        lines.push(query.to_line());
+        if lines
+            .into_iter()
+            .any(|line| engine.queries.send(line).is_err())
+        {
+            error!("Analysis engine stopped: query channel closed");
+            engine.failed = true;
+            continue;
+        }
+
+        engine.current = Some((id, position));
+        commands.entity(board).remove::<BoardAnalysis>();
+    }
+}
+
+/// 分析叠加层实体
+type AnalysisOverlay = Or<(With<CandidateMoveMarker>, With<OwnershipMarker>)>;
+
+/// 同步分析叠加层（候选着法和归属热力图）
+///
+/// 整体重绘时叠加层已随棋盘子实体一起清除，只需重新生成
+pub fn sync_analysis_overlay(
+    mut commands: Commands,
+    mut assets: BoardAssets,
+    mut redraw_events: EventReader<RedrawBoardEvent>,
+    mut removed_analysis: RemovedComponents<BoardAnalysis>,
+    boards: Query<(Entity, Ref<CurrentGoBoardConfig>), With<GoBoardRoot>>,
+    analyses: Query<Ref<BoardAnalysis>>,
+    overlays: Query<(Entity, &ChildOf), AnalysisOverlay>,
+) {
+    let redraw_targets: HashSet<Entity> = redraw_events.read().map(|event| event.board).collect();
+    let cleared: HashSet<Entity> = removed_analysis.read().collect();
+
+    for (root, config) in boards.iter() {
+        let analysis = analyses.get(root).ok();
+        let redrawn = config.is_changed() || redraw_targets.contains(&root);
+        let analysis_changed = analysis
+            .as_ref()
+            .is_some_and(|analysis| analysis.is_changed());
+        if !redrawn && !analysis_changed && !cleared.contains(&root) {
+            continue;
+        }
+
+        if !redrawn {
+            for (entity, parent) in overlays.iter() {
+                if parent.parent() == root {
+                    commands.entity(entity).despawn();
+                }
+            }
+        }
+
+        if let Some(analysis) = analysis {
+            draw_analysis(&mut commands, &mut assets, root, &config.0, &analysis.0);
+        }
+    }
+}
+
+/// 绘制归属热力图和候选着法
+fn draw_analysis(
+    commands: &mut Commands,
+    assets: &mut BoardAssets,
+    root: Entity,
+    config: &GoBoardConfig,
+    analysis: &AnalysisResponse,
+) {
+    let metrics = RenderUtils::calculate_board_metrics(config.board_extent, config.board_size);
+    let size = config.board_size.get_value();
+
+    // 归属热力图：盖在棋子上，死子会显示为对方颜色
+    if config.show_ownership && !analysis.ownership.is_empty() {
+        let square = assets.rectangle(metrics.cell_size * 0.45, metrics.cell_size * 0.45);
+        for x in 0..size {
+            for y in 0..size {
+                let Some(ownership) = analysis.ownership_at(x, y, config.board_size) else {
+                    continue;
+                };
+                if ownership.abs() < OWNERSHIP_THRESHOLD {
+                    continue;
+                }
+
+                let alpha = (ownership.abs() * 0.75).min(0.75);
+                // 量化透明度，避免每个点都生成一个新材质
+                let alpha = (alpha * 10.0).round() / 10.0;
+                let color = if ownership > 0.0 {
+                    Color::srgba(0.0, 0.0, 0.0, alpha)
+                } else {
+                    Color::srgba(1.0, 1.0, 1.0, alpha)
+                };
+
+                let local_pos =
+                    CoordinateUtils::board_to_local((x, y), config.board_size, config.board_extent);
+                commands.spawn((
+                    Mesh2d(square.clone()),
+                    MeshMaterial2d(assets.material(color)),
+                    Transform::from_translation(Vec3::new(local_pos.x, local_pos.y, 4.2)),
+                    OwnershipMarker,
+                    ChildOf(root),
+                ));
+            }
+        }
+    }
+
+    // 候选着法：胜率和目差按轮到的一方显示
+    if config.show_candidate_moves {
+        let flip = analysis.current_player() == StoneColor::White;
+        let mut candidates: Vec<_> = analysis.move_infos.iter().collect();
+        candidates.sort_by_key(|info| info.order);
+
+        let radius = metrics.cell_size * 0.45;
+        let font_size = (metrics.cell_size * 0.24).clamp(8.0, 24.0);
+        for (rank, info) in candidates.into_iter().take(MAX_CANDIDATE_MOVES).enumerate() {
+            let Some(position) = info.position(config.board_size) else {
+                continue;
+            };
+
+            let (winrate, score_lead) = if flip {
+                (1.0 - info.winrate, -info.score_lead)
+            } else {
+                (info.winrate, info.score_lead)
+            };
+            let fill = if rank == 0 {
+                Color::srgba(0.20, 0.55, 0.95, 0.85)
+            } else {
+                Color::srgba(0.30, 0.75, 0.40, 0.70)
+            };
+
+            let local_pos =
+                CoordinateUtils::board_to_local(position, config.board_size, config.board_extent);
+            let marker = commands
+                .spawn((
+                    Mesh2d(assets.circle(radius)),
+                    MeshMaterial2d(assets.material(fill)),
+                    Transform::from_translation(Vec3::new(local_pos.x, local_pos.y, 5.0)),
+                    CandidateMoveMarker { rank },
+                    ChildOf(root),
+                ))
+                .id();
+
+            commands.spawn((
+                Text2d::new(format!("{:.1}\n{:+.1}", winrate * 100.0, score_lead)),
+                TextFont {
+                    font_size,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.05, 0.05, 0.05)),
+                Transform::from_translation(Vec3::new(0.0, 0.0, 0.1)),
+                ChildOf(marker),
+            ));
+        }
+    }
+}
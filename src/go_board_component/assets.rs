@@ -0,0 +1,127 @@
+use super::utils::{BoardMetrics, RenderUtils};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+/// 网格缓存键
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum MeshKey {
+    Circle(u32),
+    Ring(u32, u32),
+    Rectangle(u32, u32),
+    Grid {
+        board_size: i32,
+        cell_size: u32,
+        line_width: u32,
+    },
+}
+
+/// 棋盘共享资源句柄缓存
+///
+/// 网格按形状和尺寸缓存，材质按颜色缓存，重绘时复用同一份资源
+#[derive(Resource, Default)]
+pub struct GoBoardAssets {
+    meshes: HashMap<MeshKey, Handle<Mesh>>,
+    materials: HashMap<[u8; 4], Handle<ColorMaterial>>,
+}
+
+impl GoBoardAssets {
+    /// 已缓存的网格数量
+    pub fn mesh_count(&self) -> usize {
+        self.meshes.len()
+    }
+
+    /// 已缓存的材质数量
+    pub fn material_count(&self) -> usize {
+        self.materials.len()
+    }
+
+    /// 丢弃不再被任何实体使用的网格
+    pub fn retain_meshes(&mut self, in_use: &HashSet<AssetId<Mesh>>) {
+        self.meshes
+            .retain(|_, handle| in_use.contains(&handle.id()));
+    }
+
+    /// 丢弃不再被任何实体使用的材质
+    pub fn retain_materials(&mut self, in_use: &HashSet<AssetId<ColorMaterial>>) {
+        self.materials
+            .retain(|_, handle| in_use.contains(&handle.id()));
+    }
+}
+
+/// 棋盘绘制所需的资源访问参数
+#[derive(SystemParam)]
+pub struct BoardAssets<'w> {
+    pub meshes: ResMut<'w, Assets<Mesh>>,
+    pub materials: ResMut<'w, Assets<ColorMaterial>>,
+    pub cache: ResMut<'w, GoBoardAssets>,
+}
+
+impl BoardAssets<'_> {
+    /// 获取指定半径的圆形网格
+    pub fn circle(&mut self, radius: f32) -> Handle<Mesh> {
+        let meshes = &mut self.meshes;
+        self.cache
+            .meshes
+            .entry(MeshKey::Circle(radius.to_bits()))
+            .or_insert_with(|| meshes.add(Circle::new(radius)))
+            .clone()
+    }
+
+    /// 获取指定内外半径的圆环网格
+    pub fn ring(&mut self, inner_radius: f32, outer_radius: f32) -> Handle<Mesh> {
+        let meshes = &mut self.meshes;
+        self.cache
+            .meshes
+            .entry(MeshKey::Ring(
+                inner_radius.to_bits(),
+                outer_radius.to_bits(),
+            ))
+            .or_insert_with(|| meshes.add(Annulus::new(inner_radius, outer_radius)))
+            .clone()
+    }
+
+    /// 获取指定尺寸的矩形网格
+    pub fn rectangle(&mut self, width: f32, height: f32) -> Handle<Mesh> {
+        let meshes = &mut self.meshes;
+        self.cache
+            .meshes
+            .entry(MeshKey::Rectangle(width.to_bits(), height.to_bits()))
+            .or_insert_with(|| meshes.add(Rectangle::new(width, height)))
+            .clone()
+    }
+
+    /// 获取整张棋盘网格线合并后的网格
+    pub fn grid(
+        &mut self,
+        board_size: i32,
+        metrics: &BoardMetrics,
+        line_width: f32,
+    ) -> Handle<Mesh> {
+        let meshes = &mut self.meshes;
+        let key = MeshKey::Grid {
+            board_size,
+            cell_size: metrics.cell_size.to_bits(),
+            line_width: line_width.to_bits(),
+        };
+        self.cache
+            .meshes
+            .entry(key)
+            .or_insert_with(|| {
+                meshes.add(RenderUtils::build_grid_mesh(
+                    board_size, metrics, line_width,
+                ))
+            })
+            .clone()
+    }
+
+    /// 获取指定颜色的材质
+    pub fn material(&mut self, color: Color) -> Handle<ColorMaterial> {
+        let materials = &mut self.materials;
+        self.cache
+            .materials
+            .entry(color.to_srgba().to_u8_array())
+            .or_insert_with(|| materials.add(color))
+            .clone()
+    }
+}
@@ -1,14 +1,127 @@
+use super::config::GoBoardConfig;
+use super::resources::{BoardState, CurrentGoBoardConfig, CurrentTurn, GameHistory};
 use bevy::prelude::*;
-use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 
-/// 围棋棋盘根实体标记
+pub use crate::go_core::StoneColor;
+
+/// 棋盘背景
 #[derive(Component)]
 pub struct GoBoard;
 
 /// 棋盘根节点
+///
+/// 棋盘背景、网格线、坐标和棋子都是它的子实体，移动或缩放根节点即可摆放整个棋盘
 #[derive(Component)]
 pub struct GoBoardRoot;
 
+/// 一局棋所需的全部组件
+///
+/// 每个棋盘根节点都带有自己的配置、棋盘状态、回合和历史，多个棋盘可以在同一个 App 中共存
+///
+/// # 使用示例
+/// ```rust,ignore
+/// let board = commands.spawn(GoBoardBundle::new(config)).id();
+/// ```
+#[derive(Bundle)]
+pub struct GoBoardBundle {
+    pub root: GoBoardRoot,
+    pub config: CurrentGoBoardConfig,
+    pub board_state: BoardState,
+    pub current_turn: CurrentTurn,
+    pub history: GameHistory,
+    pub transform: Transform,
+    pub visibility: Visibility,
+    pub name: Name,
+}
+
+impl GoBoardBundle {
+    pub fn new(config: GoBoardConfig) -> Self {
+        Self {
+            root: GoBoardRoot,
+            board_state: BoardState::new(config.board_size),
+            current_turn: CurrentTurn(StoneColor::Black),
+            history: GameHistory::default(),
+            transform: Transform::from_translation(config.board_position.extend(0.0)),
+            visibility: Visibility::default(),
+            name: Name::new("Go Board"),
+            config: CurrentGoBoardConfig(config),
+        }
+    }
+}
+
+/// 对局已结束（认输或双方连续虚手），挂在棋盘根节点上
+///
+/// 结束后不再接受落子，撤销、清空棋盘或更换棋盘大小时移除
+#[derive(Component)]
+pub struct GameOver;
+
+/// 数子阶段，挂在棋盘根节点上
+///
+/// 开启 `scoring_phase` 时双方连续虚手后进入，`dead_stones` 先填入建议的死子，
+/// 期间不接受落子，确认计分后与 [`GameOver`] 一起保留，撤销或清空棋盘时移除
+#[derive(Component)]
+pub struct ScoringPhase {
+    pub dead_stones: HashSet<(i32, i32)>,
+}
+
+/// 待确认的棋子，开启 `confirm_moves` 时挂在棋盘根节点上
+///
+/// 再点同一点时落子；棋盘变化、轮换或对局结束时移除
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProvisionalStone {
+    pub position: (i32, i32),
+    pub color: StoneColor,
+}
+
+/// 键盘光标，开启 `keyboard_cursor` 时挂在棋盘根节点上
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct BoardCursor {
+    pub position: (i32, i32),
+    /// 正在输入的坐标，不在输入时为 `None`
+    pub typed: Option<String>,
+}
+
+/// 触控缩放和平移后的视图，开启 `touch_controls` 时挂在棋盘根节点上
+///
+/// 视图直接写入根节点的 `Transform`，命中检测用的 `GlobalTransform` 随之变化，任何缩放下都能换算到正确的交叉点。
+/// 其他系统改动 `Transform` 时以新的变换作为 `home` 并恢复未缩放的视图
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct BoardView {
+    /// 未缩放时的变换，双击恢复到这里
+    pub home: Transform,
+    /// 相对于 `home` 的放大倍数，不小于 1
+    pub zoom: f32,
+    /// 相对于 `home` 的平移（世界坐标）
+    pub pan: Vec2,
+}
+
+impl BoardView {
+    pub fn new(home: Transform) -> Self {
+        Self {
+            home,
+            zoom: 1.0,
+            pan: Vec2::ZERO,
+        }
+    }
+
+    /// 当前视图对应的根节点变换
+    pub fn transform(&self) -> Transform {
+        Transform {
+            translation: self.home.translation + self.pan.extend(0.0),
+            rotation: self.home.rotation,
+            scale: self.home.scale * Vec3::new(self.zoom, self.zoom, 1.0),
+        }
+    }
+}
+
+/// 手指按在棋盘上时预览的落点，松开后在这里落子
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TouchPreview {
+    pub position: (i32, i32),
+    pub color: StoneColor,
+}
+
 /// 棋盘线条
 #[derive(Component)]
 pub struct BoardLine;
@@ -21,30 +134,11 @@ pub struct StarPoint;
 #[derive(Component)]
 pub struct CoordinateLabel;
 
-/// 棋子颜色
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-pub enum StoneColor {
-    Black,
-    White,
-}
-
-impl StoneColor {
-    pub fn opposite(&self) -> Self {
-        match self {
-            StoneColor::Black => StoneColor::White,
-            StoneColor::White => StoneColor::Black,
-        }
-    }
-}
-
 /// 棋子组件
 #[derive(Component)]
 pub struct Stone {
-    #[allow(dead_code)]
     pub color: StoneColor,
-    #[allow(dead_code)]
     pub position: (i32, i32),
-    #[allow(dead_code)]
     pub move_number: usize,
 }
 
@@ -61,9 +155,23 @@ pub struct StoneHighlight;
 pub struct MoveNumberLabel;
 
 /// 悬停指示器
+///
+/// 全局只有一个，不挂在棋盘根节点下，按 [`BoardHover`](super::hover::BoardHover) 移动到光标所在的点
 #[derive(Component)]
 pub struct HoverIndicator;
 
+/// 悬停指示器中的半透明棋子
+#[derive(Component)]
+pub struct HoverGhost;
+
+/// 悬停指示器中不合法点的红叉
+#[derive(Component)]
+pub struct HoverCross;
+
+/// 悬停指示器中的坐标和原因提示
+#[derive(Component)]
+pub struct HoverTooltip;
+
 /// 最后一手标记
 #[derive(Component)]
 pub struct LastMoveMarker;
@@ -72,6 +180,45 @@ pub struct LastMoveMarker;
 #[derive(Component)]
 pub struct DeadStoneMarker;
 
+/// 待确认棋子的半透明棋子和外圈
+#[derive(Component)]
+pub struct ProvisionalMarker;
+
+/// 键盘光标方框和正在输入的坐标
+#[derive(Component)]
+pub struct CursorMarker;
+
+/// 触控预览棋子和偏移的放大镜
+#[derive(Component)]
+pub struct TouchPreviewMarker;
+
+/// 分析候选着法标记，`rank` 为 0 时是引擎的首选
+#[derive(Component)]
+pub struct CandidateMoveMarker {
+    pub rank: usize,
+}
+
+/// 归属热力图方块
+#[derive(Component)]
+pub struct OwnershipMarker;
+
+/// 策略网络落子提示
+#[derive(Component)]
+pub struct HintMarker;
+
+/// 征子路线上的着法和引征标记
+#[derive(Component)]
+pub struct LadderMarker;
+
+/// 气数标签和叫吃警示圈
+#[derive(Component)]
+pub struct LibertyMarker {
+    /// 所在棋串的气数
+    pub liberties: usize,
+    /// 叫吃警示圈为 true，气数圆牌为 false
+    pub atari: bool,
+}
+
 /// 领地标记
 #[derive(Component)]
 pub struct TerritoryMarker {
@@ -1,6 +1,9 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
+pub use crate::go_core::BoardSize;
+use crate::go_core::DEFAULT_KOMI;
+
 /// 围棋棋盘配置
 /// Go board configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -23,7 +26,13 @@ pub struct GoBoardConfig {
     pub star_point_radius_ratio: f32,
     /// 线条宽度比例
     pub line_width_ratio: f32,
-    /// 自适应边距
+    /// 棋盘中心在世界空间中的位置
+    pub board_position: Vec2,
+    /// 棋盘背景边长（棋盘根节点的局部单位）
+    pub board_extent: f32,
+    /// 按主窗口大小缩放棋盘根节点，让棋盘始终完整显示在窗口中
+    pub fit_to_window: bool,
+    /// 自适应边距：缩放到窗口时，大窗口使用较小的边距
     pub adaptive_padding: bool,
     /// 启用悬停提示
     pub enable_hover_indicator: bool,
@@ -33,6 +42,32 @@ pub struct GoBoardConfig {
     pub enable_captures: bool,
     /// 启用打劫规则
     pub enable_ko_rule: bool,
+    /// 启用全局同形禁着（位置超级劫），按历史中的局面哈希判断
+    pub enable_superko_rule: bool,
+    /// 贴目
+    pub komi: f32,
+    /// 显示分析引擎的候选着法
+    pub show_candidate_moves: bool,
+    /// 显示分析引擎的归属热力图
+    pub show_ownership: bool,
+    /// 显示策略网络的落子提示热力图
+    pub show_hints: bool,
+    /// 显示征子路线和引征
+    pub show_ladders: bool,
+    /// 显示形势判断（影响图估计的地和目差）
+    pub show_score_estimate: bool,
+    /// 在每个棋串上显示气数
+    pub show_liberties: bool,
+    /// 用红圈标出只剩一口气的棋串（双方都标）
+    pub show_atari_warnings: bool,
+    /// 双方连续虚手后先进入数子阶段，确认死子后再计分
+    pub scoring_phase: bool,
+    /// 点击先放置待确认的棋子，再点同一点才落子
+    pub confirm_moves: bool,
+    /// 显示键盘光标，用方向键移动、回车落子；手柄也使用这个光标
+    pub keyboard_cursor: bool,
+    /// 触控下棋：轻点落子，双指缩放和平移，双击恢复视图
+    pub touch_controls: bool,
 }
 
 impl Default for GoBoardConfig {
@@ -47,58 +82,40 @@ impl Default for GoBoardConfig {
             coordinate_color: Color::srgb(0.40, 0.35, 0.30),
             star_point_radius_ratio: 0.11,
             line_width_ratio: 0.035,
+            board_position: Vec2::ZERO,
+            board_extent: 1100.0,
+            fit_to_window: false,
             adaptive_padding: true,
             enable_hover_indicator: true,
             enable_sound: false,
             enable_captures: true,
             enable_ko_rule: true,
-        }
-    }
-}
-
-/// 棋盘大小枚举
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-pub enum BoardSize {
-    Nine = 9,
-    Thirteen = 13,
-    Nineteen = 19,
-}
-
-impl BoardSize {
-    pub fn get_value(&self) -> i32 {
-        *self as i32
-    }
-
-    /// 获取星位点坐标
-    pub fn get_star_points(&self) -> Vec<(i32, i32)> {
-        match self {
-            BoardSize::Nine => vec![(2, 2), (2, 6), (4, 4), (6, 2), (6, 6)],
-            BoardSize::Thirteen => vec![(3, 3), (3, 9), (6, 6), (9, 3), (9, 9)],
-            BoardSize::Nineteen => vec![
-                (3, 3),
-                (3, 9),
-                (3, 15),
-                (9, 3),
-                (9, 9),
-                (9, 15),
-                (15, 3),
-                (15, 9),
-                (15, 15),
-            ],
+            enable_superko_rule: false,
+            komi: DEFAULT_KOMI,
+            show_candidate_moves: true,
+            show_ownership: true,
+            show_hints: true,
+            show_ladders: false,
+            show_score_estimate: false,
+            show_liberties: false,
+            show_atari_warnings: false,
+            scoring_phase: false,
+            confirm_moves: false,
+            keyboard_cursor: false,
+            touch_controls: false,
         }
     }
 }
 
 /// 配置构建器模式
+#[derive(Default)]
 pub struct GoBoardConfigBuilder {
     config: GoBoardConfig,
 }
 
 impl GoBoardConfigBuilder {
     pub fn new() -> Self {
-        Self {
-            config: GoBoardConfig::default(),
-        }
+        Self::default()
     }
 
     pub fn board_size(mut self, size: BoardSize) -> Self {
@@ -126,6 +143,26 @@ impl GoBoardConfigBuilder {
         self
     }
 
+    pub fn board_position(mut self, position: Vec2) -> Self {
+        self.config.board_position = position;
+        self
+    }
+
+    pub fn board_extent(mut self, extent: f32) -> Self {
+        self.config.board_extent = extent;
+        self
+    }
+
+    pub fn fit_to_window(mut self, enable: bool) -> Self {
+        self.config.fit_to_window = enable;
+        self
+    }
+
+    pub fn adaptive_padding(mut self, enable: bool) -> Self {
+        self.config.adaptive_padding = enable;
+        self
+    }
+
     pub fn enable_captures(mut self, enable: bool) -> Self {
         self.config.enable_captures = enable;
         self
@@ -136,6 +173,71 @@ impl GoBoardConfigBuilder {
         self
     }
 
+    pub fn enable_superko_rule(mut self, enable: bool) -> Self {
+        self.config.enable_superko_rule = enable;
+        self
+    }
+
+    pub fn komi(mut self, komi: f32) -> Self {
+        self.config.komi = komi;
+        self
+    }
+
+    pub fn show_candidate_moves(mut self, show: bool) -> Self {
+        self.config.show_candidate_moves = show;
+        self
+    }
+
+    pub fn show_ownership(mut self, show: bool) -> Self {
+        self.config.show_ownership = show;
+        self
+    }
+
+    pub fn show_hints(mut self, show: bool) -> Self {
+        self.config.show_hints = show;
+        self
+    }
+
+    pub fn show_ladders(mut self, show: bool) -> Self {
+        self.config.show_ladders = show;
+        self
+    }
+
+    pub fn show_score_estimate(mut self, show: bool) -> Self {
+        self.config.show_score_estimate = show;
+        self
+    }
+
+    pub fn show_liberties(mut self, show: bool) -> Self {
+        self.config.show_liberties = show;
+        self
+    }
+
+    pub fn show_atari_warnings(mut self, show: bool) -> Self {
+        self.config.show_atari_warnings = show;
+        self
+    }
+
+    pub fn scoring_phase(mut self, enable: bool) -> Self {
+        self.config.scoring_phase = enable;
+        self
+    }
+
+    pub fn confirm_moves(mut self, enable: bool) -> Self {
+        self.config.confirm_moves = enable;
+        self
+    }
+
+    pub fn keyboard_cursor(mut self, enable: bool) -> Self {
+        self.config.keyboard_cursor = enable;
+        self
+    }
+
+    pub fn touch_controls(mut self, enable: bool) -> Self {
+        self.config.touch_controls = enable;
+        self
+    }
+
     pub fn build(self) -> GoBoardConfig {
         self.config
     }
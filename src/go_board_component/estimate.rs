@@ -0,0 +1,135 @@
+use super::{
+    assets::BoardAssets,
+    components::*,
+    config::GoBoardConfig,
+    events::RedrawBoardEvent,
+    resources::{BoardState, CurrentGoBoardConfig},
+    utils::{CoordinateUtils, RenderUtils},
+};
+use crate::go_core::{GoBoardRules, ScoreEstimate};
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+/// 当前局面的形势判断，挂在棋盘根节点上
+///
+/// 打开 `show_score_estimate` 时随局面更新，关闭时移除
+#[derive(Component)]
+pub struct BoardScoreEstimate(pub ScoreEstimate);
+
+/// 更新形势判断需要的棋盘组件
+type EstimateInputs<'a> = (
+    Entity,
+    Ref<'a, CurrentGoBoardConfig>,
+    Ref<'a, BoardState>,
+    Has<BoardScoreEstimate>,
+);
+
+/// 局面或配置变化时重新判断形势
+pub fn update_score_estimate(
+    mut commands: Commands,
+    boards: Query<EstimateInputs, With<GoBoardRoot>>,
+) {
+    for (root, config, board_state, has_estimate) in boards.iter() {
+        if !config.0.show_score_estimate {
+            if has_estimate {
+                commands.entity(root).remove::<BoardScoreEstimate>();
+            }
+            continue;
+        }
+        if has_estimate && !config.is_changed() && !board_state.is_changed() {
+            continue;
+        }
+
+        let estimate = GoBoardRules::estimate_score(&board_state, config.0.komi);
+        commands.entity(root).insert(BoardScoreEstimate(estimate));
+    }
+}
+
+/// 同步形势判断叠加层
+///
+/// 整体重绘时标记已随棋盘子实体一起清除，只需重新生成
+pub fn sync_estimate_overlay(
+    mut commands: Commands,
+    mut assets: BoardAssets,
+    mut redraw_events: EventReader<RedrawBoardEvent>,
+    mut removed_estimates: RemovedComponents<BoardScoreEstimate>,
+    boards: Query<(Entity, Ref<CurrentGoBoardConfig>, &BoardState), With<GoBoardRoot>>,
+    estimates: Query<Ref<BoardScoreEstimate>>,
+    markers: Query<(Entity, &ChildOf), With<TerritoryMarker>>,
+) {
+    let redraw_targets: HashSet<Entity> = redraw_events.read().map(|event| event.board).collect();
+    let cleared: HashSet<Entity> = removed_estimates.read().collect();
+
+    for (root, config, board_state) in boards.iter() {
+        let board_estimate = estimates.get(root).ok();
+        let redrawn = config.is_changed() || redraw_targets.contains(&root);
+        let estimate_changed = board_estimate
+            .as_ref()
+            .is_some_and(|estimate| estimate.is_changed());
+        if !redrawn && !estimate_changed && !cleared.contains(&root) {
+            continue;
+        }
+
+        if !redrawn {
+            for (entity, parent) in markers.iter() {
+                if parent.parent() == root {
+                    commands.entity(entity).despawn();
+                }
+            }
+        }
+
+        if let Some(board_estimate) = board_estimate
+            && config.0.show_score_estimate
+        {
+            draw_estimate(
+                &mut commands,
+                &mut assets,
+                root,
+                &config.0,
+                board_state,
+                &board_estimate.0,
+            );
+        }
+    }
+}
+
+/// 在归属已定的空点和估计的死子上画半透明方块，黑地用黑色、白地用白色
+fn draw_estimate(
+    commands: &mut Commands,
+    assets: &mut BoardAssets,
+    root: Entity,
+    config: &GoBoardConfig,
+    board_state: &BoardState,
+    estimate: &ScoreEstimate,
+) {
+    let metrics = RenderUtils::calculate_board_metrics(config.board_extent, config.board_size);
+    let square = assets.rectangle(metrics.cell_size * 0.5, metrics.cell_size * 0.5);
+    let size = config.board_size.get_value();
+
+    for x in 0..size {
+        for y in 0..size {
+            let owner = match estimate.ownership_at(x, y) {
+                Some(ownership) if ownership > 0.0 => StoneColor::Black,
+                Some(ownership) if ownership < 0.0 => StoneColor::White,
+                _ => continue,
+            };
+            if board_state.get_stone(x, y) == Some(owner) {
+                continue;
+            }
+
+            let color = match owner {
+                StoneColor::Black => Color::srgba(0.0, 0.0, 0.0, 0.45),
+                StoneColor::White => Color::srgba(1.0, 1.0, 1.0, 0.55),
+            };
+            let local_pos =
+                CoordinateUtils::board_to_local((x, y), config.board_size, config.board_extent);
+            commands.spawn((
+                Mesh2d(square.clone()),
+                MeshMaterial2d(assets.material(color)),
+                Transform::from_translation(Vec3::new(local_pos.x, local_pos.y, 4.1)),
+                TerritoryMarker { owner },
+                ChildOf(root),
+            ));
+        }
+    }
+}
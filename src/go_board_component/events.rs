@@ -1,24 +1,72 @@
 use super::components::StoneColor;
 use super::config::GoBoardConfig;
+use bevy::input::touch::TouchPhase;
+use bevy::picking::pointer::PointerId;
 use bevy::prelude::*;
 
+// 所有事件都带有目标棋盘（GoBoardRoot 实体），同一个 App 中可以同时存在多个棋盘
+
 /// 重绘棋盘事件
 #[derive(Event)]
-pub struct RedrawBoardEvent;
+pub struct RedrawBoardEvent {
+    pub board: Entity,
+}
 
 /// 更新棋盘配置事件
 #[derive(Event)]
 pub struct UpdateBoardConfigEvent {
+    pub board: Entity,
     pub config: GoBoardConfig,
 }
 
 /// 落子事件
 #[derive(Event)]
 pub struct PlaceStoneEvent {
+    pub board: Entity,
     pub position: (i32, i32),
     pub color: StoneColor,
 }
 
+/// 玩家点击或轻触棋盘上的点
+///
+/// 关闭 `confirm_moves` 时直接落子；开启时先放置待确认的棋子，
+/// 再点同一点才发出 [`PlaceStoneEvent`]，点其他点则移动待确认的棋子
+#[derive(Event)]
+pub struct SelectPointEvent {
+    pub board: Entity,
+    pub position: (i32, i32),
+}
+
+/// 指针悬停的交叉点变化，离开棋盘或移出网格时 `position` 为 `None`
+#[derive(Event, Debug, Clone)]
+pub struct IntersectionHoverEvent {
+    pub board: Entity,
+    pub pointer: PointerId,
+    pub position: Option<(i32, i32)>,
+}
+
+/// 在交叉点上点击或轻触
+#[derive(Event, Debug, Clone)]
+pub struct IntersectionClickEvent {
+    pub board: Entity,
+    pub pointer: PointerId,
+    pub button: PointerButton,
+    pub position: (i32, i32),
+}
+
+/// 在棋盘上拖动，开始、经过新的交叉点和松开时发送，松开时 `finished` 为 `true`；
+/// 指针被取消时也会结束拖动，此时 `cancelled` 同为 `true`
+#[derive(Event, Debug, Clone)]
+pub struct IntersectionDragEvent {
+    pub board: Entity,
+    pub pointer: PointerId,
+    pub button: PointerButton,
+    pub start: (i32, i32),
+    pub position: (i32, i32),
+    pub finished: bool,
+    pub cancelled: bool,
+}
+
 /// 棋子动作类型
 #[derive(Debug, Clone, Copy)]
 pub enum StoneActionType {
@@ -31,6 +79,7 @@ pub enum StoneActionType {
 /// 棋子动作事件 - 更高层级的事件，可以触发游戏逻辑
 #[derive(Event)]
 pub struct StoneActionEvent {
+    pub board: Entity,
     pub action_type: StoneActionType,
     pub position: Option<(i32, i32)>,
     pub color: StoneColor,
@@ -39,31 +88,63 @@ pub struct StoneActionEvent {
 /// 游戏结束事件
 #[derive(Event)]
 pub struct GameEndEvent {
+    pub board: Entity,
     pub winner: Option<StoneColor>,
     pub black_score: f32,
     pub white_score: f32,
 }
 
+/// 数子阶段切换一块棋的死活标记
+#[derive(Event)]
+pub struct ToggleDeadStonesEvent {
+    pub board: Entity,
+    pub position: (i32, i32),
+}
+
+/// 数子阶段确认死子并计分
+#[derive(Event)]
+pub struct ConfirmScoreEvent {
+    pub board: Entity,
+}
+
 /// 撤销事件
 #[derive(Event)]
-pub struct UndoMoveEvent;
+pub struct UndoMoveEvent {
+    pub board: Entity,
+}
 
 /// 重做事件
 #[derive(Event)]
-pub struct RedoMoveEvent;
+pub struct RedoMoveEvent {
+    pub board: Entity,
+}
 
 /// 清空棋盘事件
 #[derive(Event)]
-pub struct ClearBoardEvent;
+pub struct ClearBoardEvent {
+    pub board: Entity,
+}
 
 /// 加载棋谱事件
 #[derive(Event)]
 pub struct LoadGameEvent {
+    pub board: Entity,
     pub sgf_content: String,
 }
 
 /// 保存棋谱事件
 #[derive(Event)]
 pub struct SaveGameEvent {
+    pub board: Entity,
     pub file_path: String,
 }
+
+/// 换算到世界坐标的触摸事件，不指定棋盘，由触控插件按位置找到棋盘
+///
+/// 通常由 `TouchInput` 经相机换算得到，也可以直接发送
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct BoardTouchEvent {
+    pub id: u64,
+    pub phase: TouchPhase,
+    pub position: Vec2,
+}
@@ -0,0 +1,218 @@
+use super::{
+    components::*,
+    keyboard::{ActionBindings, ActionTarget, BoardAction, BoardActionEvents, step_cursor},
+    players::ComputerPlayer,
+    resources::{CurrentGoBoardConfig, CurrentTurn, GameHistory},
+};
+use bevy::input::gamepad::{GamepadConnection, GamepadConnectionEvent};
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// 摇杆偏离中心超过此值才移动光标
+const STICK_THRESHOLD: f32 = 0.5;
+
+/// 按住方向后开始连续移动前的等待时间（秒）
+const REPEAT_DELAY: f32 = 0.35;
+
+/// 连续移动时每一步的间隔（秒）
+const REPEAT_INTERVAL: f32 = 0.12;
+
+/// 移动光标的操作和对应的方向
+const CURSOR_ACTIONS: [BoardAction; 4] = [
+    BoardAction::CursorUp,
+    BoardAction::CursorDown,
+    BoardAction::CursorLeft,
+    BoardAction::CursorRight,
+];
+
+/// 手柄按键绑定
+pub type GamepadBindings = ActionBindings<GamepadButton>;
+
+impl Default for GamepadBindings {
+    fn default() -> Self {
+        use BoardAction::*;
+        Self::from_pairs([
+            (CursorUp, vec![GamepadButton::DPadUp]),
+            (CursorDown, vec![GamepadButton::DPadDown]),
+            (CursorLeft, vec![GamepadButton::DPadLeft]),
+            (CursorRight, vec![GamepadButton::DPadRight]),
+            (Place, vec![GamepadButton::South]),
+            (Pass, vec![GamepadButton::North]),
+            // 历史是线性的，悔棋就是后退一手
+            (
+                PreviousMove,
+                vec![GamepadButton::West, GamepadButton::LeftTrigger],
+            ),
+            (NextMove, vec![GamepadButton::RightTrigger]),
+            (FirstMove, vec![GamepadButton::LeftTrigger2]),
+            (LastMove, vec![GamepadButton::RightTrigger2]),
+            (ConfirmScore, vec![GamepadButton::Start]),
+        ])
+    }
+}
+
+/// 手柄控制的棋盘和执的颜色，挂在手柄实体上
+///
+/// 连接时自动分配到挂有 [`BoardCursor`] 的棋盘，依次执黑、执白，之后的手柄不固定颜色；
+/// 也可以直接修改或在连接前插入来指定。断开后保留，重新连接时继续使用原来的座位
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GamepadPlayer {
+    pub board: Entity,
+    /// 执的颜色，`None` 时可以替轮到的一方落子
+    pub color: Option<StoneColor>,
+}
+
+/// 手柄下棋需要读取的棋盘组件
+type GamepadBoard<'a> = (
+    &'a mut BoardCursor,
+    &'a CurrentGoBoardConfig,
+    &'a CurrentTurn,
+    &'a GameHistory,
+    Has<ScoringPhase>,
+    Has<GameOver>,
+);
+
+/// 按住方向时的连续移动状态
+#[derive(Debug, Clone, Copy)]
+pub struct CursorRepeat {
+    direction: (i32, i32),
+    next_step: f32,
+}
+
+/// 记录手柄连接和断开，并给已连接、还没有座位的手柄分配 [`GamepadPlayer`]
+///
+/// 断开的手柄仍然占着原来的座位。有多块挂有 [`BoardCursor`] 的棋盘时，
+/// 新手柄固定分配到实体编号最小的一块，其他棋盘需要手动插入座位
+pub fn assign_gamepads(
+    mut commands: Commands,
+    mut connection_events: EventReader<GamepadConnectionEvent>,
+    unseated: Query<Entity, (With<Gamepad>, Without<GamepadPlayer>)>,
+    seats: Query<&GamepadPlayer>,
+    boards: Query<Entity, (With<GoBoardRoot>, With<BoardCursor>)>,
+) {
+    for event in connection_events.read() {
+        match &event.connection {
+            GamepadConnection::Connected { name, .. } => {
+                info!("Gamepad {} connected: {}", event.gamepad, name)
+            }
+            GamepadConnection::Disconnected => info!("Gamepad {} disconnected", event.gamepad),
+        }
+    }
+
+    let Some(board) = boards.iter().min() else {
+        return;
+    };
+    let mut seats: Vec<GamepadPlayer> = seats.iter().copied().collect();
+    for gamepad in unseated.iter() {
+        let color = [StoneColor::Black, StoneColor::White]
+            .into_iter()
+            .find(|color| {
+                !seats
+                    .iter()
+                    .any(|seat| seat.board == board && seat.color == Some(*color))
+            });
+        let seat = GamepadPlayer { board, color };
+        seats.push(seat);
+        commands.entity(gamepad).insert(seat);
+    }
+}
+
+/// 按 [`GamepadBindings`] 处理已连接手柄的操作
+///
+/// 方向键或左摇杆移动光标，按住时连续移动；执某一方的手柄只在轮到这一方时落子和虚手，
+/// 对方没有手柄时也可以替对方下。轮到电脑棋手时不落子也不虚手
+pub fn handle_gamepad_play(
+    time: Res<Time>,
+    bindings: Res<GamepadBindings>,
+    gamepads: Query<(Entity, &Gamepad, &GamepadPlayer)>,
+    mut boards: Query<GamepadBoard, With<GoBoardRoot>>,
+    computer_players: Query<&ComputerPlayer>,
+    mut repeats: Local<HashMap<Entity, CursorRepeat>>,
+    mut events: BoardActionEvents,
+) {
+    let now = time.elapsed_secs();
+    repeats.retain(|gamepad, _| gamepads.contains(*gamepad));
+
+    for (gamepad_entity, gamepad, seat) in gamepads.iter() {
+        let Ok((mut cursor, config, current_turn, history, scoring, game_over)) =
+            boards.get_mut(seat.board)
+        else {
+            continue;
+        };
+        let size = config.0.board_size.get_value();
+
+        // 方向键优先，其次取摇杆偏移较大的轴；摇杆向上是 y 减小
+        let mut direction = (0, 0);
+        for action in CURSOR_ACTIONS {
+            if let Some((dx, dy)) = action.cursor_step()
+                && gamepad.any_pressed(bindings.keys(action).iter().copied())
+            {
+                direction = (direction.0 + dx, direction.1 + dy);
+            }
+        }
+        let stick = gamepad.left_stick();
+        if direction == (0, 0) && stick.length() > STICK_THRESHOLD {
+            direction = if stick.x.abs() > stick.y.abs() {
+                (stick.x.signum() as i32, 0)
+            } else {
+                (0, -stick.y.signum() as i32)
+            };
+        }
+
+        if direction == (0, 0) {
+            repeats.remove(&gamepad_entity);
+        } else {
+            let repeat = repeats.get(&gamepad_entity).copied();
+            match repeat {
+                Some(repeat) if repeat.direction == direction && now < repeat.next_step => {}
+                _ => {
+                    step_cursor(&mut cursor, direction, size);
+                    let delay = if repeat.is_some_and(|repeat| repeat.direction == direction) {
+                        REPEAT_INTERVAL
+                    } else {
+                        REPEAT_DELAY
+                    };
+                    repeats.insert(
+                        gamepad_entity,
+                        CursorRepeat {
+                            direction,
+                            next_step: now + delay,
+                        },
+                    );
+                }
+            }
+        }
+
+        let turn = current_turn.0;
+        let computer_turn = computer_players
+            .iter()
+            .any(|player| player.board == seat.board && player.color == turn);
+        let turn_taken = gamepads.iter().any(|(other, _, other_seat)| {
+            other != gamepad_entity
+                && other_seat.board == seat.board
+                && other_seat.color == Some(turn)
+        });
+        let may_play =
+            !computer_turn && (seat.color.map_or(true, |color| color == turn) || !turn_taken);
+
+        for &button in gamepad.get_just_pressed() {
+            for action in bindings.actions(button) {
+                if action.cursor_step().is_some() {
+                    continue;
+                }
+                events.perform(
+                    action,
+                    &ActionTarget {
+                        board: seat.board,
+                        position: cursor.position,
+                        turn,
+                        history,
+                        scoring,
+                        game_over,
+                        may_play,
+                    },
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,294 @@
+use super::{
+    assets::BoardAssets,
+    components::*,
+    config::GoBoardConfig,
+    events::RedrawBoardEvent,
+    resources::{BoardState, CurrentGoBoardConfig, CurrentTurn, GameHistory},
+    utils::{CoordinateUtils, RenderUtils},
+};
+use crate::go_core::{BoardSize, FeaturePlanes, PolicyEvaluator, PolicyOutput};
+use bevy::prelude::*;
+use std::collections::HashSet;
+use std::io;
+use std::sync::{Mutex, mpsc};
+use std::thread;
+
+/// 最多显示的提示点数
+const MAX_HINTS: usize = 24;
+
+/// 概率低于此值的点不显示提示
+const HINT_THRESHOLD: f32 = 0.01;
+
+/// 策略网络对当前局面的判断，挂在棋盘根节点上
+///
+/// 局面变化时移除，新结果到达后重新插入
+#[derive(Component)]
+pub struct MoveHints(pub PolicyOutput);
+
+/// 已发送给网络的局面
+#[derive(PartialEq)]
+struct HintedPosition {
+    board_size: BoardSize,
+    moves: Vec<(StoneColor, Option<(i32, i32)>)>,
+    to_play: StoneColor,
+}
+
+/// 发给推理线程的请求
+struct HintRequest {
+    id: u64,
+    planes: FeaturePlanes,
+    board_state: BoardState,
+    to_play: StoneColor,
+    /// 打开全局同形禁着时用于去掉重复局面的点
+    history: Option<GameHistory>,
+}
+
+/// 策略网络提示引擎
+///
+/// 单独生成一个实体，通过 `board` 指向要提示的棋盘根节点；
+/// 推理在后台线程中进行，结果以 [`MoveHints`] 挂到棋盘上
+///
+/// # 使用示例
+/// ```rust,ignore
+/// let network = OnnxPolicy::load("policy_19x19.onnx", BoardSize::Nineteen)?;
+/// commands.spawn(HintEngine::spawn(board, Box::new(network))?);
+/// ```
+#[derive(Component)]
+pub struct HintEngine {
+    pub board: Entity,
+    requests: mpsc::Sender<HintRequest>,
+    replies: Mutex<mpsc::Receiver<(u64, Result<PolicyOutput, String>)>>,
+    next_id: u64,
+    /// 最近一次请求的编号和局面
+    current: Option<(u64, HintedPosition)>,
+    /// 推理线程退出后不再请求
+    failed: bool,
+}
+
+impl HintEngine {
+    /// 启动推理线程
+    pub fn spawn(board: Entity, mut evaluator: Box<dyn PolicyEvaluator>) -> io::Result<Self> {
+        let (request_sender, request_receiver) = mpsc::channel::<HintRequest>();
+        let (reply_sender, reply_receiver) = mpsc::channel();
+
+        thread::Builder::new()
+            .name("policy-hints".to_string())
+            .spawn(move || {
+                for request in request_receiver {
+                    let reply = evaluator
+                        .evaluate(&request.planes)
+                        .and_then(|mut output| {
+                            output.mask_illegal(
+                                &request.board_state,
+                                request.to_play,
+                                request.history.as_ref(),
+                            )?;
+                            Ok(output)
+                        })
+                        .map_err(|error| error.to_string());
+                    if reply_sender.send((request.id, reply)).is_err() {
+                        break;
+                    }
+                }
+            })?;
+
+        Ok(Self {
+            board,
+            requests: request_sender,
+            replies: Mutex::new(reply_receiver),
+            next_id: 0,
+            current: None,
+            failed: false,
+        })
+    }
+}
+
+/// 驱动提示引擎：局面变化时发出请求，把最新请求的结果挂到棋盘上
+///
+/// 推理失败（如模型与棋盘大小不符）只记录警告，局面变化后再试
+pub fn drive_hint_engines(
+    mut commands: Commands,
+    mut engines: Query<&mut HintEngine>,
+    boards: Query<
+        (
+            &CurrentGoBoardConfig,
+            &GameHistory,
+            &BoardState,
+            &CurrentTurn,
+        ),
+        With<GoBoardRoot>,
+    >,
+) {
+    for mut engine in engines.iter_mut() {
+        let board = engine.board;
+        let Ok((config, history, board_state, current_turn)) = boards.get(board) else {
+            continue;
+        };
+        if engine.failed {
+            continue;
+        }
+
+        // 收取结果，只保留最新请求的
+        let mut latest = None;
+        let mut stopped = false;
+        if let Ok(replies) = engine.replies.get_mut() {
+            loop {
+                match replies.try_recv() {
+                    Ok(reply) => latest = Some(reply),
+                    Err(mpsc::TryRecvError::Empty) => break,
+                    Err(mpsc::TryRecvError::Disconnected) => {
+                        stopped = true;
+                        break;
+                    }
+                }
+            }
+        }
+        if stopped {
+            error!("Policy network stopped: inference thread exited");
+            engine.failed = true;
+            continue;
+        }
+        if let Some((id, reply)) = latest
+            && engine
+                .current
+                .as_ref()
+                .is_some_and(|(current_id, _)| *current_id == id)
+        {
+            match reply {
+                Ok(output) => {
+                    commands.entity(board).insert(MoveHints(output));
+                }
+                Err(message) => warn!("Policy network failed: {}", message),
+            }
+        }
+
+        // 局面变化时发送新请求
+        let played = history.played_moves();
+        let position = HintedPosition {
+            board_size: config.0.board_size,
+            moves: played
+                .iter()
+                .map(|played| (played.color, played.position))
+                .collect(),
+            to_play: current_turn.0,
+        };
+        if engine
+            .current
+            .as_ref()
+            .is_some_and(|(_, hinted)| *hinted == position)
+        {
+            continue;
+        }
+
+        engine.next_id += 1;
+        let request = HintRequest {
+            id: engine.next_id,
+            planes: FeaturePlanes::from_moves(position.board_size, played, position.to_play),
+            board_state: board_state.clone(),
+            to_play: position.to_play,
+            history: config.0.enable_superko_rule.then(|| history.clone()),
+        };
+        if engine.requests.send(request).is_err() {
+            error!("Policy network stopped: request channel closed");
+            engine.failed = true;
+            continue;
+        }
+
+        engine.current = Some((engine.next_id, position));
+        commands.entity(board).remove::<MoveHints>();
+    }
+}
+
+/// 同步策略网络的落子提示热力图
+///
+/// 整体重绘时提示已随棋盘子实体一起清除，只需重新生成
+pub fn sync_hint_overlay(
+    mut commands: Commands,
+    mut assets: BoardAssets,
+    mut redraw_events: EventReader<RedrawBoardEvent>,
+    mut removed_hints: RemovedComponents<MoveHints>,
+    boards: Query<(Entity, Ref<CurrentGoBoardConfig>), With<GoBoardRoot>>,
+    hints: Query<Ref<MoveHints>>,
+    markers: Query<(Entity, &ChildOf), With<HintMarker>>,
+) {
+    let redraw_targets: HashSet<Entity> = redraw_events.read().map(|event| event.board).collect();
+    let cleared: HashSet<Entity> = removed_hints.read().collect();
+
+    for (root, config) in boards.iter() {
+        let board_hints = hints.get(root).ok();
+        let redrawn = config.is_changed() || redraw_targets.contains(&root);
+        let hints_changed = board_hints.as_ref().is_some_and(|hints| hints.is_changed());
+        if !redrawn && !hints_changed && !cleared.contains(&root) {
+            continue;
+        }
+
+        if !redrawn {
+            for (entity, parent) in markers.iter() {
+                if parent.parent() == root {
+                    commands.entity(entity).despawn();
+                }
+            }
+        }
+
+        if let Some(board_hints) = board_hints
+            && config.0.show_hints
+        {
+            draw_hints(&mut commands, &mut assets, root, &config.0, &board_hints.0);
+        }
+    }
+}
+
+/// 绘制提示热力图：颜色越深概率越高，最高的点标出百分比
+fn draw_hints(
+    commands: &mut Commands,
+    assets: &mut BoardAssets,
+    root: Entity,
+    config: &GoBoardConfig,
+    output: &PolicyOutput,
+) {
+    if output.board_size != config.board_size {
+        return;
+    }
+
+    let metrics = RenderUtils::calculate_board_metrics(config.board_extent, config.board_size);
+    let moves = output.top_moves(MAX_HINTS);
+    let Some(&(_, best)) = moves.first() else {
+        return;
+    };
+
+    let radius = metrics.cell_size * 0.4;
+    let font_size = (metrics.cell_size * 0.28).clamp(8.0, 24.0);
+    for (rank, (position, probability)) in moves.into_iter().enumerate() {
+        if probability < HINT_THRESHOLD {
+            break;
+        }
+
+        // 相对最高概率着色，量化透明度避免每个点都生成一个新材质
+        let alpha = 0.15 + 0.6 * (probability / best).sqrt();
+        let alpha = (alpha * 10.0).round() / 10.0;
+        let local_pos =
+            CoordinateUtils::board_to_local(position, config.board_size, config.board_extent);
+        let marker = commands
+            .spawn((
+                Mesh2d(assets.circle(radius)),
+                MeshMaterial2d(assets.material(Color::srgba(0.90, 0.30, 0.10, alpha))),
+                Transform::from_translation(Vec3::new(local_pos.x, local_pos.y, 4.6)),
+                HintMarker,
+                ChildOf(root),
+            ))
+            .id();
+
+        if rank == 0 {
+            commands.spawn((
+                Text2d::new(format!("{:.0}%", probability * 100.0)),
+                TextFont {
+                    font_size,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.05, 0.05, 0.05)),
+                Transform::from_translation(Vec3::new(0.0, 0.0, 0.1)),
+                ChildOf(marker),
+            ));
+        }
+    }
+}
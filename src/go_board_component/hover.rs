@@ -0,0 +1,216 @@
+use super::{
+    assets::BoardAssets,
+    components::*,
+    picking::BoardPointers,
+    resources::{BoardState, CurrentGoBoardConfig, CurrentTurn, GameHistory},
+    rules::IllegalMove,
+    systems::check_move,
+    utils::{CoordinateUtils, RenderUtils},
+};
+use bevy::picking::pointer::PointerId;
+use bevy::prelude::*;
+use std::f32::consts::FRAC_PI_4;
+
+/// 光标下可以落子的空点
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HoveredPoint {
+    pub board: Entity,
+    pub position: (i32, i32),
+    /// 轮到的一方
+    pub color: StoneColor,
+    /// 在这里落子不合法的原因
+    pub illegal: Option<IllegalMove>,
+}
+
+/// 当前悬停的点，光标不在任何对局中棋盘的空点上时为 `None`
+#[derive(Resource, Default, Debug, Clone, PartialEq, Eq)]
+pub struct BoardHover(pub Option<HoveredPoint>);
+
+/// 悬停需要读取的棋盘组件
+type HoverInputs<'a> = (
+    &'a CurrentGoBoardConfig,
+    &'a BoardState,
+    &'a CurrentTurn,
+    &'a GameHistory,
+);
+
+/// 对局中、可以悬停落子的棋盘
+type OpenBoard = (With<GoBoardRoot>, Without<GameOver>, Without<ScoringPhase>);
+
+/// 悬停指示器中半透明棋子的可变部分
+type GhostParts<'a> = (&'a mut Transform, &'a mut MeshMaterial2d<ColorMaterial>);
+
+/// 只选中红叉，与指示器和棋子的 `Transform` 不冲突
+type CrossFilter = (
+    With<HoverCross>,
+    Without<HoverIndicator>,
+    Without<HoverGhost>,
+);
+
+/// 提示文字的可变部分
+type TooltipParts<'a> = (
+    &'a mut Transform,
+    &'a mut Text2d,
+    &'a mut TextFont,
+    &'a mut TextColor,
+);
+
+/// 只选中提示文字
+type TooltipFilter = (
+    With<HoverTooltip>,
+    Without<HoverIndicator>,
+    Without<HoverGhost>,
+    Without<HoverCross>,
+);
+
+/// 生成唯一的悬停指示器，之后只更新它的位置、颜色和提示
+pub fn spawn_hover_indicator(mut commands: Commands, mut assets: BoardAssets) {
+    let bar = assets.rectangle(2.0, 0.2);
+    let cross_color = assets.material(Color::srgb(0.85, 0.10, 0.10));
+
+    commands
+        .spawn((
+            HoverIndicator,
+            Transform::default(),
+            Visibility::Hidden,
+            Pickable::IGNORE,
+            Name::new("Hover Indicator"),
+        ))
+        .with_children(|indicator| {
+            indicator.spawn((
+                HoverGhost,
+                Mesh2d(assets.circle(1.0)),
+                MeshMaterial2d(assets.material(Color::NONE)),
+                Transform::default(),
+                Pickable::IGNORE,
+            ));
+            indicator
+                .spawn((
+                    HoverCross,
+                    Transform::default(),
+                    Visibility::Hidden,
+                    Pickable::IGNORE,
+                ))
+                .with_children(|cross| {
+                    for angle in [FRAC_PI_4, -FRAC_PI_4] {
+                        cross.spawn((
+                            Mesh2d(bar.clone()),
+                            MeshMaterial2d(cross_color.clone()),
+                            Transform::from_rotation(Quat::from_rotation_z(angle)),
+                            Pickable::IGNORE,
+                        ));
+                    }
+                });
+            indicator.spawn((
+                HoverTooltip,
+                Text2d::default(),
+                TextFont::default(),
+                TextColor(Color::BLACK),
+                Transform::default(),
+                Pickable::IGNORE,
+            ));
+        });
+}
+
+/// 按鼠标悬停的交叉点更新 [`BoardHover`]，只在悬停的点或合法性变化时触发变更
+pub fn update_board_hover(
+    mut hover: ResMut<BoardHover>,
+    pointers: Res<BoardPointers>,
+    boards: Query<HoverInputs, OpenBoard>,
+) {
+    let hovered = pointers
+        .hovered(PointerId::Mouse)
+        .and_then(|(board, (x, y))| {
+            let (config, board_state, current_turn, history) = boards.get(board).ok()?;
+            if !config.0.enable_hover_indicator || board_state.get_stone(x, y).is_some() {
+                return None;
+            }
+            Some(HoveredPoint {
+                board,
+                position: (x, y),
+                color: current_turn.0,
+                illegal: check_move(&config.0, board_state, history, x, y, current_turn.0).err(),
+            })
+        });
+    hover.set_if_neq(BoardHover(hovered));
+}
+
+/// 把悬停指示器移到悬停的点：合法时是半透明棋子，不合法时是红色棋子加红叉，
+/// 上方提示坐标和不合法的原因
+pub fn sync_hover_indicator(
+    mut assets: BoardAssets,
+    hover: Res<BoardHover>,
+    boards: Query<(&GlobalTransform, &CurrentGoBoardConfig), With<GoBoardRoot>>,
+    mut indicators: Query<(&mut Transform, &mut Visibility), With<HoverIndicator>>,
+    mut ghosts: Query<GhostParts, (With<HoverGhost>, Without<HoverIndicator>)>,
+    mut crosses: Query<(&mut Transform, &mut Visibility), CrossFilter>,
+    mut tooltips: Query<TooltipParts, TooltipFilter>,
+) {
+    let Ok((mut indicator_transform, mut indicator_visibility)) = indicators.single_mut() else {
+        return;
+    };
+    let Some((point, (root_transform, config))) = hover
+        .0
+        .and_then(|point| Some((point, boards.get(point.board).ok()?)))
+    else {
+        indicator_visibility.set_if_neq(Visibility::Hidden);
+        return;
+    };
+
+    // 跟随棋盘根节点的位置和缩放，棋盘移动或窗口缩放时也能对齐
+    let board_size = config.0.board_size;
+    let local_pos =
+        CoordinateUtils::board_to_local(point.position, board_size, config.0.board_extent);
+    let transform = root_transform
+        .mul_transform(Transform::from_translation(local_pos.with_z(4.5)))
+        .compute_transform();
+    indicator_transform.set_if_neq(transform);
+    indicator_visibility.set_if_neq(Visibility::Inherited);
+
+    let cell_size =
+        RenderUtils::calculate_board_metrics(config.0.board_extent, board_size).cell_size;
+    let ghost_color = match (point.illegal, point.color) {
+        (Some(_), _) => Color::srgba(0.85, 0.10, 0.10, 0.45),
+        (None, StoneColor::Black) => Color::srgba(0.1, 0.1, 0.1, 0.5),
+        (None, StoneColor::White) => Color::srgba(0.95, 0.95, 0.95, 0.5),
+    };
+    if let Ok((mut ghost_transform, mut material)) = ghosts.single_mut() {
+        ghost_transform.set_if_neq(Transform::from_scale(Vec3::splat(cell_size * 0.45)));
+        material.set_if_neq(MeshMaterial2d(assets.material(ghost_color)));
+    }
+    if let Ok((mut cross_transform, mut cross_visibility)) = crosses.single_mut() {
+        cross_transform.set_if_neq(
+            Transform::from_translation(Vec3::Z * 0.1).with_scale(Vec3::splat(cell_size * 0.3)),
+        );
+        cross_visibility.set_if_neq(if point.illegal.is_some() {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        });
+    }
+    if let Ok((mut tooltip_transform, mut text, mut font, mut text_color)) = tooltips.single_mut() {
+        let (x, y) = point.position;
+        let coordinate = CoordinateUtils::board_to_human(x, y, board_size);
+        let (label, color) = match point.illegal {
+            Some(reason) => (
+                format!("{}: {}", coordinate, reason),
+                Color::srgb(0.75, 0.05, 0.05),
+            ),
+            None => (coordinate, Color::srgb(0.10, 0.10, 0.10)),
+        };
+        let font_size = (cell_size * 0.32).clamp(10.0, 28.0);
+
+        tooltip_transform.set_if_neq(Transform::from_translation(Vec3::new(
+            0.0,
+            cell_size * 0.75,
+            0.2,
+        )));
+        if text.0 != label {
+            text.0 = label;
+        }
+        if font.font_size != font_size {
+            font.font_size = font_size;
+        }
+        text_color.set_if_neq(TextColor(color));
+    }
+}
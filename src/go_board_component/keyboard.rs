@@ -0,0 +1,450 @@
+use super::{
+    assets::BoardAssets,
+    components::*,
+    config::GoBoardConfig,
+    events::*,
+    players::ComputerPlayer,
+    resources::{CurrentGoBoardConfig, CurrentTurn, GameHistory},
+    utils::{CoordinateUtils, RenderUtils},
+};
+use bevy::ecs::system::SystemParam;
+use bevy::input::ButtonState;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+/// 输入坐标时最多接受的字符数（列字母加两位行号）
+const MAX_TYPED_LENGTH: usize = 3;
+
+/// 可以绑定按键的棋盘操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BoardAction {
+    CursorUp,
+    CursorDown,
+    CursorLeft,
+    CursorRight,
+    /// 在光标处落子；数子阶段切换光标处棋块的死活；输入坐标时确认
+    Place,
+    Pass,
+    /// 数子阶段确认死子并计分
+    ConfirmScore,
+    PreviousMove,
+    NextMove,
+    FirstMove,
+    LastMove,
+    /// 开始输入坐标（如 "Q16"）
+    TypeCoordinate,
+    /// 放弃正在输入的坐标
+    Cancel,
+}
+
+impl BoardAction {
+    /// 移动光标的操作，按住按键时随系统按键重复连续移动
+    pub(crate) fn cursor_step(self) -> Option<(i32, i32)> {
+        match self {
+            Self::CursorUp => Some((0, -1)),
+            Self::CursorDown => Some((0, 1)),
+            Self::CursorLeft => Some((-1, 0)),
+            Self::CursorRight => Some((1, 0)),
+            _ => None,
+        }
+    }
+}
+
+/// 棋盘操作到按键的绑定，键盘和手柄各有一份，可以整体替换或逐项修改
+///
+/// # 使用示例
+/// ```rust,ignore
+/// let mut bindings = KeyBindings::default();
+/// bindings.set(BoardAction::Pass, [KeyCode::Backspace]);
+/// app.insert_resource(bindings);
+/// ```
+#[derive(Resource, Debug, Clone)]
+pub struct ActionBindings<K> {
+    bindings: HashMap<BoardAction, Vec<K>>,
+}
+
+/// 键盘按键绑定
+pub type KeyBindings = ActionBindings<KeyCode>;
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        use BoardAction::*;
+        Self::from_pairs([
+            (CursorUp, vec![KeyCode::ArrowUp, KeyCode::KeyK]),
+            (CursorDown, vec![KeyCode::ArrowDown, KeyCode::KeyJ]),
+            (CursorLeft, vec![KeyCode::ArrowLeft, KeyCode::KeyH]),
+            (CursorRight, vec![KeyCode::ArrowRight, KeyCode::KeyL]),
+            (
+                Place,
+                vec![KeyCode::Enter, KeyCode::NumpadEnter, KeyCode::Space],
+            ),
+            (Pass, vec![KeyCode::KeyP]),
+            (ConfirmScore, vec![KeyCode::KeyS]),
+            (PreviousMove, vec![KeyCode::Comma, KeyCode::PageUp]),
+            (NextMove, vec![KeyCode::Period, KeyCode::PageDown]),
+            (FirstMove, vec![KeyCode::Home]),
+            (LastMove, vec![KeyCode::End]),
+            (TypeCoordinate, vec![KeyCode::Slash]),
+            (Cancel, vec![KeyCode::Escape]),
+        ])
+    }
+}
+
+impl<K: Copy + PartialEq> ActionBindings<K> {
+    /// 由操作和按键列表构造
+    pub fn from_pairs(pairs: impl IntoIterator<Item = (BoardAction, Vec<K>)>) -> Self {
+        Self {
+            bindings: pairs.into_iter().collect(),
+        }
+    }
+
+    /// 把操作绑定到这些按键，替换原来的绑定
+    pub fn set(&mut self, action: BoardAction, keys: impl IntoIterator<Item = K>) -> &mut Self {
+        self.bindings.insert(action, keys.into_iter().collect());
+        self
+    }
+
+    /// 给操作再绑定一个按键
+    pub fn bind(&mut self, action: BoardAction, key: K) -> &mut Self {
+        let keys = self.bindings.entry(action).or_default();
+        if !keys.contains(&key) {
+            keys.push(key);
+        }
+        self
+    }
+
+    /// 解除按键上的所有操作
+    pub fn unbind(&mut self, key: K) -> &mut Self {
+        for keys in self.bindings.values_mut() {
+            keys.retain(|bound| *bound != key);
+        }
+        self
+    }
+
+    /// 操作绑定的按键
+    pub fn keys(&self, action: BoardAction) -> &[K] {
+        self.bindings.get(&action).map_or(&[], Vec::as_slice)
+    }
+
+    /// 按键触发的操作
+    pub fn actions(&self, key: K) -> impl Iterator<Item = BoardAction> + '_ {
+        self.bindings
+            .iter()
+            .filter(move |(_, keys)| keys.contains(&key))
+            .map(|(action, _)| *action)
+    }
+
+    /// 按键是否绑定了这个操作
+    pub fn is_bound(&self, action: BoardAction, key: K) -> bool {
+        self.keys(action).contains(&key)
+    }
+}
+
+/// 执行棋盘操作时需要的棋盘状态
+pub(crate) struct ActionTarget<'a> {
+    pub board: Entity,
+    pub position: (i32, i32),
+    pub turn: StoneColor,
+    pub history: &'a GameHistory,
+    pub scoring: bool,
+    pub game_over: bool,
+    /// 是否可以替轮到的一方落子或虚手
+    pub may_play: bool,
+}
+
+/// 键盘和手柄操作产生的棋盘事件
+#[derive(SystemParam)]
+pub struct BoardActionEvents<'w> {
+    select: EventWriter<'w, SelectPointEvent>,
+    action: EventWriter<'w, StoneActionEvent>,
+    toggle: EventWriter<'w, ToggleDeadStonesEvent>,
+    confirm: EventWriter<'w, ConfirmScoreEvent>,
+    undo: EventWriter<'w, UndoMoveEvent>,
+    redo: EventWriter<'w, RedoMoveEvent>,
+}
+
+impl BoardActionEvents<'_> {
+    /// 执行光标移动和坐标输入以外的操作
+    pub(crate) fn perform(&mut self, action: BoardAction, target: &ActionTarget) {
+        let ActionTarget {
+            board, position, ..
+        } = *target;
+        let playing = !target.scoring && !target.game_over && target.may_play;
+        let scoring = target.scoring && !target.game_over;
+        match action {
+            BoardAction::Place if scoring => {
+                self.toggle.write(ToggleDeadStonesEvent { board, position });
+            }
+            BoardAction::Place if playing => {
+                self.select.write(SelectPointEvent { board, position });
+            }
+            BoardAction::Pass if playing => {
+                self.action.write(StoneActionEvent {
+                    board,
+                    action_type: StoneActionType::Pass,
+                    position: None,
+                    color: target.turn,
+                });
+            }
+            BoardAction::ConfirmScore if scoring => {
+                self.confirm.write(ConfirmScoreEvent { board });
+            }
+            BoardAction::PreviousMove => {
+                self.undo.write(UndoMoveEvent { board });
+            }
+            BoardAction::NextMove => {
+                self.redo.write(RedoMoveEvent { board });
+            }
+            BoardAction::FirstMove => {
+                let played = target.history.current_index;
+                self.undo
+                    .write_batch((0..played).map(|_| UndoMoveEvent { board }));
+            }
+            BoardAction::LastMove => {
+                let remaining = target.history.moves.len() - target.history.current_index;
+                self.redo
+                    .write_batch((0..remaining).map(|_| RedoMoveEvent { board }));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// 按方向移动光标，停在棋盘边上
+pub(crate) fn step_cursor(cursor: &mut BoardCursor, (dx, dy): (i32, i32), size: i32) {
+    let (x, y) = cursor.position;
+    cursor.position = ((x + dx).clamp(0, size - 1), (y + dy).clamp(0, size - 1));
+}
+
+/// 键盘下棋需要读取的棋盘组件
+type KeyboardBoard<'a> = (
+    Entity,
+    &'a mut BoardCursor,
+    &'a CurrentGoBoardConfig,
+    &'a CurrentTurn,
+    &'a GameHistory,
+    Has<ScoringPhase>,
+    Has<GameOver>,
+);
+
+/// 同步光标需要读取的棋盘组件
+type CursorBoard<'a> = (
+    Entity,
+    Ref<'a, CurrentGoBoardConfig>,
+    Option<&'a mut BoardCursor>,
+);
+
+/// 按 `keyboard_cursor` 给棋盘挂上或移除 [`BoardCursor`]，棋盘变小时把光标移回棋盘内
+pub fn sync_board_cursors(
+    mut commands: Commands,
+    mut boards: Query<CursorBoard, With<GoBoardRoot>>,
+) {
+    for (board, config, cursor) in boards.iter_mut() {
+        if !config.is_changed() {
+            continue;
+        }
+        let size = config.0.board_size.get_value();
+        match (config.0.keyboard_cursor, cursor) {
+            (true, None) => {
+                commands.entity(board).insert(BoardCursor {
+                    position: (size / 2, size / 2),
+                    typed: None,
+                });
+            }
+            (true, Some(mut cursor)) => {
+                let (x, y) = cursor.position;
+                let clamped = (x.min(size - 1), y.min(size - 1));
+                if clamped != cursor.position {
+                    cursor.position = clamped;
+                }
+            }
+            (false, Some(_)) => {
+                commands.entity(board).remove::<BoardCursor>();
+            }
+            (false, None) => {}
+        }
+    }
+}
+
+/// 按 [`KeyBindings`] 处理挂有 [`BoardCursor`] 的棋盘上的键盘操作
+///
+/// 落子发出 [`SelectPointEvent`]，与鼠标点击一样遵循 `confirm_moves`；轮到电脑棋手时不落子也不虚手。
+/// 输入坐标期间按键只用于输入，确认后光标跳到该点并执行落子操作
+pub fn handle_keyboard_play(
+    mut keyboard_events: EventReader<KeyboardInput>,
+    bindings: Res<KeyBindings>,
+    mut boards: Query<KeyboardBoard, With<GoBoardRoot>>,
+    computer_players: Query<&ComputerPlayer>,
+    mut events: BoardActionEvents,
+) {
+    for input in keyboard_events.read() {
+        if input.state != ButtonState::Pressed {
+            continue;
+        }
+
+        for (board, mut cursor, config, current_turn, history, scoring, game_over) in
+            boards.iter_mut()
+        {
+            let board_size = config.0.board_size;
+            let computer_turn = computer_players
+                .iter()
+                .any(|player| player.board == board && player.color == current_turn.0);
+
+            // 输入坐标
+            if let Some(typed) = &cursor.typed {
+                let mut typed = typed.clone();
+                if bindings.is_bound(BoardAction::Cancel, input.key_code) {
+                    cursor.typed = None;
+                } else if bindings.is_bound(BoardAction::Place, input.key_code) {
+                    cursor.typed = None;
+                    if let Some(position) = CoordinateUtils::human_to_board(&typed, board_size) {
+                        cursor.position = position;
+                        events.perform(
+                            BoardAction::Place,
+                            &ActionTarget {
+                                board,
+                                position,
+                                turn: current_turn.0,
+                                history,
+                                scoring,
+                                game_over,
+                                may_play: !computer_turn,
+                            },
+                        );
+                    }
+                } else if input.logical_key == Key::Backspace {
+                    typed.pop();
+                    cursor.typed = Some(typed);
+                } else if let Key::Character(text) = &input.logical_key {
+                    typed.extend(text.chars().filter(char::is_ascii_alphanumeric));
+                    typed.truncate(MAX_TYPED_LENGTH);
+                    if cursor.typed.as_ref() != Some(&typed) {
+                        cursor.typed = Some(typed);
+                    }
+                }
+                continue;
+            }
+
+            for action in bindings.actions(input.key_code) {
+                if let Some(step) = action.cursor_step() {
+                    step_cursor(&mut cursor, step, board_size.get_value());
+                    continue;
+                }
+                // 其余操作不随按键重复触发
+                if input.repeat {
+                    continue;
+                }
+                if action == BoardAction::TypeCoordinate {
+                    cursor.typed = Some(String::new());
+                    continue;
+                }
+
+                events.perform(
+                    action,
+                    &ActionTarget {
+                        board,
+                        position: cursor.position,
+                        turn: current_turn.0,
+                        history,
+                        scoring,
+                        game_over,
+                        may_play: !computer_turn,
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// 同步键盘光标和正在输入的坐标
+///
+/// 整体重绘时标记已随棋盘子实体一起清除，只需重新生成
+pub fn sync_cursor_overlay(
+    mut commands: Commands,
+    mut assets: BoardAssets,
+    mut redraw_events: EventReader<RedrawBoardEvent>,
+    mut removed_cursors: RemovedComponents<BoardCursor>,
+    boards: Query<(Entity, Ref<CurrentGoBoardConfig>), With<GoBoardRoot>>,
+    cursors: Query<Ref<BoardCursor>>,
+    markers: Query<(Entity, &ChildOf), With<CursorMarker>>,
+) {
+    let redraw_targets: HashSet<Entity> = redraw_events.read().map(|event| event.board).collect();
+    let cleared: HashSet<Entity> = removed_cursors.read().collect();
+
+    for (root, config) in boards.iter() {
+        let cursor = cursors.get(root).ok();
+        let redrawn = config.is_changed() || redraw_targets.contains(&root);
+        let moved = cursor.as_ref().is_some_and(|cursor| cursor.is_changed());
+        if !redrawn && !moved && !cleared.contains(&root) {
+            continue;
+        }
+
+        if !redrawn {
+            for (entity, parent) in markers.iter() {
+                if parent.parent() == root {
+                    commands.entity(entity).despawn();
+                }
+            }
+        }
+
+        if let Some(cursor) = cursor {
+            draw_cursor(&mut commands, &mut assets, root, &config.0, &cursor);
+        }
+    }
+}
+
+/// 在光标处画一个方框；输入坐标时在上方显示已输入的内容
+fn draw_cursor(
+    commands: &mut Commands,
+    assets: &mut BoardAssets,
+    root: Entity,
+    config: &GoBoardConfig,
+    cursor: &BoardCursor,
+) {
+    let metrics = RenderUtils::calculate_board_metrics(config.board_extent, config.board_size);
+    let half = metrics.cell_size * 0.5;
+    let thickness = (metrics.cell_size * 0.08).max(2.0);
+    let color = Color::srgb(0.10, 0.45, 0.90);
+    let local_pos =
+        CoordinateUtils::board_to_local(cursor.position, config.board_size, config.board_extent);
+
+    let horizontal = assets.rectangle(half * 2.0, thickness);
+    let vertical = assets.rectangle(thickness, half * 2.0);
+    for (mesh, offset) in [
+        (horizontal.clone(), Vec2::new(0.0, half)),
+        (horizontal, Vec2::new(0.0, -half)),
+        (vertical.clone(), Vec2::new(-half, 0.0)),
+        (vertical, Vec2::new(half, 0.0)),
+    ] {
+        commands.spawn((
+            Mesh2d(mesh),
+            MeshMaterial2d(assets.material(color)),
+            Transform::from_translation(Vec3::new(
+                local_pos.x + offset.x,
+                local_pos.y + offset.y,
+                4.95,
+            )),
+            CursorMarker,
+            ChildOf(root),
+        ));
+    }
+
+    if let Some(typed) = &cursor.typed {
+        commands.spawn((
+            Text2d::new(format!("> {}_", typed)),
+            TextFont {
+                font_size: (metrics.cell_size * 0.4).clamp(12.0, 32.0),
+                ..default()
+            },
+            TextColor(color),
+            Transform::from_translation(Vec3::new(
+                local_pos.x,
+                local_pos.y + metrics.cell_size,
+                4.95,
+            )),
+            CursorMarker,
+            ChildOf(root),
+        ));
+    }
+}
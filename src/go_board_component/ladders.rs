@@ -0,0 +1,191 @@
+use super::{
+    assets::BoardAssets,
+    components::*,
+    config::GoBoardConfig,
+    events::RedrawBoardEvent,
+    resources::{BoardState, CurrentGoBoardConfig, CurrentTurn},
+    utils::{CoordinateUtils, RenderUtils},
+};
+use crate::go_core::{GoBoardRules, LadderPath, TacticalReader};
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+/// 当前局面的征子路线，挂在棋盘根节点上
+///
+/// 打开 `show_ladders` 时随局面更新，关闭时移除
+#[derive(Component)]
+pub struct BoardLadders(pub Vec<LadderPath>);
+
+/// 读出当前局面的征子路线
+///
+/// 被叫吃的棋串按它自己先逃来读，显示能否逃出和引征；
+/// 只有两口气的对方棋串按轮到方先叫吃来读，只保留征得掉的
+pub fn ladder_paths(board_state: &BoardState, to_play: StoneColor) -> Vec<LadderPath> {
+    let reader = TacticalReader::new();
+    let size = board_state.board_size.get_value();
+    let mut seen = HashSet::new();
+    let mut paths = Vec::new();
+
+    for x in 0..size {
+        for y in 0..size {
+            let Some(color) = board_state.get_stone(x, y) else {
+                continue;
+            };
+            if seen.contains(&(x, y)) {
+                continue;
+            }
+            let chain = GoBoardRules::get_group(board_state, x, y);
+            let liberties = GoBoardRules::count_liberties(board_state, &chain);
+            seen.extend(chain);
+
+            let path = match liberties {
+                1 => reader.ladder_path(board_state, (x, y), color),
+                2 if color != to_play => reader
+                    .ladder_path(board_state, (x, y), to_play)
+                    .filter(|path| path.captured),
+                _ => None,
+            };
+            paths.extend(path);
+        }
+    }
+    paths
+}
+
+/// 更新征子路线需要的棋盘组件
+type LadderInputs<'a> = (
+    Entity,
+    Ref<'a, CurrentGoBoardConfig>,
+    Ref<'a, BoardState>,
+    Ref<'a, CurrentTurn>,
+    Has<BoardLadders>,
+);
+
+/// 局面或配置变化时重新读出征子路线
+pub fn update_board_ladders(
+    mut commands: Commands,
+    boards: Query<LadderInputs, With<GoBoardRoot>>,
+) {
+    for (root, config, board_state, current_turn, has_ladders) in boards.iter() {
+        if !config.0.show_ladders {
+            if has_ladders {
+                commands.entity(root).remove::<BoardLadders>();
+            }
+            continue;
+        }
+        if has_ladders
+            && !config.is_changed()
+            && !board_state.is_changed()
+            && !current_turn.is_changed()
+        {
+            continue;
+        }
+
+        let paths = ladder_paths(&board_state, current_turn.0);
+        commands.entity(root).insert(BoardLadders(paths));
+    }
+}
+
+/// 同步征子路线叠加层
+///
+/// 整体重绘时标记已随棋盘子实体一起清除，只需重新生成
+pub fn sync_ladder_overlay(
+    mut commands: Commands,
+    mut assets: BoardAssets,
+    mut redraw_events: EventReader<RedrawBoardEvent>,
+    mut removed_ladders: RemovedComponents<BoardLadders>,
+    boards: Query<(Entity, Ref<CurrentGoBoardConfig>), With<GoBoardRoot>>,
+    ladders: Query<Ref<BoardLadders>>,
+    markers: Query<(Entity, &ChildOf), With<LadderMarker>>,
+) {
+    let redraw_targets: HashSet<Entity> = redraw_events.read().map(|event| event.board).collect();
+    let cleared: HashSet<Entity> = removed_ladders.read().collect();
+
+    for (root, config) in boards.iter() {
+        let board_ladders = ladders.get(root).ok();
+        let redrawn = config.is_changed() || redraw_targets.contains(&root);
+        let ladders_changed = board_ladders
+            .as_ref()
+            .is_some_and(|ladders| ladders.is_changed());
+        if !redrawn && !ladders_changed && !cleared.contains(&root) {
+            continue;
+        }
+
+        if !redrawn {
+            for (entity, parent) in markers.iter() {
+                if parent.parent() == root {
+                    commands.entity(entity).despawn();
+                }
+            }
+        }
+
+        if let Some(board_ladders) = board_ladders
+            && config.0.show_ladders
+        {
+            for path in &board_ladders.0 {
+                draw_ladder(&mut commands, &mut assets, root, &config.0, path);
+            }
+        }
+    }
+}
+
+/// 绘制一条征子路线：半透明的棋子标出顺序，征子成立时最后一手的序号为红色，
+/// 引征的棋子用红色方块标出
+fn draw_ladder(
+    commands: &mut Commands,
+    assets: &mut BoardAssets,
+    root: Entity,
+    config: &GoBoardConfig,
+    path: &LadderPath,
+) {
+    let metrics = RenderUtils::calculate_board_metrics(config.board_extent, config.board_size);
+    let radius = metrics.cell_size * 0.42;
+    let font_size = (metrics.cell_size * 0.36).clamp(8.0, 24.0);
+    let warning = Color::srgb(0.85, 0.10, 0.10);
+
+    for (index, &(color, position)) in path.moves.iter().enumerate() {
+        let (fill, text_color) = match color {
+            StoneColor::Black => (Color::srgba(0.0, 0.0, 0.0, 0.5), Color::WHITE),
+            StoneColor::White => (Color::srgba(1.0, 1.0, 1.0, 0.6), Color::BLACK),
+        };
+        let text_color = if path.captured && index + 1 == path.moves.len() {
+            warning
+        } else {
+            text_color
+        };
+
+        let local_pos =
+            CoordinateUtils::board_to_local(position, config.board_size, config.board_extent);
+        let marker = commands
+            .spawn((
+                Mesh2d(assets.circle(radius)),
+                MeshMaterial2d(assets.material(fill)),
+                Transform::from_translation(Vec3::new(local_pos.x, local_pos.y, 4.4)),
+                LadderMarker,
+                ChildOf(root),
+            ))
+            .id();
+        commands.spawn((
+            Text2d::new((index + 1).to_string()),
+            TextFont {
+                font_size,
+                ..default()
+            },
+            TextColor(text_color),
+            Transform::from_translation(Vec3::new(0.0, 0.0, 0.1)),
+            ChildOf(marker),
+        ));
+    }
+
+    if let Some(breaker) = path.breaker {
+        let side = metrics.cell_size * 0.3;
+        let local_pos =
+            CoordinateUtils::board_to_local(breaker, config.board_size, config.board_extent);
+        commands.spawn((
+            Mesh2d(assets.rectangle(side, side)),
+            MeshMaterial2d(assets.material(warning)),
+            Transform::from_translation(Vec3::new(local_pos.x, local_pos.y, 4.8)),
+            LadderMarker,
+            ChildOf(root),
+        ));
+    }
+}
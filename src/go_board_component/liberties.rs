@@ -0,0 +1,142 @@
+use super::{
+    assets::BoardAssets,
+    components::*,
+    config::GoBoardConfig,
+    events::RedrawBoardEvent,
+    resources::{BoardState, CurrentGoBoardConfig},
+    utils::{CoordinateUtils, RenderUtils},
+};
+use crate::go_core::GoBoardRules;
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+/// 同步气数和叫吃警示叠加层，棋盘状态变化时自动重画
+///
+/// 整体重绘时标记已随棋盘子实体一起清除，只需重新生成
+pub fn sync_liberty_overlay(
+    mut commands: Commands,
+    mut assets: BoardAssets,
+    mut redraw_events: EventReader<RedrawBoardEvent>,
+    boards: Query<(Entity, Ref<CurrentGoBoardConfig>, Ref<BoardState>), With<GoBoardRoot>>,
+    markers: Query<(Entity, &ChildOf), With<LibertyMarker>>,
+) {
+    let redraw_targets: HashSet<Entity> = redraw_events.read().map(|event| event.board).collect();
+
+    for (root, config, board_state) in boards.iter() {
+        let redrawn = config.is_changed() || redraw_targets.contains(&root);
+        if !redrawn && !board_state.is_changed() {
+            continue;
+        }
+
+        if !redrawn {
+            for (entity, parent) in markers.iter() {
+                if parent.parent() == root {
+                    commands.entity(entity).despawn();
+                }
+            }
+        }
+
+        if config.0.show_liberties || config.0.show_atari_warnings {
+            draw_liberties(&mut commands, &mut assets, root, &config.0, &board_state);
+        }
+    }
+}
+
+/// 每个棋串在最靠近中心的棋子右上角画一个气数小圆牌，只剩一口气时圆牌为红色；
+/// 打开叫吃警示时给只剩一口气的棋串的每颗棋子套上红圈
+fn draw_liberties(
+    commands: &mut Commands,
+    assets: &mut BoardAssets,
+    root: Entity,
+    config: &GoBoardConfig,
+    board_state: &BoardState,
+) {
+    let metrics = RenderUtils::calculate_board_metrics(config.board_extent, config.board_size);
+    let badge_radius = metrics.cell_size * 0.2;
+    let badge_offset = metrics.cell_size * 0.3;
+    let font_size = (metrics.cell_size * 0.24).clamp(8.0, 20.0);
+    let warning = Color::srgb(0.85, 0.10, 0.10);
+    let size = board_state.board_size.get_value();
+    let mut seen = HashSet::new();
+
+    for x in 0..size {
+        for y in 0..size {
+            if board_state.get_stone(x, y).is_none() || seen.contains(&(x, y)) {
+                continue;
+            }
+            let chain = GoBoardRules::get_group(board_state, x, y);
+            let liberties = GoBoardRules::count_liberties(board_state, &chain);
+            seen.extend(chain.iter().copied());
+
+            if config.show_atari_warnings && liberties == 1 {
+                let ring = assets.ring(metrics.cell_size * 0.42, metrics.cell_size * 0.5);
+                for &position in &chain {
+                    let local_pos = CoordinateUtils::board_to_local(
+                        position,
+                        config.board_size,
+                        config.board_extent,
+                    );
+                    commands.spawn((
+                        Mesh2d(ring.clone()),
+                        MeshMaterial2d(assets.material(warning)),
+                        Transform::from_translation(Vec3::new(local_pos.x, local_pos.y, 4.85)),
+                        LibertyMarker {
+                            liberties,
+                            atari: true,
+                        },
+                        ChildOf(root),
+                    ));
+                }
+            }
+
+            if config.show_liberties {
+                let count = chain.len() as f32;
+                let center = chain.iter().fold(Vec2::ZERO, |sum, &(cx, cy)| {
+                    sum + Vec2::new(cx as f32, cy as f32)
+                }) / count;
+                let anchor = *chain
+                    .iter()
+                    .min_by(|a, b| {
+                        let da = Vec2::new(a.0 as f32, a.1 as f32).distance_squared(center);
+                        let db = Vec2::new(b.0 as f32, b.1 as f32).distance_squared(center);
+                        da.total_cmp(&db).then(a.cmp(b))
+                    })
+                    .expect("chains are not empty");
+
+                let fill = if liberties == 1 {
+                    warning
+                } else {
+                    Color::srgb(0.15, 0.30, 0.60)
+                };
+                let local_pos =
+                    CoordinateUtils::board_to_local(anchor, config.board_size, config.board_extent);
+                let badge = commands
+                    .spawn((
+                        Mesh2d(assets.circle(badge_radius)),
+                        MeshMaterial2d(assets.material(fill)),
+                        Transform::from_translation(Vec3::new(
+                            local_pos.x + badge_offset,
+                            local_pos.y + badge_offset,
+                            4.9,
+                        )),
+                        LibertyMarker {
+                            liberties,
+                            atari: false,
+                        },
+                        ChildOf(root),
+                    ))
+                    .id();
+                commands.spawn((
+                    Text2d::new(liberties.to_string()),
+                    TextFont {
+                        font_size,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                    Transform::from_translation(Vec3::new(0.0, 0.0, 0.05)),
+                    ChildOf(badge),
+                ));
+            }
+        }
+    }
+}
@@ -1,27 +1,72 @@
 // 围棋棋盘组件 - 高度可复用的独立模块
 // Go Board Component - Highly reusable standalone module
 
+pub mod analysis;
+pub mod assets;
 pub mod components;
 pub mod config;
+pub mod estimate;
 pub mod events;
+pub mod gamepad;
+pub mod hints;
+pub mod hover;
+pub mod keyboard;
+pub mod ladders;
+pub mod liberties;
+pub mod picking;
+pub mod players;
 pub mod plugin;
+pub mod provisional;
+pub mod render;
 pub mod resources;
-pub mod rules;
+pub mod scoring;
 pub mod systems;
+pub mod touch;
+pub mod tournament;
 pub mod utils;
 
+// 规则引擎位于不依赖 Bevy 的核心模块
+pub use crate::go_core::rules;
+
 // Re-export main types for convenience
-pub use components::{Stone, StoneColor};
+pub use analysis::{AnalysisEngine, BoardAnalysis};
+pub use components::{
+    BoardCursor, BoardView, GoBoardBundle, GoBoardRoot, ProvisionalStone, ScoringPhase, Stone,
+    StoneColor, TouchPreview,
+};
 pub use config::{BoardSize, GoBoardConfig};
-pub use events::{PlaceStoneEvent, RedrawBoardEvent, UpdateBoardConfigEvent};
-pub use plugin::GoBoardPlugin;
-pub use resources::{BoardState, CurrentTurn};
+pub use estimate::BoardScoreEstimate;
+pub use events::{
+    BoardTouchEvent, IntersectionClickEvent, IntersectionDragEvent, IntersectionHoverEvent,
+    PlaceStoneEvent, RedrawBoardEvent, SelectPointEvent, UpdateBoardConfigEvent,
+};
+pub use gamepad::{GamepadBindings, GamepadPlayer};
+pub use hints::{HintEngine, MoveHints};
+pub use hover::{BoardHover, HoveredPoint};
+pub use keyboard::{ActionBindings, BoardAction, KeyBindings};
+pub use ladders::BoardLadders;
+pub use picking::{BoardPoint, BoardPointers};
+pub use players::{ComputerPlayer, EngineCommand, PlayerBackend};
+pub use plugin::{
+    AnalysisPlugin, BoardPickingPlugin, ComputerPlayerPlugin, GamepadPlayPlugin,
+    GoBoardLogicPlugin, GoBoardPlugin, GoBoardRenderPlugin, GoBoardSystems, HintPlugin,
+    HoverPlugin, KeyboardPlayPlugin, TouchPlayPlugin, TournamentPlugin,
+};
+pub use resources::{BoardState, CurrentGoBoardConfig, CurrentTurn};
 pub use rules::GoBoardRules;
+pub use touch::BoardTouches;
+pub use tournament::{EndReason, Entrant, Tournament, TournamentConfig, TournamentGame};
 
 // Component prelude for easy importing
 pub mod prelude {
     pub use super::{
-        BoardSize, BoardState, CurrentTurn, GoBoardConfig, GoBoardPlugin, GoBoardRules,
-        PlaceStoneEvent, StoneColor,
+        AnalysisEngine, AnalysisPlugin, BoardAction, BoardAnalysis, BoardCursor, BoardLadders,
+        BoardScoreEstimate, BoardSize, BoardState, BoardTouchEvent, BoardView, ComputerPlayer,
+        ComputerPlayerPlugin, CurrentGoBoardConfig, CurrentTurn, EngineCommand, GamepadPlayer,
+        GoBoardBundle, GoBoardConfig, GoBoardLogicPlugin, GoBoardPlugin, GoBoardRenderPlugin,
+        GoBoardRoot, GoBoardRules, HintEngine, HintPlugin, IntersectionClickEvent,
+        IntersectionDragEvent, IntersectionHoverEvent, KeyBindings, MoveHints, PlaceStoneEvent,
+        PlayerBackend, ProvisionalStone, ScoringPhase, SelectPointEvent, StoneColor, TouchPreview,
+        Tournament, TournamentPlugin,
     };
 }
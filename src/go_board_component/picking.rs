@@ -0,0 +1,238 @@
+use super::{
+    components::GoBoardRoot,
+    events::{IntersectionClickEvent, IntersectionDragEvent, IntersectionHoverEvent},
+    resources::CurrentGoBoardConfig,
+    utils::CoordinateUtils,
+};
+use bevy::ecs::system::SystemParam;
+use bevy::picking::backend::HitData;
+use bevy::picking::pointer::PointerId;
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// 指针所在的棋盘和交叉点
+pub type BoardPoint = (Entity, (i32, i32));
+
+/// 换算交叉点需要读取的棋盘组件
+type PickedBoard<'a> = (&'a GlobalTransform, &'a CurrentGoBoardConfig);
+
+/// 每个指针当前悬停和正在拖动的交叉点
+#[derive(Resource, Default, Debug)]
+pub struct BoardPointers {
+    hovered: HashMap<PointerId, BoardPoint>,
+    drags: HashMap<(PointerId, PointerButton), DragState>,
+}
+
+/// 一次拖动的起点和当前经过的交叉点
+#[derive(Debug, Clone, Copy)]
+struct DragState {
+    board: Entity,
+    start: (i32, i32),
+    position: (i32, i32),
+}
+
+impl BoardPointers {
+    /// 指针悬停的棋盘和交叉点，不在任何棋盘的网格上时为 `None`
+    pub fn hovered(&self, pointer: PointerId) -> Option<BoardPoint> {
+        self.hovered.get(&pointer).copied()
+    }
+}
+
+/// bevy_picking 发出的指针事件
+#[derive(SystemParam)]
+pub struct PointerEvents<'w, 's> {
+    over: EventReader<'w, 's, Pointer<Over>>,
+    moves: EventReader<'w, 's, Pointer<Move>>,
+    out: EventReader<'w, 's, Pointer<Out>>,
+    cancels: EventReader<'w, 's, Pointer<Cancel>>,
+    clicks: EventReader<'w, 's, Pointer<Click>>,
+    drag_starts: EventReader<'w, 's, Pointer<DragStart>>,
+    drag_ends: EventReader<'w, 's, Pointer<DragEnd>>,
+}
+
+/// 换算后的交叉点事件
+#[derive(SystemParam)]
+pub struct IntersectionEvents<'w> {
+    hover: EventWriter<'w, IntersectionHoverEvent>,
+    click: EventWriter<'w, IntersectionClickEvent>,
+    drag: EventWriter<'w, IntersectionDragEvent>,
+}
+
+/// 把 bevy_picking 的指针事件换算成棋盘交叉点事件
+///
+/// 命中点来自拾取后端，和相机、视口、棋盘位置无关；命中棋子或叠加层时沿父实体找到棋盘根节点。
+/// 拖过不同交叉点后松开不算点击；指针被取消（如触摸中断）时清除悬停并结束拖动
+pub fn pick_board_intersections(
+    mut pointers: ResMut<BoardPointers>,
+    mut input: PointerEvents,
+    mut output: IntersectionEvents,
+    parents: Query<&ChildOf>,
+    boards: Query<PickedBoard, With<GoBoardRoot>>,
+) {
+    let locate = |target: Entity, hit: &HitData| locate(target, hit, &parents, &boards);
+    let previous = pointers.hovered.clone();
+
+    // 先处理离开，同一帧换到另一个实体时再由 Over / Move 写入新的交叉点
+    for event in input.out.read() {
+        pointers.hovered.remove(&event.pointer_id);
+    }
+    // 取消后这个指针不会再有 Out 和 DragEnd
+    for event in input.cancels.read() {
+        let pointer = event.pointer_id;
+        pointers.hovered.remove(&pointer);
+        let buttons: Vec<_> = pointers
+            .drags
+            .keys()
+            .filter(|(drag_pointer, _)| *drag_pointer == pointer)
+            .map(|&(_, button)| button)
+            .collect();
+        for button in buttons {
+            let Some(drag) = pointers.drags.remove(&(pointer, button)) else {
+                continue;
+            };
+            output
+                .drag
+                .write(drag_event(pointer, button, drag, true, true));
+        }
+    }
+    let entered = input
+        .over
+        .read()
+        .map(|event| (event.pointer_id, locate(event.target, &event.hit)));
+    let moved = input
+        .moves
+        .read()
+        .map(|event| (event.pointer_id, locate(event.target, &event.hit)));
+    for (pointer, point) in entered.chain(moved).collect::<Vec<_>>() {
+        match point {
+            Some(point) => pointers.hovered.insert(pointer, point),
+            None => pointers.hovered.remove(&pointer),
+        };
+    }
+
+    for (&pointer, &(board, position)) in &pointers.hovered {
+        if previous.get(&pointer) != Some(&(board, position)) {
+            output.hover.write(IntersectionHoverEvent {
+                board,
+                pointer,
+                position: Some(position),
+            });
+        }
+    }
+    for (&pointer, &(board, _)) in &previous {
+        if pointers
+            .hovered
+            .get(&pointer)
+            .map_or(true, |(current, _)| *current != board)
+        {
+            output.hover.write(IntersectionHoverEvent {
+                board,
+                pointer,
+                position: None,
+            });
+        }
+    }
+
+    for event in input.drag_starts.read() {
+        let Some((board, start)) = locate(event.target, &event.hit) else {
+            continue;
+        };
+        let drag = DragState {
+            board,
+            start,
+            position: start,
+        };
+        pointers
+            .drags
+            .insert((event.pointer_id, event.button), drag);
+        output.drag.write(drag_event(
+            event.pointer_id,
+            event.button,
+            drag,
+            false,
+            false,
+        ));
+    }
+
+    // 拖动中经过的交叉点来自悬停，离开棋盘时保留最后经过的点
+    let BoardPointers { hovered, drags } = &mut *pointers;
+    for (&(pointer, button), drag) in drags.iter_mut() {
+        let Some(&(board, position)) = hovered.get(&pointer) else {
+            continue;
+        };
+        if board == drag.board && position != drag.position {
+            drag.position = position;
+            output
+                .drag
+                .write(drag_event(pointer, button, *drag, false, false));
+        }
+    }
+
+    for event in input.clicks.read() {
+        let Some((board, position)) = locate(event.target, &event.hit) else {
+            continue;
+        };
+        let dragged_away = pointers
+            .drags
+            .get(&(event.pointer_id, event.button))
+            .is_some_and(|drag| drag.start != drag.position);
+        if !dragged_away {
+            output.click.write(IntersectionClickEvent {
+                board,
+                pointer: event.pointer_id,
+                button: event.button,
+                position,
+            });
+        }
+    }
+
+    for event in input.drag_ends.read() {
+        if let Some(drag) = pointers.drags.remove(&(event.pointer_id, event.button)) {
+            output.drag.write(drag_event(
+                event.pointer_id,
+                event.button,
+                drag,
+                true,
+                false,
+            ));
+        }
+    }
+}
+
+/// 命中点所在的棋盘交叉点，命中的实体不属于棋盘或命中点在网格外时为 `None`
+fn locate(
+    target: Entity,
+    hit: &HitData,
+    parents: &Query<&ChildOf>,
+    boards: &Query<PickedBoard, With<GoBoardRoot>>,
+) -> Option<BoardPoint> {
+    let board = std::iter::once(target)
+        .chain(parents.iter_ancestors(target))
+        .find(|entity| boards.contains(*entity))?;
+    let (root_transform, config) = boards.get(board).ok()?;
+    let position = CoordinateUtils::world_to_board(
+        hit.position?.truncate(),
+        root_transform,
+        config.0.board_size,
+        config.0.board_extent,
+    )?;
+    Some((board, position))
+}
+
+fn drag_event(
+    pointer: PointerId,
+    button: PointerButton,
+    drag: DragState,
+    finished: bool,
+    cancelled: bool,
+) -> IntersectionDragEvent {
+    IntersectionDragEvent {
+        board: drag.board,
+        pointer,
+        button,
+        start: drag.start,
+        position: drag.position,
+        finished,
+        cancelled,
+    }
+}
@@ -0,0 +1,388 @@
+use super::{
+    components::*,
+    events::{PlaceStoneEvent, StoneActionEvent, StoneActionType},
+    resources::{BoardState, CurrentGoBoardConfig, CurrentTurn, GameHistory},
+    systems::check_move,
+};
+#[cfg(feature = "nn")]
+use crate::go_core::OnnxPolicy;
+use crate::go_core::{
+    BoardSize, BotLevel, GeneratedMove, GtpClient, GtpError, HeuristicBot, MctsBot, MctsConfig,
+    MoveGenerator, SearchBudget,
+};
+use bevy::ecs::query::QueryData;
+use bevy::prelude::*;
+use std::io;
+#[cfg(feature = "nn")]
+use std::path::PathBuf;
+use std::sync::{Mutex, mpsc};
+use std::thread;
+use std::time::Duration;
+
+/// 外部引擎（GTP 引擎或 KataGo 分析引擎）的启动命令
+#[derive(Debug, Clone)]
+pub struct EngineCommand {
+    pub program: String,
+    pub args: Vec<String>,
+}
+
+impl EngineCommand {
+    pub fn new(program: impl Into<String>) -> Self {
+        Self {
+            program: program.into(),
+            args: Vec::new(),
+        }
+    }
+
+    pub fn arg(mut self, arg: impl Into<String>) -> Self {
+        self.args.push(arg.into());
+        self
+    }
+
+    /// 按空白拆分命令行，如 `"gnugo --mode gtp"`
+    pub fn parse(command_line: &str) -> Option<Self> {
+        let mut parts = command_line.split_whitespace();
+        let program = parts.next()?;
+        Some(Self {
+            program: program.to_string(),
+            args: parts.map(str::to_string).collect(),
+        })
+    }
+}
+
+/// 电脑棋手的后端
+#[derive(Debug, Clone)]
+pub enum PlayerBackend {
+    /// 外部 GTP 引擎进程
+    Gtp(EngineCommand),
+    /// 内置 MCTS 棋手，不需要外部引擎
+    Mcts(MctsConfig),
+    /// 内置入门棋手，同一种子下着法固定
+    Heuristic { level: BotLevel, seed: u64 },
+    /// 由 ONNX 策略网络引导的内置 MCTS 棋手，模型按 `board_size` 加载
+    #[cfg(feature = "nn")]
+    PolicyMcts {
+        config: MctsConfig,
+        model: PathBuf,
+        board_size: BoardSize,
+    },
+}
+
+impl PlayerBackend {
+    /// 把内置 MCTS 棋手的搜索预算改为每手固定时间，其他后端不变
+    ///
+    /// GTP 引擎的时间用 [`ComputerPlayer::with_move_time`] 设置
+    pub fn with_move_time(mut self, move_time: Duration) -> Self {
+        match &mut self {
+            PlayerBackend::Mcts(config) => config.budget = SearchBudget::Time(move_time),
+            #[cfg(feature = "nn")]
+            PlayerBackend::PolicyMcts { config, .. } => {
+                config.budget = SearchBudget::Time(move_time)
+            }
+            PlayerBackend::Gtp(_) | PlayerBackend::Heuristic { .. } => {}
+        }
+        self
+    }
+}
+
+/// 已下的着法（颜色和位置，虚手为 `None`）
+type PlayedMoves = Vec<(StoneColor, Option<(i32, i32)>)>;
+
+/// 发给后台线程的思考请求
+struct ThinkRequest {
+    board_size: BoardSize,
+    komi: f32,
+    moves: PlayedMoves,
+    /// 当前局面，内置棋手直接在上面思考
+    board_state: BoardState,
+    /// 到当前局面为止的着法，内置棋手用于策略网络的历史平面
+    history: GameHistory,
+    color: StoneColor,
+    /// 每手时间，GTP 引擎通过 `time_settings` 设置
+    move_time: Option<Duration>,
+}
+
+/// 电脑棋手
+///
+/// 单独生成一个实体，通过 `board` 指向它控制的棋盘根节点；
+/// 轮到它时把局面发给后台线程思考，结果以落子、虚手或认输事件送回，画面不会卡住
+///
+/// # 使用示例
+/// ```rust,ignore
+/// let command = EngineCommand::new("gnugo").arg("--mode").arg("gtp");
+/// let player = ComputerPlayer::spawn(board, StoneColor::White, &PlayerBackend::Gtp(command))?;
+/// commands.spawn(player);
+/// ```
+#[derive(Component)]
+pub struct ComputerPlayer {
+    pub board: Entity,
+    pub color: StoneColor,
+    requests: mpsc::Sender<ThinkRequest>,
+    replies: Mutex<mpsc::Receiver<Result<GeneratedMove, String>>>,
+    /// 正在思考的局面，没有请求时为 `None`
+    pending: Option<PlayedMoves>,
+    /// 后端出错后不再请求
+    failed: bool,
+    move_time: Option<Duration>,
+}
+
+impl ComputerPlayer {
+    /// 启动后端和后台线程
+    pub fn spawn(board: Entity, color: StoneColor, backend: &PlayerBackend) -> io::Result<Self> {
+        let (request_sender, request_receiver) = mpsc::channel();
+        let (reply_sender, reply_receiver) = mpsc::channel();
+
+        match backend {
+            PlayerBackend::Gtp(command) => {
+                let client = GtpClient::spawn(&command.program, &command.args)?;
+                thread::Builder::new()
+                    .name(format!("gtp-{}", command.program))
+                    .spawn(move || run_gtp_worker(client, request_receiver, reply_sender))?;
+            }
+            PlayerBackend::Mcts(config) => {
+                let generator = Box::new(MctsBot::new(config.clone()));
+                spawn_generator_worker(generator, request_receiver, reply_sender)?;
+            }
+            PlayerBackend::Heuristic { level, seed } => {
+                let generator = Box::new(HeuristicBot::new(*level, *seed));
+                spawn_generator_worker(generator, request_receiver, reply_sender)?;
+            }
+            #[cfg(feature = "nn")]
+            PlayerBackend::PolicyMcts {
+                config,
+                model,
+                board_size,
+            } => {
+                let network = OnnxPolicy::load(model, *board_size)
+                    .map_err(|error| io::Error::new(io::ErrorKind::Other, error))?;
+                let generator =
+                    Box::new(MctsBot::new(config.clone()).with_policy(Box::new(network)));
+                spawn_generator_worker(generator, request_receiver, reply_sender)?;
+            }
+        }
+
+        Ok(Self {
+            board,
+            color,
+            requests: request_sender,
+            replies: Mutex::new(reply_receiver),
+            pending: None,
+            failed: false,
+            move_time: None,
+        })
+    }
+
+    /// 让 GTP 引擎按每手 `move_time` 思考（读秒，无基本用时）
+    pub fn with_move_time(mut self, move_time: Duration) -> Self {
+        self.move_time = Some(move_time);
+        self
+    }
+
+    /// 是否正在思考
+    pub fn is_thinking(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// 后端是否已经出错停止
+    pub fn has_failed(&self) -> bool {
+        self.failed
+    }
+}
+
+/// 电脑棋手需要读取的棋盘组件
+#[derive(QueryData)]
+pub struct BoardView {
+    config: &'static CurrentGoBoardConfig,
+    board_state: &'static BoardState,
+    current_turn: &'static CurrentTurn,
+    history: &'static GameHistory,
+    game_over: Has<GameOver>,
+    scoring: Has<ScoringPhase>,
+}
+
+/// 驱动电脑棋手：轮到它时发出请求，收到结果后转换为棋盘事件
+///
+/// 请求发出后局面发生变化（撤销、清空、对手抢先落子）的结果会被丢弃
+pub fn drive_computer_players(
+    mut players: Query<&mut ComputerPlayer>,
+    boards: Query<BoardView, With<GoBoardRoot>>,
+    mut stone_events: EventWriter<PlaceStoneEvent>,
+    mut action_events: EventWriter<StoneActionEvent>,
+) {
+    for mut player in players.iter_mut() {
+        let Ok(view) = boards.get(player.board) else {
+            continue;
+        };
+
+        let moves: PlayedMoves = view
+            .history
+            .played_moves()
+            .iter()
+            .map(|played| (played.color, played.position))
+            .collect();
+        let to_move = view.current_turn.0 == player.color && !view.game_over && !view.scoring;
+
+        let stale = player
+            .pending
+            .as_ref()
+            .map(|requested| *requested != moves || !to_move);
+        if let Some(stale) = stale {
+            let reply = match player.replies.get_mut() {
+                Ok(replies) => match replies.try_recv() {
+                    Ok(reply) => reply,
+                    Err(mpsc::TryRecvError::Empty) => continue,
+                    Err(mpsc::TryRecvError::Disconnected) => {
+                        Err("engine thread stopped".to_string())
+                    }
+                },
+                Err(_) => Err("engine thread panicked".to_string()),
+            };
+            player.pending = None;
+
+            let (board, color) = (player.board, player.color);
+            match reply {
+                Err(message) => {
+                    error!("Computer player {:?} stopped: {}", color, message);
+                    player.failed = true;
+                }
+                // 局面已变化，丢弃
+                Ok(_) if stale => {}
+                Ok(GeneratedMove::Play(x, y))
+                    if check_move(&view.config.0, view.board_state, view.history, x, y, color)
+                        .is_ok() =>
+                {
+                    stone_events.write(PlaceStoneEvent {
+                        board,
+                        position: (x, y),
+                        color,
+                    });
+                }
+                Ok(generated) => {
+                    let action_type = match generated {
+                        GeneratedMove::Resign => StoneActionType::Resign,
+                        GeneratedMove::Pass => StoneActionType::Pass,
+                        GeneratedMove::Play(x, y) => {
+                            warn!(
+                                "Computer player {:?} chose illegal move {:?}, passing",
+                                color,
+                                (x, y)
+                            );
+                            StoneActionType::Pass
+                        }
+                    };
+                    action_events.write(StoneActionEvent {
+                        board,
+                        action_type,
+                        position: None,
+                        color,
+                    });
+                }
+            }
+            continue;
+        }
+
+        if !to_move || player.failed {
+            continue;
+        }
+
+        let request = ThinkRequest {
+            board_size: view.config.0.board_size,
+            komi: view.config.0.komi,
+            moves: moves.clone(),
+            board_state: view.board_state.clone(),
+            history: view.history.clone(),
+            color: player.color,
+            move_time: player.move_time,
+        };
+        if player.requests.send(request).is_err() {
+            error!(
+                "Computer player {:?} stopped: engine thread stopped",
+                player.color
+            );
+            player.failed = true;
+            continue;
+        }
+        player.pending = Some(moves);
+    }
+}
+
+/// 内置棋手后台线程：直接在请求的局面上生成一手棋
+fn spawn_generator_worker(
+    mut generator: Box<dyn MoveGenerator>,
+    requests: mpsc::Receiver<ThinkRequest>,
+    replies: mpsc::Sender<Result<GeneratedMove, String>>,
+) -> io::Result<()> {
+    thread::Builder::new()
+        .name(generator.name().to_string())
+        .spawn(move || {
+            for request in requests {
+                let generated = generator.generate_move(
+                    &request.board_state,
+                    &request.history,
+                    request.color,
+                    request.komi,
+                );
+                if replies.send(Ok(generated)).is_err() {
+                    break;
+                }
+            }
+        })?;
+    Ok(())
+}
+
+/// GTP 后台线程：增量同步棋盘后请求 `genmove`，棋手组件移除后退出并关闭引擎
+fn run_gtp_worker(
+    mut client: GtpClient,
+    requests: mpsc::Receiver<ThinkRequest>,
+    replies: mpsc::Sender<Result<GeneratedMove, String>>,
+) {
+    let mut setup = None;
+    let mut sent = PlayedMoves::new();
+
+    for request in requests {
+        let reply = think_with_gtp(&mut client, &mut setup, &mut sent, &request)
+            .map_err(|error| error.to_string());
+        if replies.send(reply).is_err() {
+            break;
+        }
+    }
+}
+
+/// 把引擎的棋盘同步到请求的局面并生成一手棋
+///
+/// 新局面是已发送着法的延续时只补发新着法，否则清空棋盘重新发送
+fn think_with_gtp(
+    client: &mut GtpClient,
+    setup: &mut Option<(BoardSize, f32, Option<Duration>)>,
+    sent: &mut PlayedMoves,
+    request: &ThinkRequest,
+) -> Result<GeneratedMove, GtpError> {
+    let board_size = request.board_size;
+    let settings = (board_size, request.komi, request.move_time);
+    if *setup != Some(settings) || !request.moves.starts_with(sent) {
+        client.send(&format!("boardsize {}", board_size.get_value()))?;
+        client.send("clear_board")?;
+        client.send(&format!("komi {}", request.komi))?;
+        if let Some(move_time) = request.move_time {
+            // 不支持计时的引擎会返回错误，忽略即可
+            let seconds = move_time.as_secs().max(1);
+            let _ = client.send(&format!("time_settings 0 {} 1", seconds));
+        }
+        *setup = Some(settings);
+        sent.clear();
+    }
+
+    for &(color, position) in &request.moves[sent.len()..] {
+        client.play(color, position, board_size)?;
+        sent.push((color, position));
+    }
+
+    let generated = client.genmove(request.color, board_size)?;
+    // 引擎已经在自己的棋盘上落下这一手（虚手也算）
+    match generated {
+        GeneratedMove::Play(x, y) => sent.push((request.color, Some((x, y)))),
+        GeneratedMove::Pass => sent.push((request.color, None)),
+        GeneratedMove::Resign => {}
+    }
+    Ok(generated)
+}
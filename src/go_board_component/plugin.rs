@@ -1,12 +1,47 @@
-use super::{components::StoneColor, config::GoBoardConfig, events::*, resources::*, systems::*};
+use super::{
+    analysis::*,
+    assets::GoBoardAssets,
+    components::{GoBoardBundle, StoneColor},
+    config::GoBoardConfig,
+    estimate::*,
+    events::*,
+    gamepad::*,
+    hints::*,
+    hover::*,
+    keyboard::*,
+    ladders::*,
+    liberties::*,
+    picking::*,
+    players::*,
+    provisional::*,
+    render::*,
+    scoring::*,
+    systems::*,
+    touch::*,
+    tournament::*,
+};
+#[cfg(feature = "nn")]
+use crate::go_core::OnnxPolicy;
+use crate::go_core::{BotLevel, MctsConfig};
 use bevy::prelude::*;
+#[cfg(feature = "nn")]
+use std::path::PathBuf;
 
-/// 围棋棋盘插件
+/// 棋盘系统集，渲染总在逻辑之后运行
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GoBoardSystems {
+    /// 规则、棋盘状态和历史
+    Logic,
+    /// 根据棋盘状态同步画面
+    Render,
+}
+
+/// 围棋棋盘插件（逻辑 + 渲染）
 ///
 /// # 使用示例
-/// ```rust
+/// ```rust,no_run
 /// use bevy::prelude::*;
-/// use go_board_component::prelude::*;
+/// use black_white_legends::prelude::*;
 ///
 /// fn main() {
 ///     App::new()
@@ -15,34 +50,106 @@ use bevy::prelude::*;
 ///         .run();
 /// }
 /// ```
+#[derive(Default)]
 pub struct GoBoardPlugin {
     pub initial_config: GoBoardConfig,
+    /// 初始棋盘上由电脑控制的一方
+    pub computer_players: Vec<(StoneColor, PlayerBackend)>,
+    /// 分析初始棋盘的 KataGo 分析引擎
+    pub analysis_engine: Option<EngineCommand>,
+    /// 为初始棋盘提供落子提示的 ONNX 策略网络
+    #[cfg(feature = "nn")]
+    pub policy_model: Option<PathBuf>,
 }
 
-impl Default for GoBoardPlugin {
-    fn default() -> Self {
-        Self {
-            initial_config: GoBoardConfig::default(),
-        }
+impl Plugin for GoBoardPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((
+            GoBoardLogicPlugin,
+            GoBoardRenderPlugin,
+            ComputerPlayerPlugin,
+            AnalysisPlugin,
+            HintPlugin,
+            BoardPickingPlugin,
+            HoverPlugin,
+            KeyboardPlayPlugin,
+            GamepadPlayPlugin,
+            TouchPlayPlugin,
+        ));
+
+        // 生成初始棋盘，更多棋盘可以用 GoBoardBundle 自行生成
+        let initial_config = self.initial_config.clone();
+        let computer_players = self.computer_players.clone();
+        let analysis_engine = self.analysis_engine.clone();
+        #[cfg(feature = "nn")]
+        let policy_model = self.policy_model.clone();
+        app.add_systems(Startup, move |mut commands: Commands| {
+            let board = commands
+                .spawn(GoBoardBundle::new(initial_config.clone()))
+                .id();
+
+            for (color, backend) in &computer_players {
+                match ComputerPlayer::spawn(board, *color, backend) {
+                    Ok(player) => {
+                        commands.spawn(player);
+                    }
+                    Err(error) => error!("Failed to start computer player {:?}: {}", color, error),
+                }
+            }
+
+            if let Some(command) = &analysis_engine {
+                match AnalysisEngine::spawn(board, command) {
+                    Ok(engine) => {
+                        commands.spawn(engine);
+                    }
+                    Err(error) => error!("Failed to start analysis engine: {}", error),
+                }
+            }
+
+            #[cfg(feature = "nn")]
+            if let Some(path) = &policy_model {
+                let engine = OnnxPolicy::load(path, initial_config.board_size)
+                    .map_err(|error| error.to_string())
+                    .and_then(|network| {
+                        HintEngine::spawn(board, Box::new(network))
+                            .map_err(|error| error.to_string())
+                    });
+                match engine {
+                    Ok(engine) => {
+                        commands.spawn(engine);
+                    }
+                    Err(error) => error!("Failed to start policy network: {}", error),
+                }
+            }
+        });
     }
 }
 
-impl Plugin for GoBoardPlugin {
-    fn build(&self, app: &mut App) {
-        // 添加资源
-        app.insert_resource(CurrentGoBoardConfig(self.initial_config.clone()))
-            .insert_resource(CurrentTurn(StoneColor::Black))
-            .insert_resource(BoardState::new(self.initial_config.board_size))
-            .insert_resource(GameHistory::default());
+/// 围棋逻辑插件
+///
+/// 只包含规则、棋盘状态、历史和事件，不需要窗口和渲染器，
+/// 可以在服务器、`MinimalPlugins` 测试或机器人进程中使用
+///
+/// # 使用示例
+/// ```rust,ignore
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, GoBoardLogicPlugin));
+/// let board = app.world_mut().spawn(GoBoardBundle::new(GoBoardConfig::default())).id();
+/// ```
+pub struct GoBoardLogicPlugin;
 
+impl Plugin for GoBoardLogicPlugin {
+    fn build(&self, app: &mut App) {
         // 添加事件
-        app.add_event::<RedrawBoardEvent>()
-            .add_event::<UpdateBoardConfigEvent>()
+        app.add_event::<UpdateBoardConfigEvent>()
+            .add_event::<SelectPointEvent>()
             .add_event::<PlaceStoneEvent>()
             .add_event::<StoneActionEvent>()
             .add_event::<ClearBoardEvent>()
             .add_event::<UndoMoveEvent>()
             .add_event::<RedoMoveEvent>()
+            .add_event::<ToggleDeadStonesEvent>()
+            .add_event::<ConfirmScoreEvent>()
             .add_event::<GameEndEvent>()
             .add_event::<LoadGameEvent>()
             .add_event::<SaveGameEvent>();
@@ -52,25 +159,225 @@ impl Plugin for GoBoardPlugin {
             Update,
             (
                 handle_config_update,
+                handle_select_point,
                 handle_place_stone,
-                handle_board_redraw,
+                handle_stone_action,
+                handle_scoring,
+                handle_undo_move,
+                handle_redo_move,
                 handle_clear_board,
+                update_board_ladders,
+                update_score_estimate,
+                discard_stale_provisional_stones,
+            )
+                .chain()
+                .in_set(GoBoardSystems::Logic),
+        );
+    }
+}
+
+/// 围棋渲染插件
+///
+/// 根据棋盘根节点上的状态绘制棋盘和棋子，需要和 [`GoBoardLogicPlugin`] 一起使用
+pub struct GoBoardRenderPlugin;
+
+impl Plugin for GoBoardRenderPlugin {
+    fn build(&self, app: &mut App) {
+        // 添加资源
+        app.init_resource::<GoBoardAssets>();
+
+        // 添加事件
+        app.add_event::<RedrawBoardEvent>();
+
+        // 添加系统
+        app.configure_sets(Update, GoBoardSystems::Render.after(GoBoardSystems::Logic));
+        app.add_systems(
+            Update,
+            (
+                sync_board_transform,
+                fit_boards_to_window,
+                sync_board_visuals,
+                sync_analysis_overlay,
+                sync_hint_overlay,
+                sync_ladder_overlay,
+                sync_dead_stone_overlay,
+                sync_estimate_overlay,
+                sync_liberty_overlay,
+                sync_provisional_overlay,
+                sync_cursor_overlay,
+                sync_touch_preview_overlay,
+                prune_board_assets,
             )
-                .chain(),
+                .chain()
+                .in_set(GoBoardSystems::Render),
+        );
+    }
+}
+
+/// 电脑棋手插件
+///
+/// 驱动所有 [`ComputerPlayer`] 实体，在逻辑系统之前把它们的着法作为事件送出
+pub struct ComputerPlayerPlugin;
+
+impl Plugin for ComputerPlayerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, drive_computer_players.before(GoBoardSystems::Logic));
+    }
+}
+
+/// 分析插件
+///
+/// 驱动所有 [`AnalysisEngine`] 实体，在逻辑系统之后按最新局面发送查询
+pub struct AnalysisPlugin;
+
+impl Plugin for AnalysisPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, drive_analysis_engines.after(GoBoardSystems::Logic));
+    }
+}
+
+/// 落子提示插件
+///
+/// 驱动所有 [`HintEngine`] 实体，在逻辑系统之后按最新局面请求策略网络
+pub struct HintPlugin;
+
+impl Plugin for HintPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, drive_hint_engines.after(GoBoardSystems::Logic));
+    }
+}
+
+/// 交叉点拾取插件
+///
+/// 通过 bevy_picking 的网格拾取后端发出 [`IntersectionHoverEvent`]、[`IntersectionClickEvent`]
+/// 和 [`IntersectionDragEvent`]，适用于任意相机、视口和 UI 层级；需要 `DefaultPlugins` 中的 `PickingPlugin`
+pub struct BoardPickingPlugin;
+
+impl Plugin for BoardPickingPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<MeshPickingPlugin>() {
+            app.add_plugins(MeshPickingPlugin);
+        }
+
+        app.init_resource::<BoardPointers>()
+            .add_event::<IntersectionHoverEvent>()
+            .add_event::<IntersectionClickEvent>()
+            .add_event::<IntersectionDragEvent>()
+            .add_systems(
+                Update,
+                pick_board_intersections.before(GoBoardSystems::Logic),
+            );
+    }
+}
+
+/// 键盘下棋插件
+///
+/// 开启 `keyboard_cursor` 的棋盘挂上 [`BoardCursor`]，按 [`KeyBindings`] 移动光标、落子、虚手、
+/// 切换手数和输入坐标；需要 `DefaultPlugins` 中的 `InputPlugin`
+pub struct KeyboardPlayPlugin;
+
+impl Plugin for KeyboardPlayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<KeyBindings>().add_systems(
+            Update,
+            (sync_board_cursors, handle_keyboard_play)
+                .chain()
+                .before(GoBoardSystems::Logic),
+        );
+    }
+}
+
+/// 手柄下棋插件
+///
+/// 已连接的手柄自动分配到挂有 [`BoardCursor`] 的棋盘并记为 [`GamepadPlayer`]，按 [`GamepadBindings`]
+/// 移动光标、落子、虚手和切换手数；需要 `DefaultPlugins` 中的 `GilrsPlugin`
+pub struct GamepadPlayPlugin;
+
+impl Plugin for GamepadPlayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GamepadBindings>().add_systems(
+            Update,
+            (assign_gamepads, handle_gamepad_play)
+                .chain()
+                .after(sync_board_cursors)
+                .before(GoBoardSystems::Logic),
+        );
+    }
+}
+
+/// 触控下棋插件
+///
+/// 把 `TouchInput` 经相机换算成世界坐标的 [`BoardTouchEvent`]；开启 `touch_controls` 的棋盘挂上
+/// [`BoardView`]，单指轻点落子（按住时显示 [`TouchPreview`]），双指缩放和平移，双击恢复视图
+pub struct TouchPlayPlugin;
+
+impl Plugin for TouchPlayPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<BoardTouchEvent>()
+            .init_resource::<BoardTouches>()
+            .add_systems(
+                Update,
+                (touch_input_to_board, sync_board_views, handle_board_touches)
+                    .chain()
+                    .before(GoBoardSystems::Logic),
+            );
+    }
+}
+
+/// 悬停插件
+///
+/// 维护 [`BoardHover`] 和唯一的悬停指示器，需要 [`BoardPickingPlugin`] 和 [`GoBoardRenderPlugin`]
+pub struct HoverPlugin;
+
+impl Plugin for HoverPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<BoardPickingPlugin>() {
+            app.add_plugins(BoardPickingPlugin);
+        }
+
+        app.init_resource::<BoardHover>()
+            .add_systems(Startup, spawn_hover_indicator)
+            .add_systems(
+                Update,
+                (update_board_hover, sync_hover_indicator)
+                    .chain()
+                    .in_set(GoBoardSystems::Render),
+            );
+    }
+}
+
+/// 比赛插件
+///
+/// 插入 [`Tournament`] 资源后按赛程运行对局，需要同时添加 [`ComputerPlayerPlugin`]
+pub struct TournamentPlugin;
+
+impl Plugin for TournamentPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                (start_tournament_games, enforce_tournament_limits)
+                    .chain()
+                    .before(GoBoardSystems::Logic),
+                record_tournament_games.after(GoBoardSystems::Logic),
+            ),
         );
     }
 }
 
 /// 插件扩展构建器
+#[derive(Default)]
 pub struct GoBoardPluginBuilder {
     config: GoBoardConfig,
+    computer_players: Vec<(StoneColor, PlayerBackend)>,
+    analysis_engine: Option<EngineCommand>,
+    #[cfg(feature = "nn")]
+    policy_model: Option<PathBuf>,
 }
 
 impl GoBoardPluginBuilder {
     pub fn new() -> Self {
-        Self {
-            config: GoBoardConfig::default(),
-        }
+        Self::default()
     }
 
     pub fn with_board_size(mut self, size: super::config::BoardSize) -> Self {
@@ -88,6 +395,22 @@ impl GoBoardPluginBuilder {
         self
     }
 
+    pub fn with_board_position(mut self, position: Vec2) -> Self {
+        self.config.board_position = position;
+        self
+    }
+
+    pub fn with_board_extent(mut self, extent: f32) -> Self {
+        self.config.board_extent = extent;
+        self
+    }
+
+    /// 按主窗口大小缩放棋盘，让棋盘始终完整显示在窗口中
+    pub fn with_fit_to_window(mut self, enable: bool) -> Self {
+        self.config.fit_to_window = enable;
+        self
+    }
+
     pub fn with_captures(mut self, enable: bool) -> Self {
         self.config.enable_captures = enable;
         self
@@ -98,9 +421,100 @@ impl GoBoardPluginBuilder {
         self
     }
 
+    pub fn with_superko_rule(mut self, enable: bool) -> Self {
+        self.config.enable_superko_rule = enable;
+        self
+    }
+
+    pub fn with_komi(mut self, komi: f32) -> Self {
+        self.config.komi = komi;
+        self
+    }
+
+    /// 双方连续虚手后先进入数子阶段，确认死子后再计分
+    pub fn with_scoring_phase(mut self, enable: bool) -> Self {
+        self.config.scoring_phase = enable;
+        self
+    }
+
+    /// 点击先放置待确认的棋子，再点同一点才落子
+    pub fn with_move_confirmation(mut self, enable: bool) -> Self {
+        self.config.confirm_moves = enable;
+        self
+    }
+
+    /// 显示键盘光标，用方向键移动、回车落子
+    pub fn with_keyboard_cursor(mut self, enable: bool) -> Self {
+        self.config.keyboard_cursor = enable;
+        self
+    }
+
+    /// 触控下棋：轻点落子，双指缩放和平移，双击恢复视图
+    pub fn with_touch_controls(mut self, enable: bool) -> Self {
+        self.config.touch_controls = enable;
+        self
+    }
+
+    /// 由电脑棋手执 `color` 一方
+    pub fn with_computer_player(mut self, color: StoneColor, backend: PlayerBackend) -> Self {
+        self.computer_players.push((color, backend));
+        self
+    }
+
+    /// 由外部 GTP 引擎执 `color` 一方
+    pub fn with_gtp_engine(self, color: StoneColor, command: EngineCommand) -> Self {
+        self.with_computer_player(color, PlayerBackend::Gtp(command))
+    }
+
+    /// 由内置 MCTS 棋手执 `color` 一方
+    pub fn with_mcts_bot(self, color: StoneColor, config: MctsConfig) -> Self {
+        self.with_computer_player(color, PlayerBackend::Mcts(config))
+    }
+
+    /// 由内置入门棋手执 `color` 一方，同一种子下对局可复现
+    pub fn with_heuristic_bot(self, color: StoneColor, level: BotLevel, seed: u64) -> Self {
+        self.with_computer_player(color, PlayerBackend::Heuristic { level, seed })
+    }
+
+    /// 用 KataGo 分析引擎分析初始棋盘
+    pub fn with_analysis_engine(mut self, command: EngineCommand) -> Self {
+        self.analysis_engine = Some(command);
+        self
+    }
+
+    /// 用 ONNX 策略网络为初始棋盘提供落子提示，模型需与初始棋盘大小一致
+    #[cfg(feature = "nn")]
+    pub fn with_policy_model(mut self, path: impl Into<PathBuf>) -> Self {
+        self.policy_model = Some(path.into());
+        self
+    }
+
+    /// 由策略网络引导的 MCTS 棋手执 `color` 一方，模型需与初始棋盘大小一致
+    #[cfg(feature = "nn")]
+    pub fn with_policy_mcts_bot(
+        self,
+        color: StoneColor,
+        config: MctsConfig,
+        model: impl Into<PathBuf>,
+    ) -> Self {
+        let board_size = self.config.board_size;
+        self.with_computer_player(
+            color,
+            PlayerBackend::PolicyMcts {
+                config,
+                model: model.into(),
+                board_size,
+            },
+        )
+    }
+
     pub fn build(self) -> GoBoardPlugin {
         GoBoardPlugin {
             initial_config: self.config,
+            computer_players: self.computer_players,
+            analysis_engine: self.analysis_engine,
+            #[cfg(feature = "nn")]
+            policy_model: self.policy_model,
         }
     }
 }
@@ -0,0 +1,90 @@
+use super::{
+    assets::BoardAssets,
+    components::*,
+    config::GoBoardConfig,
+    events::RedrawBoardEvent,
+    resources::CurrentGoBoardConfig,
+    utils::{CoordinateUtils, RenderUtils},
+};
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+/// 同步待确认的棋子
+///
+/// 整体重绘时标记已随棋盘子实体一起清除，只需重新生成
+pub fn sync_provisional_overlay(
+    mut commands: Commands,
+    mut assets: BoardAssets,
+    mut redraw_events: EventReader<RedrawBoardEvent>,
+    mut removed_provisional: RemovedComponents<ProvisionalStone>,
+    boards: Query<(Entity, Ref<CurrentGoBoardConfig>), With<GoBoardRoot>>,
+    provisional: Query<Ref<ProvisionalStone>>,
+    markers: Query<(Entity, &ChildOf), With<ProvisionalMarker>>,
+) {
+    let redraw_targets: HashSet<Entity> = redraw_events.read().map(|event| event.board).collect();
+    let cleared: HashSet<Entity> = removed_provisional.read().collect();
+
+    for (root, config) in boards.iter() {
+        let board_provisional = provisional.get(root).ok();
+        let redrawn = config.is_changed() || redraw_targets.contains(&root);
+        let moved = board_provisional
+            .as_ref()
+            .is_some_and(|provisional| provisional.is_changed());
+        if !redrawn && !moved && !cleared.contains(&root) {
+            continue;
+        }
+
+        if !redrawn {
+            for (entity, parent) in markers.iter() {
+                if parent.parent() == root {
+                    commands.entity(entity).despawn();
+                }
+            }
+        }
+
+        if let Some(board_provisional) = board_provisional {
+            draw_provisional_stone(
+                &mut commands,
+                &mut assets,
+                root,
+                &config.0,
+                &board_provisional,
+            );
+        }
+    }
+}
+
+/// 画一颗半透明棋子，外面套一圈橙色表示还要再点一次确认
+fn draw_provisional_stone(
+    commands: &mut Commands,
+    assets: &mut BoardAssets,
+    root: Entity,
+    config: &GoBoardConfig,
+    provisional: &ProvisionalStone,
+) {
+    let metrics = RenderUtils::calculate_board_metrics(config.board_extent, config.board_size);
+    let fill = match provisional.color {
+        StoneColor::Black => Color::srgba(0.05, 0.05, 0.05, 0.7),
+        StoneColor::White => Color::srgba(0.95, 0.95, 0.95, 0.7),
+    };
+    let local_pos = CoordinateUtils::board_to_local(
+        provisional.position,
+        config.board_size,
+        config.board_extent,
+    );
+
+    commands.spawn((
+        Mesh2d(assets.circle(metrics.cell_size * 0.47)),
+        MeshMaterial2d(assets.material(fill)),
+        Transform::from_translation(Vec3::new(local_pos.x, local_pos.y, 4.7)),
+        ProvisionalMarker,
+        ChildOf(root),
+    ));
+    commands.spawn((
+        Mesh2d(assets.ring(metrics.cell_size * 0.4, metrics.cell_size * 0.47)),
+        MeshMaterial2d(assets.material(Color::srgb(0.95, 0.55, 0.10))),
+        Transform::from_translation(Vec3::new(local_pos.x, local_pos.y, 4.75)),
+        ProvisionalMarker,
+        ChildOf(root),
+    ));
+}
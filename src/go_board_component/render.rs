@@ -0,0 +1,377 @@
+use super::{
+    assets::{BoardAssets, GoBoardAssets},
+    components::*,
+    config::*,
+    events::RedrawBoardEvent,
+    resources::*,
+    utils::{CoordinateUtils, RenderUtils},
+};
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use std::collections::{HashMap, HashSet};
+
+/// 缩放到窗口时需要读取的棋盘组件
+type FittedBoard<'a> = (
+    &'a mut Transform,
+    &'a CurrentGoBoardConfig,
+    Option<&'a mut BoardView>,
+);
+
+/// 配置中的棋盘位置变化时移动棋盘根节点
+pub fn sync_board_transform(
+    mut boards: Query<(&CurrentGoBoardConfig, &mut Transform), Changed<CurrentGoBoardConfig>>,
+) {
+    for (config, mut transform) in boards.iter_mut() {
+        transform.translation = config.0.board_position.extend(transform.translation.z);
+    }
+}
+
+/// 按主窗口大小缩放开启 `fit_to_window` 的棋盘根节点，让棋盘始终完整显示在窗口中
+///
+/// 开启 `adaptive_padding` 时大窗口使用较小的边距；触控缩放过的棋盘只更新 [`BoardView::home`]，保留放大倍数
+pub fn fit_boards_to_window(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut boards: Query<FittedBoard, With<GoBoardRoot>>,
+) {
+    let Ok(window) = windows.single() else {
+        return;
+    };
+    let window_size = window.resolution.width().min(window.resolution.height());
+
+    for (mut transform, config, view) in boards.iter_mut() {
+        if !config.0.fit_to_window {
+            continue;
+        }
+        let padding = if config.0.adaptive_padding && window_size > 1400.0 {
+            50.0
+        } else {
+            100.0
+        };
+        let scale = Vec3::splat((window_size - padding) / config.0.board_extent);
+        match view {
+            Some(mut view) => {
+                if view.home.scale != scale {
+                    view.home.scale = scale;
+                    *transform = view.transform();
+                }
+            }
+            None => {
+                if transform.scale != scale {
+                    transform.scale = scale;
+                }
+            }
+        }
+    }
+}
+
+/// 同步棋盘画面
+///
+/// 新生成的棋盘、配置变化或收到重绘事件时整体重绘；
+/// 仅棋盘状态变化时只增删有变化的棋子（包括被提的棋子）
+pub fn sync_board_visuals(
+    mut commands: Commands,
+    mut assets: BoardAssets,
+    mut redraw_events: EventReader<RedrawBoardEvent>,
+    boards: Query<(Entity, Ref<CurrentGoBoardConfig>, Ref<BoardState>), With<GoBoardRoot>>,
+    stone_entities: Query<(Entity, &Stone, &ChildOf)>,
+) {
+    let redraw_targets: HashSet<Entity> = redraw_events.read().map(|event| event.board).collect();
+
+    for (root, config, board_state) in boards.iter() {
+        if config.is_changed() || redraw_targets.contains(&root) {
+            // 清除棋盘根节点下的所有实体（棋盘、棋子及其附属实体）
+            commands.entity(root).despawn_related::<Children>();
+
+            // 重绘棋盘和棋子
+            draw_board(&mut commands, &mut assets, root, &config.0);
+            for (position, color, move_number) in board_stones(&board_state) {
+                draw_single_stone(
+                    &mut commands,
+                    &mut assets,
+                    root,
+                    &config.0,
+                    position,
+                    color,
+                    move_number,
+                );
+            }
+        } else if board_state.is_changed() {
+            let mut existing: HashMap<(i32, i32), (Entity, &Stone)> = stone_entities
+                .iter()
+                .filter(|(_, _, parent)| parent.parent() == root)
+                .map(|(entity, stone, _)| (stone.position, (entity, stone)))
+                .collect();
+
+            // 补上新落的棋子，替换位置上已变化的棋子
+            for (position, color, move_number) in board_stones(&board_state) {
+                if let Some((entity, stone)) = existing.remove(&position) {
+                    if stone.color == color && stone.move_number == move_number {
+                        continue;
+                    }
+                    commands.entity(entity).despawn();
+                }
+                draw_single_stone(
+                    &mut commands,
+                    &mut assets,
+                    root,
+                    &config.0,
+                    position,
+                    color,
+                    move_number,
+                );
+            }
+
+            // 剩下的是已被提走的棋子（阴影、高光和手数是棋子的子实体，会一并清除）
+            for (entity, _) in existing.into_values() {
+                commands.entity(entity).despawn();
+            }
+        }
+    }
+}
+
+/// 清理不再被任何实体引用的缓存网格和材质，避免窗口缩放或更换样式后旧的资源堆积
+///
+/// 缓存持有强句柄，丢弃后资源随之从 `Assets` 中释放
+pub fn prune_board_assets(
+    mut removed_meshes: RemovedComponents<Mesh2d>,
+    mut removed_materials: RemovedComponents<MeshMaterial2d<ColorMaterial>>,
+    mut cache: ResMut<GoBoardAssets>,
+    mesh_query: Query<&Mesh2d>,
+    material_query: Query<&MeshMaterial2d<ColorMaterial>>,
+) {
+    // 两个读取器都要读完，下一帧不再重复处理
+    let meshes_removed = removed_meshes.read().count() > 0;
+    let materials_removed = removed_materials.read().count() > 0;
+
+    if meshes_removed {
+        let in_use: HashSet<AssetId<Mesh>> = mesh_query.iter().map(|mesh| mesh.0.id()).collect();
+        cache.retain_meshes(&in_use);
+    }
+    if materials_removed {
+        let in_use: HashSet<AssetId<ColorMaterial>> = material_query
+            .iter()
+            .map(|material| material.0.id())
+            .collect();
+        cache.retain_materials(&in_use);
+    }
+}
+
+/// 棋盘上所有棋子的位置、颜色和手数
+fn board_stones(board_state: &BoardState) -> Vec<((i32, i32), StoneColor, usize)> {
+    let board_size_value = board_state.board_size.get_value();
+    let mut stones = Vec::new();
+
+    for x in 0..board_size_value {
+        for y in 0..board_size_value {
+            if let Some(color) = board_state.stones[x as usize][y as usize] {
+                let move_number = board_state.move_numbers[x as usize][y as usize].unwrap_or(0);
+                stones.push(((x, y), color, move_number));
+            }
+        }
+    }
+
+    stones
+}
+
+/// 绘制棋盘
+fn draw_board(
+    commands: &mut Commands,
+    assets: &mut BoardAssets,
+    root: Entity,
+    config: &GoBoardConfig,
+) {
+    let metrics = RenderUtils::calculate_board_metrics(config.board_extent, config.board_size);
+
+    // 绘制棋盘背景
+    commands.spawn((
+        Mesh2d(assets.rectangle(metrics.board_background_size, metrics.board_background_size)),
+        MeshMaterial2d(assets.material(config.board_color)),
+        Transform::from_translation(Vec3::new(0.0, 0.0, 0.0)),
+        GoBoard,
+        ChildOf(root),
+    ));
+
+    let board_size_value = config.board_size.get_value();
+    let line_width = (metrics.cell_size * config.line_width_ratio).max(1.5);
+    let star_point_radius = metrics.cell_size * config.star_point_radius_ratio;
+
+    // 绘制网格线（合并为单个网格）
+    commands.spawn((
+        Mesh2d(assets.grid(board_size_value, &metrics, line_width)),
+        MeshMaterial2d(assets.material(config.line_color)),
+        Transform::from_translation(Vec3::new(0.0, 0.0, 1.0)),
+        BoardLine,
+        ChildOf(root),
+    ));
+
+    // 绘制星位点
+    let star_point_mesh = assets.circle(star_point_radius);
+    let star_point_material = assets.material(config.line_color);
+    for (row, col) in config.board_size.get_star_points() {
+        let x = col as f32 * metrics.cell_size - metrics.half_board;
+        let y = row as f32 * metrics.cell_size - metrics.half_board;
+        commands.spawn((
+            Mesh2d(star_point_mesh.clone()),
+            MeshMaterial2d(star_point_material.clone()),
+            Transform::from_translation(Vec3::new(x, y, 2.0)),
+            StarPoint,
+            ChildOf(root),
+        ));
+    }
+
+    // 绘制坐标（如果启用）
+    if config.show_coordinates {
+        draw_coordinates(
+            commands,
+            root,
+            board_size_value,
+            metrics.cell_size,
+            metrics.half_board,
+            config.coordinate_color,
+        );
+    }
+}
+
+/// 绘制单个棋子
+///
+/// 阴影、高光和手数作为棋子的子实体，随棋子一起移除
+fn draw_single_stone(
+    commands: &mut Commands,
+    assets: &mut BoardAssets,
+    root: Entity,
+    config: &GoBoardConfig,
+    position: (i32, i32),
+    color: StoneColor,
+    move_number: usize,
+) {
+    let metrics = RenderUtils::calculate_board_metrics(config.board_extent, config.board_size);
+
+    // 计算棋子在棋盘局部空间中的位置
+    let local_pos =
+        CoordinateUtils::board_to_local(position, config.board_size, config.board_extent);
+
+    // 棋子大小
+    let stone_radius = metrics.cell_size * 0.47;
+
+    // 简单的纯色棋子
+    let stone_color = match color {
+        StoneColor::Black => Color::srgb(0.05, 0.05, 0.05), // 纯黑色
+        StoneColor::White => Color::srgb(0.95, 0.95, 0.94), // 纯白色（略微偏灰）
+    };
+
+    let stone = commands
+        .spawn((
+            Mesh2d(assets.circle(stone_radius)),
+            MeshMaterial2d(assets.material(stone_color)),
+            Transform::from_translation(Vec3::new(local_pos.x, local_pos.y, 3.9)),
+            Stone {
+                color,
+                position,
+                move_number,
+            },
+            ChildOf(root),
+        ))
+        .id();
+
+    // 非常微妙的阴影
+    let shadow_offset = metrics.cell_size * 0.03;
+    commands.spawn((
+        Mesh2d(assets.circle(stone_radius * 1.04)),
+        MeshMaterial2d(assets.material(Color::srgba(0.0, 0.0, 0.0, 0.05))),
+        Transform::from_translation(Vec3::new(shadow_offset, -shadow_offset, -0.1)),
+        StoneShadow,
+        ChildOf(stone),
+    ));
+
+    // 单个小高光以获得最小的3D效果
+    if config.use_3d_stones {
+        let highlight_radius = stone_radius * 0.2;
+        let highlight_offset = stone_radius * 0.25;
+        let highlight_color = match color {
+            StoneColor::Black => Color::srgba(0.25, 0.25, 0.27, 0.2),
+            StoneColor::White => Color::srgba(1.0, 1.0, 1.0, 0.25),
+        };
+
+        commands.spawn((
+            Mesh2d(assets.circle(highlight_radius)),
+            MeshMaterial2d(assets.material(highlight_color)),
+            Transform::from_translation(Vec3::new(-highlight_offset, highlight_offset, 0.1)),
+            StoneHighlight,
+            ChildOf(stone),
+        ));
+    }
+
+    // 添加手数（如果启用）
+    if config.show_move_numbers {
+        let text_color = match color {
+            StoneColor::Black => Color::srgb(0.95, 0.95, 0.95),
+            StoneColor::White => Color::srgb(0.05, 0.05, 0.05),
+        };
+
+        let font_size = (metrics.cell_size * 0.32).clamp(10.0, 30.0);
+
+        commands.spawn((
+            Text2d::new(move_number.to_string()),
+            TextFont {
+                font_size,
+                ..default()
+            },
+            TextColor(text_color),
+            Transform::from_translation(Vec3::new(0.0, 0.0, 0.8)),
+            MoveNumberLabel,
+            ChildOf(stone),
+        ));
+    }
+}
+
+/// 绘制坐标
+fn draw_coordinates(
+    commands: &mut Commands,
+    root: Entity,
+    board_size: i32,
+    cell_size: f32,
+    half_board: f32,
+    color: Color,
+) {
+    let label_offset = cell_size * 0.7;
+    let font_size = (cell_size * 0.35).clamp(14.0, 40.0);
+
+    // 水平坐标（A-T，跳过I）
+    let letters = [
+        'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S',
+        'T',
+    ];
+    for i in 0..board_size {
+        if i < letters.len() as i32 {
+            let x = i as f32 * cell_size - half_board;
+            commands.spawn((
+                Text2d::new(letters[i as usize].to_string()),
+                TextFont {
+                    font_size,
+                    ..default()
+                },
+                TextColor(color),
+                Transform::from_translation(Vec3::new(x, half_board + label_offset, 3.0)),
+                CoordinateLabel,
+                ChildOf(root),
+            ));
+        }
+    }
+
+    // 垂直坐标（1-19），与 `board_to_human` 一致，最下面一行是 1
+    for i in 0..board_size {
+        let y = half_board - i as f32 * cell_size;
+        let number = (board_size - i).to_string();
+        commands.spawn((
+            Text2d::new(number),
+            TextFont {
+                font_size,
+                ..default()
+            },
+            TextColor(color),
+            Transform::from_translation(Vec3::new(-half_board - label_offset, y, 3.0)),
+            CoordinateLabel,
+            ChildOf(root),
+        ));
+    }
+}
@@ -0,0 +1,88 @@
+use super::{
+    assets::BoardAssets,
+    components::*,
+    config::GoBoardConfig,
+    events::RedrawBoardEvent,
+    resources::{BoardState, CurrentGoBoardConfig},
+    utils::{CoordinateUtils, RenderUtils},
+};
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+/// 同步数子阶段的死子标记
+///
+/// 整体重绘时标记已随棋盘子实体一起清除，只需重新生成
+pub fn sync_dead_stone_overlay(
+    mut commands: Commands,
+    mut assets: BoardAssets,
+    mut redraw_events: EventReader<RedrawBoardEvent>,
+    mut removed_scoring: RemovedComponents<ScoringPhase>,
+    boards: Query<(Entity, Ref<CurrentGoBoardConfig>, &BoardState), With<GoBoardRoot>>,
+    scoring: Query<Ref<ScoringPhase>>,
+    markers: Query<(Entity, &ChildOf), With<DeadStoneMarker>>,
+) {
+    let redraw_targets: HashSet<Entity> = redraw_events.read().map(|event| event.board).collect();
+    let cleared: HashSet<Entity> = removed_scoring.read().collect();
+
+    for (root, config, board_state) in boards.iter() {
+        let board_scoring = scoring.get(root).ok();
+        let redrawn = config.is_changed() || redraw_targets.contains(&root);
+        let scoring_changed = board_scoring
+            .as_ref()
+            .is_some_and(|scoring| scoring.is_changed());
+        if !redrawn && !scoring_changed && !cleared.contains(&root) {
+            continue;
+        }
+
+        if !redrawn {
+            for (entity, parent) in markers.iter() {
+                if parent.parent() == root {
+                    commands.entity(entity).despawn();
+                }
+            }
+        }
+
+        if let Some(board_scoring) = board_scoring {
+            draw_dead_stones(
+                &mut commands,
+                &mut assets,
+                root,
+                &config.0,
+                board_state,
+                &board_scoring.dead_stones,
+            );
+        }
+    }
+}
+
+/// 在死子上画对方颜色的小方块，表示这里算作对方的地
+fn draw_dead_stones(
+    commands: &mut Commands,
+    assets: &mut BoardAssets,
+    root: Entity,
+    config: &GoBoardConfig,
+    board_state: &BoardState,
+    dead_stones: &HashSet<(i32, i32)>,
+) {
+    let metrics = RenderUtils::calculate_board_metrics(config.board_extent, config.board_size);
+    let side = metrics.cell_size * 0.36;
+
+    for &(x, y) in dead_stones {
+        let Some(color) = board_state.get_stone(x, y) else {
+            continue;
+        };
+        let fill = match color.opposite() {
+            StoneColor::Black => Color::srgb(0.05, 0.05, 0.05),
+            StoneColor::White => Color::srgb(0.95, 0.95, 0.95),
+        };
+        let local_pos =
+            CoordinateUtils::board_to_local((x, y), config.board_size, config.board_extent);
+        commands.spawn((
+            Mesh2d(assets.rectangle(side, side)),
+            MeshMaterial2d(assets.material(fill)),
+            Transform::from_translation(Vec3::new(local_pos.x, local_pos.y, 4.6)),
+            DeadStoneMarker,
+            ChildOf(root),
+        ));
+    }
+}
@@ -2,437 +2,492 @@ use super::{
     components::*,
     config::*,
     events::*,
-    resources::*,
-    rules::GoBoardRules,
-    utils::{CoordinateUtils, RenderUtils},
+    resources::{BoardState, CurrentGoBoardConfig, CurrentTurn, GameHistory, Move},
+    rules::{GoBoardRules, IllegalMove},
 };
 use bevy::prelude::*;
 
+/// 对局尚未结束、也不在数子阶段的棋盘
+type PlayableBoard = (With<GoBoardRoot>, Without<GameOver>, Without<ScoringPhase>);
+
+/// 对局尚未结束的棋盘
+type UnfinishedBoard = (With<GoBoardRoot>, Without<GameOver>);
+
+/// 点击落子需要读取的棋盘组件
+type SelectableBoard<'a> = (
+    &'a CurrentGoBoardConfig,
+    &'a BoardState,
+    &'a CurrentTurn,
+    &'a GameHistory,
+    Option<&'a ProvisionalStone>,
+);
+
+/// 检查待确认棋子是否过时需要读取的棋盘组件
+type ProvisionalBoard<'a> = (
+    Entity,
+    &'a ProvisionalStone,
+    &'a CurrentGoBoardConfig,
+    Ref<'a, BoardState>,
+    &'a CurrentTurn,
+    Has<GameOver>,
+    Has<ScoringPhase>,
+);
+
 /// 处理配置更新
+///
+/// 棋盘大小变化时重置该棋盘的对局状态
 pub fn handle_config_update(
+    mut commands: Commands,
     mut config_events: EventReader<UpdateBoardConfigEvent>,
-    mut current_config: ResMut<CurrentGoBoardConfig>,
-    mut redraw_events: EventWriter<RedrawBoardEvent>,
+    mut boards: Query<
+        (
+            &mut CurrentGoBoardConfig,
+            &mut BoardState,
+            &mut CurrentTurn,
+            &mut GameHistory,
+        ),
+        With<GoBoardRoot>,
+    >,
 ) {
     for event in config_events.read() {
-        current_config.0 = event.config.clone();
-        redraw_events.write(RedrawBoardEvent);
+        let Ok((mut config, mut board_state, mut current_turn, mut history)) =
+            boards.get_mut(event.board)
+        else {
+            continue;
+        };
+
+        if config.0.board_size != event.config.board_size {
+            *board_state = BoardState::new(event.config.board_size);
+            *history = GameHistory::default();
+            current_turn.0 = StoneColor::Black;
+            commands
+                .entity(event.board)
+                .remove::<(GameOver, ScoringPhase)>();
+        }
+
+        config.0 = event.config.clone();
     }
 }
 
-/// 处理棋盘重绘
-pub fn handle_board_redraw(
+/// 处理点击棋盘事件
+///
+/// 空点且合法时，关闭 `confirm_moves` 直接由轮到的一方落子；开启时先放置待确认的棋子，
+/// 再点同一点才落子
+pub fn handle_select_point(
     mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<ColorMaterial>>,
-    mut redraw_events: EventReader<RedrawBoardEvent>,
-    windows: Query<&Window, With<bevy::window::PrimaryWindow>>,
-    board_entities: Query<
-        Entity,
-        Or<(
-            With<GoBoard>,
-            With<BoardLine>,
-            With<StarPoint>,
-            With<CoordinateLabel>,
-        )>,
-    >,
-    stone_entities: Query<Entity, With<Stone>>,
-    shadow_entities: Query<Entity, With<StoneShadow>>,
-    highlight_entities: Query<Entity, With<StoneHighlight>>,
-    move_label_entities: Query<Entity, With<MoveNumberLabel>>,
-    config: Res<CurrentGoBoardConfig>,
-    board_state: Res<BoardState>,
+    mut select_events: EventReader<SelectPointEvent>,
+    mut stone_events: EventWriter<PlaceStoneEvent>,
+    boards: Query<SelectableBoard, PlayableBoard>,
 ) {
-    if redraw_events.is_empty() {
-        return;
-    }
-
-    redraw_events.clear();
+    for event in select_events.read() {
+        let Ok((config, board_state, current_turn, history, provisional)) = boards.get(event.board)
+        else {
+            continue;
+        };
+        let (x, y) = event.position;
+        let color = current_turn.0;
+        if check_move(&config.0, board_state, history, x, y, color).is_err() {
+            continue;
+        }
 
-    // 清除现有棋盘
-    for entity in board_entities.iter() {
-        commands.entity(entity).despawn();
-    }
+        let pending = ProvisionalStone {
+            position: event.position,
+            color,
+        };
+        if config.0.confirm_moves && provisional != Some(&pending) {
+            commands.entity(event.board).insert(pending);
+            continue;
+        }
 
-    // 清除所有棋子相关实体（每次重绘都清除，确保位置正确）
-    for entity in stone_entities.iter() {
-        commands.entity(entity).despawn();
-    }
-    for entity in shadow_entities.iter() {
-        commands.entity(entity).despawn();
-    }
-    for entity in highlight_entities.iter() {
-        commands.entity(entity).despawn();
-    }
-    for entity in move_label_entities.iter() {
-        commands.entity(entity).despawn();
+        commands.entity(event.board).remove::<ProvisionalStone>();
+        stone_events.write(PlaceStoneEvent {
+            board: event.board,
+            position: event.position,
+            color,
+        });
     }
+}
 
-    // 重绘棋盘和棋子
-    if let Ok(window) = windows.single() {
-        draw_board(
-            &mut commands,
-            &mut meshes,
-            &mut materials,
-            window,
-            &config.0,
-        );
-        // 总是重绘棋子以确保位置正确
-        draw_stones(
-            &mut commands,
-            &mut meshes,
-            &mut materials,
-            window,
-            &config.0,
-            &board_state,
-        );
+/// 棋盘变化、轮换或对局结束后移除已经过时的待确认棋子
+pub fn discard_stale_provisional_stones(
+    mut commands: Commands,
+    boards: Query<ProvisionalBoard, With<GoBoardRoot>>,
+) {
+    for (board, provisional, config, board_state, current_turn, game_over, scoring) in boards.iter()
+    {
+        if game_over
+            || scoring
+            || !config.0.confirm_moves
+            || board_state.is_changed()
+            || provisional.color != current_turn.0
+        {
+            commands.entity(board).remove::<ProvisionalStone>();
+        }
     }
 }
 
 /// 处理落子事件
 pub fn handle_place_stone(
-    mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<ColorMaterial>>,
     mut stone_events: EventReader<PlaceStoneEvent>,
-    mut board_state: ResMut<BoardState>,
-    windows: Query<&Window, With<bevy::window::PrimaryWindow>>,
-    config: Res<CurrentGoBoardConfig>,
+    mut boards: Query<
+        (
+            &CurrentGoBoardConfig,
+            &mut BoardState,
+            &mut CurrentTurn,
+            &mut GameHistory,
+        ),
+        PlayableBoard,
+    >,
 ) {
     for event in stone_events.read() {
+        let Ok((config, mut board_state, mut current_turn, mut history)) =
+            boards.get_mut(event.board)
+        else {
+            continue;
+        };
+        // 不是该方落子时忽略
+        if event.color != current_turn.0 {
+            continue;
+        }
         let (x, y) = event.position;
+        if check_move(&config.0, &board_state, &history, x, y, event.color).is_err() {
+            continue;
+        }
 
-        // 使用规则引擎检查是否合法
-        if config.0.enable_captures || config.0.enable_ko_rule {
-            if !GoBoardRules::is_valid_move(&board_state, x, y, event.color) {
-                continue;
-            }
-        } else {
-            // 简单检查
-            if x < 0
-                || x >= config.0.board_size.get_value()
-                || y < 0
-                || y >= config.0.board_size.get_value()
-            {
-                continue;
-            }
-            if board_state.stones[x as usize][y as usize].is_some() {
-                continue;
-            }
+        if let Some(captured_stones) = apply_move(&config.0, &mut board_state, x, y, event.color) {
+            history.record(Move {
+                position: Some(event.position),
+                color: event.color,
+                captured_stones,
+                move_number: board_state.move_count,
+                position_hash: board_state.position_hash(),
+            });
+
+            // 轮到对方
+            current_turn.0 = event.color.opposite();
         }
+    }
+}
 
-        // 放置棋子
-        if board_state.place_stone(x, y, event.color) {
-            // 处理提子
-            if config.0.enable_captures {
-                let _captured = GoBoardRules::capture_stones(&mut board_state, x, y, event.color);
-                // TODO: 移除被提棋子的实体
-            }
+/// 处理虚手和认输
+///
+/// 虚手和落子一样记入历史，双方连续虚手后结束对局；认输时对方获胜
+pub fn handle_stone_action(
+    mut commands: Commands,
+    mut action_events: EventReader<StoneActionEvent>,
+    mut game_end_events: EventWriter<GameEndEvent>,
+    mut boards: Query<
+        (
+            &CurrentGoBoardConfig,
+            &mut BoardState,
+            &mut CurrentTurn,
+            &mut GameHistory,
+        ),
+        PlayableBoard,
+    >,
+) {
+    for event in action_events.read() {
+        let Ok((config, mut board_state, mut current_turn, mut history)) =
+            boards.get_mut(event.board)
+        else {
+            continue;
+        };
 
-            // 绘制棋子
-            if let Ok(window) = windows.single() {
-                draw_single_stone(
-                    &mut commands,
-                    &mut meshes,
-                    &mut materials,
-                    window,
-                    &config.0,
-                    event.position,
-                    event.color,
-                    board_state.move_count,
-                );
+        match event.action_type {
+            StoneActionType::Pass => {
+                if event.color != current_turn.0 {
+                    continue;
+                }
+                GoBoardRules::pass(&mut board_state);
+                history.record(Move {
+                    position: None,
+                    color: event.color,
+                    captured_stones: Vec::new(),
+                    move_number: board_state.move_count,
+                    position_hash: board_state.position_hash(),
+                });
+                current_turn.0 = event.color.opposite();
+                if board_state.consecutive_passes >= 2 {
+                    finish_after_passes(
+                        &mut commands,
+                        &mut game_end_events,
+                        event.board,
+                        &config.0,
+                        &board_state,
+                    );
+                }
             }
+            StoneActionType::Resign => {
+                let (black_score, white_score) =
+                    GoBoardRules::area_score(&board_state, config.0.komi);
+                commands.entity(event.board).insert(GameOver);
+                game_end_events.write(GameEndEvent {
+                    board: event.board,
+                    winner: Some(event.color.opposite()),
+                    black_score,
+                    white_score,
+                });
+            }
+            // 落子和提子由 PlaceStoneEvent 处理
+            StoneActionType::Place | StoneActionType::Capture => {}
         }
     }
 }
 
-/// 处理清空棋盘事件
-pub fn handle_clear_board(
+/// 双方连续虚手后结束对局
+///
+/// 开启 `scoring_phase` 时先进入数子阶段，否则直接按数子法计分
+fn finish_after_passes(
+    commands: &mut Commands,
+    game_end_events: &mut EventWriter<GameEndEvent>,
+    board: Entity,
+    config: &GoBoardConfig,
+    board_state: &BoardState,
+) {
+    if config.scoring_phase {
+        commands.entity(board).insert(ScoringPhase {
+            dead_stones: GoBoardRules::suggest_dead_stones(board_state),
+        });
+        return;
+    }
+
+    let (black_score, white_score) = GoBoardRules::area_score(board_state, config.komi);
+    commands.entity(board).insert(GameOver);
+    game_end_events.write(GameEndEvent {
+        board,
+        winner: score_winner(black_score, white_score),
+        black_score,
+        white_score,
+    });
+}
+
+/// 处理数子阶段的死子标记和确认
+///
+/// 点一块棋切换整块棋串的死活；确认后提掉死子计分并结束对局
+pub fn handle_scoring(
     mut commands: Commands,
-    mut clear_events: EventReader<ClearBoardEvent>,
-    mut board_state: ResMut<BoardState>,
-    mut current_turn: ResMut<CurrentTurn>,
-    stone_entities: Query<Entity, With<Stone>>,
-    shadow_entities: Query<Entity, With<StoneShadow>>,
-    highlight_entities: Query<Entity, With<StoneHighlight>>,
-    move_label_entities: Query<Entity, With<MoveNumberLabel>>,
+    mut toggle_events: EventReader<ToggleDeadStonesEvent>,
+    mut confirm_events: EventReader<ConfirmScoreEvent>,
+    mut game_end_events: EventWriter<GameEndEvent>,
+    mut boards: Query<(&CurrentGoBoardConfig, &BoardState, &mut ScoringPhase), UnfinishedBoard>,
 ) {
-    for _ in clear_events.read() {
-        // 清除所有棋子实体
-        for entity in stone_entities.iter() {
-            commands.entity(entity).despawn();
+    for event in toggle_events.read() {
+        let Ok((_, board_state, mut scoring)) = boards.get_mut(event.board) else {
+            continue;
+        };
+        let (x, y) = event.position;
+        if board_state.get_stone(x, y).is_none() {
+            continue;
         }
-        for entity in shadow_entities.iter() {
-            commands.entity(entity).despawn();
+
+        let chain = GoBoardRules::get_group(board_state, x, y);
+        if scoring.dead_stones.contains(&(x, y)) {
+            scoring.dead_stones.retain(|point| !chain.contains(point));
+        } else {
+            scoring.dead_stones.extend(chain);
         }
-        for entity in highlight_entities.iter() {
-            commands.entity(entity).despawn();
+    }
+
+    for event in confirm_events.read() {
+        let Ok((config, board_state, scoring)) = boards.get(event.board) else {
+            continue;
+        };
+
+        let (black_score, white_score) =
+            GoBoardRules::area_score_with_dead(board_state, config.0.komi, &scoring.dead_stones);
+        commands.entity(event.board).insert(GameOver);
+        game_end_events.write(GameEndEvent {
+            board: event.board,
+            winner: score_winner(black_score, white_score),
+            black_score,
+            white_score,
+        });
+    }
+}
+
+/// 按分数判定胜方，相同时为和棋
+fn score_winner(black_score: f32, white_score: f32) -> Option<StoneColor> {
+    if black_score > white_score {
+        Some(StoneColor::Black)
+    } else if white_score > black_score {
+        Some(StoneColor::White)
+    } else {
+        None
+    }
+}
+
+/// 处理撤销事件
+///
+/// 每次后退一条历史记录，虚手也算一条
+pub fn handle_undo_move(
+    mut commands: Commands,
+    mut undo_events: EventReader<UndoMoveEvent>,
+    mut boards: Query<
+        (
+            &CurrentGoBoardConfig,
+            &mut BoardState,
+            &mut CurrentTurn,
+            &mut GameHistory,
+        ),
+        With<GoBoardRoot>,
+    >,
+) {
+    for event in undo_events.read() {
+        let Ok((config, mut board_state, mut current_turn, mut history)) =
+            boards.get_mut(event.board)
+        else {
+            continue;
+        };
+
+        if history.current_index == 0 {
+            continue;
         }
-        for entity in move_label_entities.iter() {
-            commands.entity(entity).despawn();
+
+        history.current_index -= 1;
+        current_turn.0 = history.moves[history.current_index].color;
+        *board_state = replay_history(&config.0, &history);
+        commands
+            .entity(event.board)
+            .remove::<(GameOver, ScoringPhase)>();
+    }
+}
+
+/// 处理重做事件
+///
+/// 重做的虚手构成双方连续虚手时再次结束对局
+pub fn handle_redo_move(
+    mut commands: Commands,
+    mut redo_events: EventReader<RedoMoveEvent>,
+    mut game_end_events: EventWriter<GameEndEvent>,
+    mut boards: Query<
+        (
+            &CurrentGoBoardConfig,
+            &mut BoardState,
+            &mut CurrentTurn,
+            &mut GameHistory,
+        ),
+        With<GoBoardRoot>,
+    >,
+) {
+    for event in redo_events.read() {
+        let Ok((config, mut board_state, mut current_turn, mut history)) =
+            boards.get_mut(event.board)
+        else {
+            continue;
+        };
+
+        if history.current_index >= history.moves.len() {
+            continue;
+        }
+
+        current_turn.0 = history.moves[history.current_index].color.opposite();
+        history.current_index += 1;
+        *board_state = replay_history(&config.0, &history);
+        if board_state.consecutive_passes >= 2 {
+            finish_after_passes(
+                &mut commands,
+                &mut game_end_events,
+                event.board,
+                &config.0,
+                &board_state,
+            );
         }
+    }
+}
+
+/// 处理清空棋盘事件
+pub fn handle_clear_board(
+    mut commands: Commands,
+    mut clear_events: EventReader<ClearBoardEvent>,
+    mut boards: Query<(&mut BoardState, &mut CurrentTurn, &mut GameHistory), With<GoBoardRoot>>,
+) {
+    for event in clear_events.read() {
+        let Ok((mut board_state, mut current_turn, mut history)) = boards.get_mut(event.board)
+        else {
+            continue;
+        };
 
         // 重置棋盘状态
         board_state.clear();
+        *history = GameHistory::default();
         current_turn.0 = StoneColor::Black;
+        commands
+            .entity(event.board)
+            .remove::<(GameOver, ScoringPhase)>();
     }
 }
 
-/// 绘制棋盘
-fn draw_board(
-    commands: &mut Commands,
-    meshes: &mut ResMut<Assets<Mesh>>,
-    materials: &mut ResMut<Assets<ColorMaterial>>,
-    window: &Window,
+/// 按配置检查一手棋是否合法，关闭打劫或提子规则时忽略对应的限制；
+/// 打开全局同形禁着时，落子后的局面不能与 `history` 中出现过的局面相同
+pub fn check_move(
     config: &GoBoardConfig,
-) {
-    let window_size = window.resolution.width().min(window.resolution.height());
-    let metrics = RenderUtils::calculate_board_metrics(
-        window_size,
-        config.board_size,
-        config.adaptive_padding,
-    );
-
-    // 绘制棋盘背景
-    commands.spawn((
-        Mesh2d(meshes.add(Rectangle::new(
-            metrics.board_background_size,
-            metrics.board_background_size,
-        ))),
-        MeshMaterial2d(materials.add(config.board_color)),
-        Transform::from_translation(Vec3::new(0.0, 0.0, 0.0)),
-        GoBoard,
-        GoBoardRoot,
-    ));
-
-    let board_size_value = config.board_size.get_value();
-    let line_width = (metrics.cell_size * config.line_width_ratio).max(1.5);
-    let star_point_radius = metrics.cell_size * config.star_point_radius_ratio;
-
-    // 绘制网格线
-    for i in 0..board_size_value {
-        let offset = i as f32 * metrics.cell_size - metrics.half_board;
-
-        // 垂直线
-        commands.spawn((
-            Mesh2d(meshes.add(Rectangle::new(line_width, metrics.board_size_pixels))),
-            MeshMaterial2d(materials.add(config.line_color)),
-            Transform::from_translation(Vec3::new(offset, 0.0, 1.0)),
-            BoardLine,
-        ));
-
-        // 水平线
-        commands.spawn((
-            Mesh2d(meshes.add(Rectangle::new(metrics.board_size_pixels, line_width))),
-            MeshMaterial2d(materials.add(config.line_color)),
-            Transform::from_translation(Vec3::new(0.0, offset, 1.0)),
-            BoardLine,
-        ));
-    }
-
-    // 绘制星位点
-    for (row, col) in config.board_size.get_star_points() {
-        let x = col as f32 * metrics.cell_size - metrics.half_board;
-        let y = row as f32 * metrics.cell_size - metrics.half_board;
-        commands.spawn((
-            Mesh2d(meshes.add(Circle::new(star_point_radius))),
-            MeshMaterial2d(materials.add(config.line_color)),
-            Transform::from_translation(Vec3::new(x, y, 2.0)),
-            StarPoint,
-        ));
-    }
+    board_state: &BoardState,
+    history: &GameHistory,
+    x: i32,
+    y: i32,
+    color: StoneColor,
+) -> Result<(), IllegalMove> {
+    check_board_move(config, board_state, x, y, color)?;
 
-    // 绘制坐标（如果启用）
-    if config.show_coordinates {
-        draw_coordinates(
-            commands,
-            board_size_value,
-            metrics.cell_size,
-            metrics.half_board,
-            config.coordinate_color,
-        );
+    if config.enable_superko_rule {
+        let mut next = board_state.clone();
+        apply_move(config, &mut next, x, y, color);
+        GoBoardRules::check_superko(&next, history)?;
     }
+    Ok(())
 }
 
-/// 绘制所有棋子
-fn draw_stones(
-    commands: &mut Commands,
-    meshes: &mut ResMut<Assets<Mesh>>,
-    materials: &mut ResMut<Assets<ColorMaterial>>,
-    window: &Window,
+/// 只看当前局面的合法性检查，不涉及历史
+fn check_board_move(
     config: &GoBoardConfig,
     board_state: &BoardState,
-) {
-    let board_size_value = config.board_size.get_value();
-
-    for x in 0..board_size_value {
-        for y in 0..board_size_value {
-            if let Some(color) = board_state.stones[x as usize][y as usize] {
-                let move_number = board_state.move_numbers[x as usize][y as usize].unwrap_or(0);
-                draw_single_stone(
-                    commands,
-                    meshes,
-                    materials,
-                    window,
-                    config,
-                    (x, y),
-                    color,
-                    move_number,
-                );
-            }
-        }
+    x: i32,
+    y: i32,
+    color: StoneColor,
+) -> Result<(), IllegalMove> {
+    match GoBoardRules::check_move(board_state, x, y, color) {
+        Err(IllegalMove::Ko) if !config.enable_ko_rule => Ok(()),
+        Err(IllegalMove::Suicide) if !config.enable_captures => Ok(()),
+        result => result,
     }
 }
 
-/// 绘制单个棋子
-fn draw_single_stone(
-    commands: &mut Commands,
-    meshes: &mut ResMut<Assets<Mesh>>,
-    materials: &mut ResMut<Assets<ColorMaterial>>,
-    window: &Window,
+/// 按配置检查并执行一手棋，返回被提的棋子；不合法时返回 `None`
+///
+/// 不检查全局同形，需要时先用 [`check_move`] 对照历史检查
+pub fn apply_move(
     config: &GoBoardConfig,
-    position: (i32, i32),
+    board_state: &mut BoardState,
+    x: i32,
+    y: i32,
     color: StoneColor,
-    move_number: usize,
-) {
-    let window_size = window.resolution.width().min(window.resolution.height());
-    let metrics = RenderUtils::calculate_board_metrics(
-        window_size,
-        config.board_size,
-        config.adaptive_padding,
-    );
-
-    // 计算棋子位置
-    let world_pos = CoordinateUtils::board_to_world(
-        position,
-        config.board_size,
-        window_size,
-        config.adaptive_padding,
-    );
-
-    // 棋子大小
-    let stone_radius = metrics.cell_size * 0.47;
-
-    // 简单的纯色棋子
-
-    // 非常微妙的阴影
-    let shadow_offset = metrics.cell_size * 0.03;
-    commands.spawn((
-        Mesh2d(meshes.add(Circle::new(stone_radius * 1.04))),
-        MeshMaterial2d(materials.add(Color::srgba(0.0, 0.0, 0.0, 0.05))),
-        Transform::from_translation(Vec3::new(
-            world_pos.x + shadow_offset,
-            world_pos.y - shadow_offset,
-            3.8,
-        )),
-        StoneShadow,
-    ));
-
-    // 主棋子体
-    let stone_color = match color {
-        StoneColor::Black => Color::srgb(0.05, 0.05, 0.05), // 纯黑色
-        StoneColor::White => Color::srgb(0.95, 0.95, 0.94), // 纯白色（略微偏灰）
-    };
-
-    commands.spawn((
-        Mesh2d(meshes.add(Circle::new(stone_radius))),
-        MeshMaterial2d(materials.add(stone_color)),
-        Transform::from_translation(Vec3::new(world_pos.x, world_pos.y, 3.9)),
-        Stone {
-            color,
-            position,
-            move_number,
-        },
-    ));
-
-    // 单个小高光以获得最小的3D效果
-    if config.use_3d_stones {
-        let highlight_radius = stone_radius * 0.2;
-        let highlight_offset = stone_radius * 0.25;
-        let highlight_color = match color {
-            StoneColor::Black => Color::srgba(0.25, 0.25, 0.27, 0.2),
-            StoneColor::White => Color::srgba(1.0, 1.0, 1.0, 0.25),
-        };
+) -> Option<Vec<(i32, i32)>> {
+    check_board_move(config, board_state, x, y, color).ok()?;
 
-        commands.spawn((
-            Mesh2d(meshes.add(Circle::new(highlight_radius))),
-            MeshMaterial2d(materials.add(highlight_color)),
-            Transform::from_translation(Vec3::new(
-                world_pos.x - highlight_offset,
-                world_pos.y + highlight_offset,
-                4.0,
-            )),
-            StoneHighlight,
-        ));
+    // 放置棋子
+    if !board_state.place_stone(x, y, color) {
+        return None;
     }
 
-    // 添加手数（如果启用）
-    if config.show_move_numbers {
-        let text_color = match color {
-            StoneColor::Black => Color::srgb(0.95, 0.95, 0.95),
-            StoneColor::White => Color::srgb(0.05, 0.05, 0.05),
-        };
-
-        let font_size = (metrics.cell_size * 0.32).max(10.0).min(30.0);
-
-        commands.spawn((
-            Text2d::new(move_number.to_string()),
-            TextFont {
-                font_size,
-                ..default()
-            },
-            TextColor(text_color),
-            Transform::from_translation(Vec3::new(world_pos.x, world_pos.y, 4.7)),
-            MoveNumberLabel,
-        ));
+    // 处理提子
+    if !config.enable_captures {
+        return Some(Vec::new());
     }
+    let captured = GoBoardRules::capture_stones(board_state, x, y, color);
+    GoBoardRules::update_ko(board_state, x, y, &captured);
+    Some(captured)
 }
 
-/// 绘制坐标
-fn draw_coordinates(
-    commands: &mut Commands,
-    board_size: i32,
-    cell_size: f32,
-    half_board: f32,
-    color: Color,
-) {
-    let label_offset = cell_size * 0.7;
-    let font_size = (cell_size * 0.35).max(14.0).min(40.0);
-
-    // 水平坐标（A-T，跳过I）
-    let letters = [
-        'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S',
-        'T',
-    ];
-    for i in 0..board_size {
-        if i < letters.len() as i32 {
-            let x = i as f32 * cell_size - half_board;
-            commands.spawn((
-                Text2d::new(letters[i as usize].to_string()),
-                TextFont {
-                    font_size,
-                    ..default()
-                },
-                TextColor(color),
-                Transform::from_translation(Vec3::new(x, half_board + label_offset, 3.0)),
-                CoordinateLabel,
-            ));
+/// 从空棋盘重放历史中已执行的着法和虚手，连续虚手数随之恢复
+fn replay_history(config: &GoBoardConfig, history: &GameHistory) -> BoardState {
+    let mut board_state = BoardState::new(config.board_size);
+    for played in history.played_moves() {
+        match played.position {
+            Some((x, y)) => {
+                apply_move(config, &mut board_state, x, y, played.color);
+            }
+            None => GoBoardRules::pass(&mut board_state),
         }
     }
-
-    // 垂直坐标（1-19）
-    for i in 0..board_size {
-        let y = half_board - i as f32 * cell_size;
-        let number = (i + 1).to_string();
-        commands.spawn((
-            Text2d::new(number),
-            TextFont {
-                font_size,
-                ..default()
-            },
-            TextColor(color),
-            Transform::from_translation(Vec3::new(-half_board - label_offset, y, 3.0)),
-            CoordinateLabel,
-        ));
-    }
+    board_state
 }
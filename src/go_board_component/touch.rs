@@ -0,0 +1,559 @@
+use super::{
+    assets::BoardAssets,
+    components::*,
+    config::GoBoardConfig,
+    events::{BoardTouchEvent, RedrawBoardEvent},
+    keyboard::{ActionTarget, BoardAction, BoardActionEvents},
+    players::ComputerPlayer,
+    resources::{CurrentGoBoardConfig, CurrentTurn, GameHistory},
+    utils::{CoordinateUtils, RenderUtils},
+};
+use bevy::input::touch::{TouchInput, TouchPhase};
+use bevy::prelude::*;
+use bevy::render::camera::NormalizedRenderTarget;
+use bevy::window::PrimaryWindow;
+use std::collections::{HashMap, HashSet};
+
+/// 相对于未缩放视图的最大放大倍数
+const MAX_ZOOM: f32 = 4.0;
+
+/// 两次轻点间隔不超过此值（秒）算双击，所以轻点要等这么久才落子
+const DOUBLE_TAP_TIME: f32 = 0.3;
+
+/// 触控下棋需要读取的棋盘组件
+type TouchBoard<'a> = (
+    Entity,
+    &'a GlobalTransform,
+    &'a mut Transform,
+    &'a mut BoardView,
+    &'a CurrentGoBoardConfig,
+    &'a CurrentTurn,
+    &'a GameHistory,
+    Option<&'a TouchPreview>,
+    Has<ScoringPhase>,
+    Has<GameOver>,
+);
+
+/// 同步视图需要读取的棋盘组件
+type ViewBoard<'a> = (
+    Entity,
+    &'a CurrentGoBoardConfig,
+    &'a mut Transform,
+    Option<&'a mut BoardView>,
+);
+
+/// 正在进行的触摸手势
+#[derive(Debug, Clone, Copy)]
+enum TouchGesture {
+    /// 单指按在棋盘上，松开后在最后经过的交叉点落子，移出网格时为 `None`
+    Tap {
+        id: u64,
+        board: Entity,
+        position: Option<(i32, i32)>,
+    },
+    /// 双指缩放和平移
+    Pinch { board: Entity },
+    /// 双击恢复视图或按在棋盘外，等所有手指离开
+    Ignored,
+}
+
+/// 已松开、还在等待是否构成双击的轻点
+#[derive(Debug, Clone, Copy)]
+struct PendingTap {
+    board: Entity,
+    position: (i32, i32),
+    released: f32,
+}
+
+/// 按在屏幕上的手指和正在进行的手势
+#[derive(Resource, Default, Debug)]
+pub struct BoardTouches {
+    points: HashMap<u64, Vec2>,
+    gesture: Option<TouchGesture>,
+    pending: Option<PendingTap>,
+}
+
+impl BoardTouches {
+    /// 按在屏幕上的手指数
+    pub fn count(&self) -> usize {
+        self.points.len()
+    }
+}
+
+/// 把 [`TouchInput`] 经对应窗口的相机换算成世界坐标的 [`BoardTouchEvent`]
+///
+/// 同一窗口有多个相机时使用 `order` 最大的；抬起时无法换算则按取消处理，使用最后已知的位置
+pub fn touch_input_to_board(
+    mut touch_inputs: EventReader<TouchInput>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    mut last_positions: Local<HashMap<u64, Vec2>>,
+    mut touch_events: EventWriter<BoardTouchEvent>,
+) {
+    let primary_window = primary_window.single().ok();
+    for input in touch_inputs.read() {
+        let position = cameras
+            .iter()
+            .filter(|(camera, _)| {
+                camera.is_active
+                    && matches!(
+                        camera.target.normalize(primary_window),
+                        Some(NormalizedRenderTarget::Window(window)) if window.entity() == input.window
+                    )
+            })
+            .max_by_key(|(camera, _)| camera.order)
+            .and_then(|(camera, camera_transform)| {
+                camera
+                    .viewport_to_world_2d(camera_transform, input.position)
+                    .ok()
+            });
+
+        let (phase, position) = match (input.phase, position) {
+            (TouchPhase::Ended | TouchPhase::Canceled, _) => {
+                let Some(last) = last_positions.remove(&input.id) else {
+                    continue;
+                };
+                match position {
+                    Some(position) => (input.phase, position),
+                    None => (TouchPhase::Canceled, last),
+                }
+            }
+            (phase, Some(position)) => {
+                last_positions.insert(input.id, position);
+                (phase, position)
+            }
+            (_, None) => continue,
+        };
+        touch_events.write(BoardTouchEvent {
+            id: input.id,
+            phase,
+            position,
+        });
+    }
+}
+
+/// 按 `touch_controls` 给棋盘挂上或移除 [`BoardView`]
+///
+/// 关闭时恢复未缩放的变换；`Transform` 被其他系统改动时以它作为新的未缩放视图
+pub fn sync_board_views(mut commands: Commands, mut boards: Query<ViewBoard, With<GoBoardRoot>>) {
+    for (board, config, mut transform, view) in boards.iter_mut() {
+        match (config.0.touch_controls, view) {
+            (true, None) => {
+                commands.entity(board).insert(BoardView::new(*transform));
+            }
+            (true, Some(mut view)) => {
+                if *transform != view.transform() {
+                    *view = BoardView::new(*transform);
+                }
+            }
+            (false, Some(view)) => {
+                *transform = view.home;
+                commands.entity(board).remove::<(BoardView, TouchPreview)>();
+            }
+            (false, None) => {}
+        }
+    }
+}
+
+/// 处理挂有 [`BoardView`] 的棋盘上的触摸手势
+///
+/// 单指按下时在手指下的交叉点显示 [`TouchPreview`]，移动手指可以调整，松开后与鼠标点击一样落子
+/// （数子阶段切换死活）；双指缩放和平移；在同一处附近双击恢复视图，所以轻点要等双击时间过去才落子。
+/// 轮到电脑棋手时不落子
+pub fn handle_board_touches(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut touch_events: EventReader<BoardTouchEvent>,
+    mut touches: ResMut<BoardTouches>,
+    mut boards: Query<TouchBoard, With<GoBoardRoot>>,
+    computer_players: Query<&ComputerPlayer>,
+    mut events: BoardActionEvents,
+) {
+    let now = time.elapsed_secs();
+    let touches = &mut *touches;
+
+    for event in touch_events.read() {
+        match event.phase {
+            TouchPhase::Started => {
+                touches.points.insert(event.id, event.position);
+                match touches.points.len() {
+                    1 => {
+                        let hit = boards.iter().find_map(|board| {
+                            intersection(board.1, board.4, event.position)
+                                .map(|position| (board.0, position))
+                        });
+                        let Some((board, position)) = hit else {
+                            touches.gesture = Some(TouchGesture::Ignored);
+                            continue;
+                        };
+
+                        if let Some(pending) = touches.pending.take() {
+                            let (dx, dy) = (
+                                pending.position.0 - position.0,
+                                pending.position.1 - position.1,
+                            );
+                            if pending.board == board
+                                && now - pending.released <= DOUBLE_TAP_TIME
+                                && dx.abs() <= 1
+                                && dy.abs() <= 1
+                            {
+                                if let Ok(mut touched) = boards.get_mut(board) {
+                                    *touched.3 = BoardView::new(touched.3.home);
+                                    *touched.2 = touched.3.home;
+                                }
+                                commands.entity(board).remove::<TouchPreview>();
+                                touches.gesture = Some(TouchGesture::Ignored);
+                                continue;
+                            }
+                            place(&boards, &computer_players, &mut events, pending);
+                            if pending.board != board {
+                                commands.entity(pending.board).remove::<TouchPreview>();
+                            }
+                        }
+
+                        touches.gesture = Some(TouchGesture::Tap {
+                            id: event.id,
+                            board,
+                            position: Some(position),
+                        });
+                        show_preview(&mut commands, &boards, board, Some(position));
+                    }
+                    2 => {
+                        let board = match touches.gesture {
+                            Some(TouchGesture::Tap { board, .. }) => {
+                                commands.entity(board).remove::<TouchPreview>();
+                                Some(board)
+                            }
+                            Some(TouchGesture::Pinch { board }) => Some(board),
+                            _ => {
+                                let middle = touches.points.values().sum::<Vec2>() / 2.0;
+                                boards
+                                    .iter()
+                                    .find(|board| contains(board.1, board.4, middle))
+                                    .map(|board| board.0)
+                            }
+                        };
+                        touches.gesture = Some(match board {
+                            Some(board) => TouchGesture::Pinch { board },
+                            None => TouchGesture::Ignored,
+                        });
+                    }
+                    _ => {}
+                }
+            }
+            TouchPhase::Moved => {
+                let Some(previous) = touches.points.insert(event.id, event.position) else {
+                    continue;
+                };
+                match touches.gesture {
+                    Some(TouchGesture::Tap {
+                        id,
+                        board,
+                        position,
+                    }) if id == event.id => {
+                        let moved_to = boards
+                            .get(board)
+                            .ok()
+                            .and_then(|touched| intersection(touched.1, touched.4, event.position));
+                        if moved_to != position {
+                            touches.gesture = Some(TouchGesture::Tap {
+                                id,
+                                board,
+                                position: moved_to,
+                            });
+                            show_preview(&mut commands, &boards, board, moved_to);
+                        }
+                    }
+                    Some(TouchGesture::Pinch { board }) => {
+                        let anchor = touches
+                            .points
+                            .iter()
+                            .find(|(id, _)| **id != event.id)
+                            .map(|(_, point)| *point);
+                        if let Some(anchor) = anchor
+                            && let Ok(mut touched) = boards.get_mut(board)
+                        {
+                            let board_extent = touched.4.0.board_extent;
+                            pinch(
+                                &mut touched.3,
+                                board_extent,
+                                anchor,
+                                previous,
+                                event.position,
+                            );
+                            let transform = touched.3.transform();
+                            if *touched.2 != transform {
+                                *touched.2 = transform;
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            TouchPhase::Ended | TouchPhase::Canceled => {
+                if touches.points.remove(&event.id).is_none() {
+                    continue;
+                }
+                match touches.gesture {
+                    Some(TouchGesture::Tap {
+                        id,
+                        board,
+                        position,
+                    }) if id == event.id => {
+                        touches.gesture = None;
+                        match position {
+                            Some(position) if event.phase == TouchPhase::Ended => {
+                                touches.pending = Some(PendingTap {
+                                    board,
+                                    position,
+                                    released: now,
+                                });
+                            }
+                            _ => {
+                                commands.entity(board).remove::<TouchPreview>();
+                            }
+                        }
+                    }
+                    Some(_) if touches.points.is_empty() => touches.gesture = None,
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    if let Some(pending) = touches.pending
+        && now - pending.released > DOUBLE_TAP_TIME
+    {
+        touches.pending = None;
+        place(&boards, &computer_players, &mut events, pending);
+        if !matches!(touches.gesture, Some(TouchGesture::Tap { board, .. }) if board == pending.board)
+        {
+            commands.entity(pending.board).remove::<TouchPreview>();
+        }
+    }
+}
+
+/// 世界坐标下的交叉点
+fn intersection(
+    root_transform: &GlobalTransform,
+    config: &CurrentGoBoardConfig,
+    point: Vec2,
+) -> Option<(i32, i32)> {
+    CoordinateUtils::world_to_board(
+        point,
+        root_transform,
+        config.0.board_size,
+        config.0.board_extent,
+    )
+}
+
+/// 世界坐标是否落在棋盘范围内（包括网格外的边框）
+fn contains(root_transform: &GlobalTransform, config: &CurrentGoBoardConfig, point: Vec2) -> bool {
+    let local = root_transform
+        .affine()
+        .inverse()
+        .transform_point3(point.extend(0.0));
+    let half = config.0.board_extent / 2.0;
+    local.x.abs() <= half && local.y.abs() <= half
+}
+
+/// 一根手指从 `from` 移到 `to`、另一根停在 `anchor` 时缩放和平移视图
+///
+/// 两指中点下的棋盘位置跟着中点移动；放大倍数限制在 1 到 [`MAX_ZOOM`]，
+/// 平移限制在放大后的棋盘仍然盖住原来位置的范围内，缩回原大小时自动回到原位
+fn pinch(view: &mut BoardView, board_extent: f32, anchor: Vec2, from: Vec2, to: Vec2) {
+    let (from_span, to_span) = (anchor.distance(from), anchor.distance(to));
+    if from_span <= f32::EPSILON || to_span <= f32::EPSILON {
+        return;
+    }
+    let (from_middle, to_middle) = ((anchor + from) / 2.0, (anchor + to) / 2.0);
+
+    let zoom = (view.zoom * to_span / from_span).clamp(1.0, MAX_ZOOM);
+    let factor = zoom / view.zoom;
+    let home = view.home.translation.truncate();
+    let translation = to_middle + (home + view.pan - from_middle) * factor;
+
+    let slack = board_extent / 2.0 * view.home.scale.truncate().abs() * (zoom - 1.0);
+    view.zoom = zoom;
+    view.pan = (translation - home).clamp(-slack, slack);
+}
+
+/// 按手指下的交叉点更新预览；数子阶段和终局后不预览
+fn show_preview(
+    commands: &mut Commands,
+    boards: &Query<TouchBoard, With<GoBoardRoot>>,
+    board: Entity,
+    position: Option<(i32, i32)>,
+) {
+    let Ok((_, _, _, _, _, current_turn, _, current, scoring, game_over)) = boards.get(board)
+    else {
+        return;
+    };
+    let preview = position
+        .filter(|_| !scoring && !game_over)
+        .map(|position| TouchPreview {
+            position,
+            color: current_turn.0,
+        });
+    match preview {
+        Some(preview) if current != Some(&preview) => {
+            commands.entity(board).insert(preview);
+        }
+        None if current.is_some() => {
+            commands.entity(board).remove::<TouchPreview>();
+        }
+        _ => {}
+    }
+}
+
+/// 在轻点的交叉点执行落子操作
+fn place(
+    boards: &Query<TouchBoard, With<GoBoardRoot>>,
+    computer_players: &Query<&ComputerPlayer>,
+    events: &mut BoardActionEvents,
+    tap: PendingTap,
+) {
+    let Ok((board, _, _, _, _, current_turn, history, _, scoring, game_over)) =
+        boards.get(tap.board)
+    else {
+        return;
+    };
+    let computer_turn = computer_players
+        .iter()
+        .any(|player| player.board == board && player.color == current_turn.0);
+    events.perform(
+        BoardAction::Place,
+        &ActionTarget {
+            board,
+            position: tap.position,
+            turn: current_turn.0,
+            history,
+            scoring,
+            game_over,
+            may_play: !computer_turn,
+        },
+    );
+}
+
+/// 同步触控预览
+///
+/// 整体重绘时标记已随棋盘子实体一起清除，只需重新生成
+pub fn sync_touch_preview_overlay(
+    mut commands: Commands,
+    mut assets: BoardAssets,
+    mut redraw_events: EventReader<RedrawBoardEvent>,
+    mut removed_previews: RemovedComponents<TouchPreview>,
+    boards: Query<(Entity, Ref<CurrentGoBoardConfig>), With<GoBoardRoot>>,
+    previews: Query<Ref<TouchPreview>>,
+    markers: Query<(Entity, &ChildOf), With<TouchPreviewMarker>>,
+) {
+    let redraw_targets: HashSet<Entity> = redraw_events.read().map(|event| event.board).collect();
+    let cleared: HashSet<Entity> = removed_previews.read().collect();
+
+    for (root, config) in boards.iter() {
+        let preview = previews.get(root).ok();
+        let redrawn = config.is_changed() || redraw_targets.contains(&root);
+        let moved = preview.as_ref().is_some_and(|preview| preview.is_changed());
+        if !redrawn && !moved && !cleared.contains(&root) {
+            continue;
+        }
+
+        if !redrawn {
+            for (entity, parent) in markers.iter() {
+                if parent.parent() == root {
+                    commands.entity(entity).despawn();
+                }
+            }
+        }
+
+        if let Some(preview) = preview {
+            draw_touch_preview(&mut commands, &mut assets, root, &config.0, &preview);
+        }
+    }
+}
+
+/// 在落点画半透明棋子，并在落点上方（靠近上边时在下方）画一个不被手指挡住的放大镜，
+/// 里面是放大两倍的交叉点、棋子和坐标
+fn draw_touch_preview(
+    commands: &mut Commands,
+    assets: &mut BoardAssets,
+    root: Entity,
+    config: &GoBoardConfig,
+    preview: &TouchPreview,
+) {
+    let metrics = RenderUtils::calculate_board_metrics(config.board_extent, config.board_size);
+    let cell = metrics.cell_size;
+    let fill = match preview.color {
+        StoneColor::Black => Color::srgba(0.05, 0.05, 0.05, 0.6),
+        StoneColor::White => Color::srgba(0.95, 0.95, 0.95, 0.6),
+    };
+    let local_pos =
+        CoordinateUtils::board_to_local(preview.position, config.board_size, config.board_extent);
+
+    commands.spawn((
+        Mesh2d(assets.circle(cell * 0.47)),
+        MeshMaterial2d(assets.material(fill)),
+        Transform::from_translation(Vec3::new(local_pos.x, local_pos.y, 4.7)),
+        TouchPreviewMarker,
+        ChildOf(root),
+    ));
+
+    let radius = cell * 1.2;
+    let offset = cell * 2.5;
+    let center_y = if local_pos.y + offset + radius > config.board_extent / 2.0 {
+        local_pos.y - offset
+    } else {
+        local_pos.y + offset
+    };
+    let center = |z: f32| Transform::from_translation(Vec3::new(local_pos.x, center_y, z));
+    let line = (cell * 0.06).max(1.5);
+
+    commands.spawn((
+        Mesh2d(assets.circle(radius)),
+        MeshMaterial2d(assets.material(config.board_color)),
+        center(5.0),
+        TouchPreviewMarker,
+        ChildOf(root),
+    ));
+    commands.spawn((
+        Mesh2d(assets.ring(radius - line, radius)),
+        MeshMaterial2d(assets.material(Color::srgb(0.30, 0.25, 0.20))),
+        center(5.01),
+        TouchPreviewMarker,
+        ChildOf(root),
+    ));
+    for mesh in [
+        assets.rectangle(radius * 2.0 - line * 2.0, line),
+        assets.rectangle(line, radius * 2.0 - line * 2.0),
+    ] {
+        commands.spawn((
+            Mesh2d(mesh),
+            MeshMaterial2d(assets.material(config.line_color)),
+            center(5.02),
+            TouchPreviewMarker,
+            ChildOf(root),
+        ));
+    }
+    commands.spawn((
+        Mesh2d(assets.circle(cell * 0.94)),
+        MeshMaterial2d(assets.material(fill)),
+        center(5.03),
+        TouchPreviewMarker,
+        ChildOf(root),
+    ));
+    commands.spawn((
+        Text2d::new(CoordinateUtils::board_to_human(
+            preview.position.0,
+            preview.position.1,
+            config.board_size,
+        )),
+        TextFont {
+            font_size: (cell * 0.4).clamp(12.0, 32.0),
+            ..default()
+        },
+        TextColor(Color::srgb(0.95, 0.55, 0.10)),
+        Transform::from_translation(Vec3::new(local_pos.x, center_y - radius - cell * 0.3, 5.04)),
+        TouchPreviewMarker,
+        ChildOf(root),
+    ));
+}
@@ -0,0 +1,372 @@
+use super::{
+    components::*,
+    config::GoBoardConfig,
+    events::{GameEndEvent, StoneActionEvent, StoneActionType},
+    players::{ComputerPlayer, PlayerBackend},
+    resources::{CurrentTurn, GameHistory},
+};
+use crate::go_core::{BoardSize, Crosstable, DEFAULT_KOMI, SgfRecord, TournamentFormat};
+use bevy::prelude::*;
+use std::time::{Duration, Instant};
+
+/// 参赛者
+#[derive(Debug, Clone)]
+pub struct Entrant {
+    pub name: String,
+    pub backend: PlayerBackend,
+}
+
+/// 比赛设置，所有对局使用相同的规则、贴目和用时
+#[derive(Debug, Clone)]
+pub struct TournamentConfig {
+    pub format: TournamentFormat,
+    /// 每个对阵的对局数，双方轮流执黑
+    pub games_per_pairing: usize,
+    pub board_size: BoardSize,
+    pub komi: f32,
+    /// 每手时间，超过两倍再加一秒判超时负；`None` 时不限时
+    pub move_time: Option<Duration>,
+    /// 超过此手数时双方虚手，按数子结束
+    pub max_moves: usize,
+    /// 同时进行的对局数
+    pub concurrent_games: usize,
+}
+
+impl Default for TournamentConfig {
+    fn default() -> Self {
+        Self {
+            format: TournamentFormat::RoundRobin,
+            games_per_pairing: 2,
+            board_size: BoardSize::Nine,
+            komi: DEFAULT_KOMI,
+            move_time: Some(Duration::from_secs(1)),
+            max_moves: 9 * 9 * 3,
+            concurrent_games: 1,
+        }
+    }
+}
+
+/// 对局结束的方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EndReason {
+    /// 双方连续虚手后数子
+    Score,
+    Resign,
+    /// 超时或引擎出错
+    Time,
+}
+
+/// 一局已完成的比赛对局
+#[derive(Debug, Clone)]
+pub struct TournamentGame {
+    /// 在赛程中的序号
+    pub index: usize,
+    pub black: usize,
+    pub white: usize,
+    /// `None` 为和棋
+    pub winner: Option<StoneColor>,
+    pub reason: EndReason,
+    pub record: SgfRecord,
+}
+
+/// 正在进行的对局
+struct RunningGame {
+    index: usize,
+    black: usize,
+    white: usize,
+    board: Entity,
+    players: Vec<Entity>,
+    /// 已记录的着法，`None` 为虚手
+    moves: Vec<(StoneColor, Option<(i32, i32)>)>,
+    /// 手数超限后发出双方虚手时的手数，局面没有变化时不再重复发送
+    limit_passes: Option<usize>,
+    turn_started: Instant,
+    forfeit: Option<StoneColor>,
+}
+
+impl RunningGame {
+    /// 是否以双方连续虚手结束
+    fn ended_by_passes(&self) -> bool {
+        matches!(self.moves.as_slice(), [.., (_, None), (_, None)])
+    }
+}
+
+/// 电脑棋手比赛
+///
+/// 插入后由 [`TournamentPlugin`] 按赛程为每局生成棋盘和双方的 [`ComputerPlayer`]，
+/// 从 [`GameEndEvent`] 收集结果；全部完成后 [`Tournament::is_finished`] 返回 true
+///
+/// # 使用示例
+/// ```rust,ignore
+/// app.add_plugins((MinimalPlugins, GoBoardLogicPlugin, ComputerPlayerPlugin, TournamentPlugin))
+///     .insert_resource(Tournament::new(entrants, TournamentConfig::default()));
+/// while !app.world().resource::<Tournament>().is_finished() {
+///     app.update();
+/// }
+/// println!("{}", app.world().resource::<Tournament>().crosstable.to_table());
+/// ```
+#[derive(Resource)]
+pub struct Tournament {
+    pub config: TournamentConfig,
+    pub entrants: Vec<Entrant>,
+    /// 每局的 `(黑, 白)`
+    pub schedule: Vec<(usize, usize)>,
+    pub crosstable: Crosstable,
+    /// 按完成顺序排列
+    pub finished: Vec<TournamentGame>,
+    next: usize,
+    running: Vec<RunningGame>,
+}
+
+impl Tournament {
+    pub fn new(entrants: Vec<Entrant>, config: TournamentConfig) -> Self {
+        let schedule = config
+            .format
+            .pairings(entrants.len(), config.games_per_pairing);
+        let names = entrants
+            .iter()
+            .map(|entrant| entrant.name.clone())
+            .collect();
+        Self {
+            config,
+            entrants,
+            schedule,
+            crosstable: Crosstable::new(names),
+            finished: Vec::new(),
+            next: 0,
+            running: Vec::new(),
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.next >= self.schedule.len() && self.running.is_empty()
+    }
+
+    /// 第 `index` 局使用的后端：内置棋手的种子按局数错开，避免重复同一局
+    fn backend_for_game(&self, entrant: usize, index: usize) -> PlayerBackend {
+        let mut backend = self.entrants[entrant].backend.clone();
+        match &mut backend {
+            PlayerBackend::Heuristic { seed, .. } => *seed = seed.wrapping_add(index as u64),
+            PlayerBackend::Mcts(config) => {
+                config.seed = config.seed.map(|seed| seed.wrapping_add(index as u64))
+            }
+            _ => {}
+        }
+        match self.config.move_time {
+            Some(move_time) => backend.with_move_time(move_time),
+            None => backend,
+        }
+    }
+
+    /// 一局结束，记入交叉表；`margin` 为数子胜负的目数
+    fn finish(
+        &mut self,
+        game: RunningGame,
+        winner: Option<StoneColor>,
+        reason: EndReason,
+        margin: f32,
+    ) {
+        let mut record = SgfRecord::new(self.config.board_size, self.config.komi);
+        record.black_player = self.entrants[game.black].name.clone();
+        record.white_player = self.entrants[game.white].name.clone();
+        record.result = Some(sgf_result(winner, reason, margin));
+        record.moves = game.moves;
+        self.crosstable.record(game.black, game.white, winner);
+        self.finished.push(TournamentGame {
+            index: game.index,
+            black: game.black,
+            white: game.white,
+            winner,
+            reason,
+            record,
+        });
+    }
+}
+
+/// SGF `RE` 属性
+fn sgf_result(winner: Option<StoneColor>, reason: EndReason, margin: f32) -> String {
+    let Some(winner) = winner else {
+        return "0".to_string();
+    };
+    let color = match winner {
+        StoneColor::Black => 'B',
+        StoneColor::White => 'W',
+    };
+    match reason {
+        EndReason::Score => format!("{}+{}", color, margin),
+        EndReason::Resign => format!("{}+R", color),
+        EndReason::Time => format!("{}+T", color),
+    }
+}
+
+/// 按赛程开始新对局，直到达到同时进行的上限
+pub fn start_tournament_games(mut commands: Commands, tournament: Option<ResMut<Tournament>>) {
+    let Some(mut tournament) = tournament else {
+        return;
+    };
+
+    while tournament.running.len() < tournament.config.concurrent_games.max(1)
+        && tournament.next < tournament.schedule.len()
+    {
+        let index = tournament.next;
+        tournament.next += 1;
+        let (black, white) = tournament.schedule[index];
+
+        let board = commands
+            .spawn(GoBoardBundle::new(GoBoardConfig {
+                board_size: tournament.config.board_size,
+                komi: tournament.config.komi,
+                ..default()
+            }))
+            .id();
+        let mut players = Vec::new();
+        let mut failed = None;
+        for (color, entrant) in [(StoneColor::Black, black), (StoneColor::White, white)] {
+            let backend = tournament.backend_for_game(entrant, index);
+            match ComputerPlayer::spawn(board, color, &backend) {
+                Ok(player) => {
+                    let player = match tournament.config.move_time {
+                        Some(move_time) => player.with_move_time(move_time),
+                        None => player,
+                    };
+                    players.push(commands.spawn(player).id());
+                }
+                Err(error) => {
+                    error!(
+                        "Entrant {} failed to start: {}",
+                        tournament.entrants[entrant].name, error
+                    );
+                    failed.get_or_insert(color);
+                }
+            }
+        }
+
+        let game = RunningGame {
+            index,
+            black,
+            white,
+            board,
+            players,
+            moves: Vec::new(),
+            limit_passes: None,
+            turn_started: Instant::now(),
+            forfeit: None,
+        };
+        if let Some(loser) = failed {
+            commands.entity(game.board).despawn();
+            for &player in &game.players {
+                commands.entity(player).despawn();
+            }
+            tournament.finish(game, Some(loser.opposite()), EndReason::Time, 0.0);
+            continue;
+        }
+        tournament.running.push(game);
+    }
+}
+
+/// 执行用时和手数限制：超时或引擎出错的一方认输，手数超限时双方虚手
+pub fn enforce_tournament_limits(
+    tournament: Option<ResMut<Tournament>>,
+    boards: Query<&CurrentTurn, (With<GoBoardRoot>, Without<GameOver>)>,
+    players: Query<&ComputerPlayer>,
+    mut action_events: EventWriter<StoneActionEvent>,
+) {
+    let Some(mut tournament) = tournament else {
+        return;
+    };
+    let grace = tournament
+        .config
+        .move_time
+        .map(|move_time| move_time * 2 + Duration::from_secs(1));
+    let max_moves = tournament.config.max_moves;
+
+    for game in tournament.running.iter_mut() {
+        let Ok(current_turn) = boards.get(game.board) else {
+            continue;
+        };
+        let color = current_turn.0;
+        let resign = |color| StoneActionEvent {
+            board: game.board,
+            action_type: StoneActionType::Resign,
+            position: None,
+            color,
+        };
+
+        let failed = game.players.iter().find_map(|&player| {
+            let player = players.get(player).ok()?;
+            player.has_failed().then_some(player.color)
+        });
+        if let Some(loser) = failed.filter(|_| game.forfeit.is_none()) {
+            game.forfeit = Some(loser);
+            action_events.write(resign(loser));
+        } else if grace.is_some_and(|grace| game.turn_started.elapsed() > grace)
+            && game.forfeit.is_none()
+        {
+            game.forfeit = Some(color);
+            action_events.write(resign(color));
+        } else if game.moves.len() >= max_moves && game.limit_passes != Some(game.moves.len()) {
+            game.limit_passes = Some(game.moves.len());
+            for color in [color, color.opposite()] {
+                action_events.write(StoneActionEvent {
+                    board: game.board,
+                    action_type: StoneActionType::Pass,
+                    position: None,
+                    color,
+                });
+            }
+        }
+    }
+}
+
+/// 记录着法，收到 [`GameEndEvent`] 后结束对局并移除棋盘和棋手
+pub fn record_tournament_games(
+    mut commands: Commands,
+    tournament: Option<ResMut<Tournament>>,
+    boards: Query<&GameHistory, With<GoBoardRoot>>,
+    mut game_end_events: EventReader<GameEndEvent>,
+) {
+    let Some(mut tournament) = tournament else {
+        return;
+    };
+
+    for game in tournament.running.iter_mut() {
+        let Ok(history) = boards.get(game.board) else {
+            continue;
+        };
+        let played = history.played_moves();
+        if played.len() == game.moves.len() {
+            continue;
+        }
+
+        game.moves = played
+            .iter()
+            .map(|played| (played.color, played.position))
+            .collect();
+        game.turn_started = Instant::now();
+    }
+
+    for event in game_end_events.read() {
+        let Some(position) = tournament
+            .running
+            .iter()
+            .position(|game| game.board == event.board)
+        else {
+            continue;
+        };
+        let game = tournament.running.remove(position);
+        let reason = if game.forfeit.is_some() {
+            EndReason::Time
+        } else if game.ended_by_passes() {
+            EndReason::Score
+        } else {
+            EndReason::Resign
+        };
+
+        commands.entity(game.board).despawn();
+        for &player in &game.players {
+            commands.entity(player).despawn();
+        }
+        let margin = (event.black_score - event.white_score).abs();
+        tournament.finish(game, event.winner, reason, margin);
+    }
+}
@@ -1,104 +1,86 @@
 use super::config::BoardSize;
+use crate::go_core::BoardCoordinates;
+use bevy::asset::RenderAssetUsages;
 use bevy::prelude::*;
+use bevy::render::mesh::{Indices, PrimitiveTopology};
 
 /// 坐标转换工具
 pub struct CoordinateUtils;
 
 impl CoordinateUtils {
     /// 将世界坐标转换为棋盘坐标
+    ///
+    /// 通过棋盘根节点的 `GlobalTransform` 逆变换到棋盘局部空间，与相机和窗口无关
     pub fn world_to_board(
         world_pos: Vec2,
+        root_transform: &GlobalTransform,
         board_size: BoardSize,
-        window_size: f32,
-        adaptive_padding: bool,
+        board_extent: f32,
     ) -> Option<(i32, i32)> {
-        let board_size_value = board_size.get_value();
-
-        let padding = if adaptive_padding && window_size > 1400.0 {
-            50.0
-        } else {
-            100.0
-        };
-
-        let board_background_size = window_size - padding;
-        let cell_size = board_background_size / (board_size_value as f32 + 1.0);
-        let board_size_pixels = (board_size_value - 1) as f32 * cell_size;
-        let half_board = board_size_pixels / 2.0;
-
-        let board_x = ((world_pos.x + half_board) / cell_size).round() as i32;
-        let board_y = ((half_board - world_pos.y) / cell_size).round() as i32;
-
-        if board_x >= 0 && board_x < board_size_value && board_y >= 0 && board_y < board_size_value
-        {
-            Some((board_x, board_y))
-        } else {
-            None
-        }
+        let local_pos = root_transform
+            .affine()
+            .inverse()
+            .transform_point3(world_pos.extend(0.0));
+        Self::local_to_board(local_pos.truncate(), board_size, board_extent)
     }
 
     /// 将棋盘坐标转换为世界坐标
     pub fn board_to_world(
         board_pos: (i32, i32),
+        root_transform: &GlobalTransform,
         board_size: BoardSize,
-        window_size: f32,
-        adaptive_padding: bool,
+        board_extent: f32,
     ) -> Vec3 {
+        root_transform.transform_point(Self::board_to_local(board_pos, board_size, board_extent))
+    }
+
+    /// 将棋盘局部坐标转换为棋盘坐标
+    pub fn local_to_board(
+        local_pos: Vec2,
+        board_size: BoardSize,
+        board_extent: f32,
+    ) -> Option<(i32, i32)> {
         let board_size_value = board_size.get_value();
+        let metrics = RenderUtils::calculate_board_metrics(board_extent, board_size);
+
+        let board_x = ((local_pos.x + metrics.half_board) / metrics.cell_size).round() as i32;
+        let board_y = ((metrics.half_board - local_pos.y) / metrics.cell_size).round() as i32;
 
-        let padding = if adaptive_padding && window_size > 1400.0 {
-            50.0
+        if (0..board_size_value).contains(&board_x) && (0..board_size_value).contains(&board_y) {
+            Some((board_x, board_y))
         } else {
-            100.0
-        };
+            None
+        }
+    }
 
-        let board_background_size = window_size - padding;
-        let cell_size = board_background_size / (board_size_value as f32 + 1.0);
-        let board_size_pixels = (board_size_value - 1) as f32 * cell_size;
-        let half_board = board_size_pixels / 2.0;
+    /// 将棋盘坐标转换为棋盘局部坐标
+    pub fn board_to_local(board_pos: (i32, i32), board_size: BoardSize, board_extent: f32) -> Vec3 {
+        let metrics = RenderUtils::calculate_board_metrics(board_extent, board_size);
 
-        let x = board_pos.0 as f32 * cell_size - half_board;
-        let y = half_board - board_pos.1 as f32 * cell_size;
+        let x = board_pos.0 as f32 * metrics.cell_size - metrics.half_board;
+        let y = metrics.half_board - board_pos.1 as f32 * metrics.cell_size;
 
         Vec3::new(x, y, 0.0)
     }
 
     /// 将棋盘坐标转换为SGF格式坐标
     pub fn board_to_sgf(x: i32, y: i32) -> String {
-        let col = (b'a' + x as u8) as char;
-        let row = (b'a' + y as u8) as char;
-        format!("{}{}", col, row)
+        BoardCoordinates::board_to_sgf(x, y)
     }
 
     /// 将SGF格式坐标转换为棋盘坐标
     pub fn sgf_to_board(sgf: &str) -> Option<(i32, i32)> {
-        if sgf.len() != 2 {
-            return None;
-        }
-
-        let chars: Vec<char> = sgf.chars().collect();
-        let x = (chars[0] as u8 - b'a') as i32;
-        let y = (chars[1] as u8 - b'a') as i32;
-
-        if x >= 0 && x < 19 && y >= 0 && y < 19 {
-            Some((x, y))
-        } else {
-            None
-        }
+        BoardCoordinates::sgf_to_board(sgf)
     }
 
     /// 将棋盘坐标转换为人类可读格式（如 "A1", "K10"）
     pub fn board_to_human(x: i32, y: i32, board_size: BoardSize) -> String {
-        let letters = [
-            'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R',
-            'S', 'T',
-        ];
-        if x < letters.len() as i32 {
-            let col = letters[x as usize];
-            let row = board_size.get_value() - y;
-            format!("{}{}", col, row)
-        } else {
-            format!("({},{})", x, y)
-        }
+        BoardCoordinates::board_to_human(x, y, board_size)
+    }
+
+    /// 将人类可读格式（如 "Q16"，不区分大小写）转换为棋盘坐标
+    pub fn human_to_board(text: &str, board_size: BoardSize) -> Option<(i32, i32)> {
+        BoardCoordinates::human_to_board(text, board_size)
     }
 }
 
@@ -107,37 +89,69 @@ pub struct RenderUtils;
 
 impl RenderUtils {
     /// 计算棋盘尺寸参数
-    pub fn calculate_board_metrics(
-        window_size: f32,
-        board_size: BoardSize,
-        adaptive_padding: bool,
-    ) -> BoardMetrics {
+    pub fn calculate_board_metrics(board_extent: f32, board_size: BoardSize) -> BoardMetrics {
         let board_size_value = board_size.get_value();
 
-        let padding = if adaptive_padding && window_size > 1400.0 {
-            50.0
-        } else {
-            100.0
-        };
-
-        let board_background_size = window_size - padding;
+        let board_background_size = board_extent;
         let cell_size = board_background_size / (board_size_value as f32 + 1.0);
         let board_size_pixels = (board_size_value - 1) as f32 * cell_size;
         let half_board = board_size_pixels / 2.0;
 
         BoardMetrics {
-            padding,
             board_background_size,
             cell_size,
             board_size_pixels,
             half_board,
         }
     }
+
+    /// 将所有网格线合并为单个网格
+    pub fn build_grid_mesh(board_size: i32, metrics: &BoardMetrics, line_width: f32) -> Mesh {
+        let half_width = line_width / 2.0;
+        let half_length = metrics.board_size_pixels / 2.0;
+
+        let mut positions = Vec::with_capacity(board_size as usize * 8);
+        let mut indices = Vec::with_capacity(board_size as usize * 12);
+
+        let mut push_quad = |min: Vec2, max: Vec2| {
+            let base = positions.len() as u32;
+            positions.push([min.x, min.y, 0.0]);
+            positions.push([max.x, min.y, 0.0]);
+            positions.push([max.x, max.y, 0.0]);
+            positions.push([min.x, max.y, 0.0]);
+            indices.extend_from_slice(&[base, base + 1, base + 2, base, base + 2, base + 3]);
+        };
+
+        for i in 0..board_size {
+            let offset = i as f32 * metrics.cell_size - metrics.half_board;
+
+            // 垂直线
+            push_quad(
+                Vec2::new(offset - half_width, -half_length),
+                Vec2::new(offset + half_width, half_length),
+            );
+
+            // 水平线
+            push_quad(
+                Vec2::new(-half_length, offset - half_width),
+                Vec2::new(half_length, offset + half_width),
+            );
+        }
+
+        let vertex_count = positions.len();
+        Mesh::new(
+            PrimitiveTopology::TriangleList,
+            RenderAssetUsages::default(),
+        )
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, vec![[0.0, 0.0, 1.0]; vertex_count])
+        .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, vec![[0.0, 0.0]; vertex_count])
+        .with_inserted_indices(Indices::U32(indices))
+    }
 }
 
 /// 棋盘度量参数
 pub struct BoardMetrics {
-    pub padding: f32,
     pub board_background_size: f32,
     pub cell_size: f32,
     pub board_size_pixels: f32,
@@ -0,0 +1,175 @@
+use super::board::{BoardSize, Move, StoneColor};
+use super::coordinates::BoardCoordinates;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+// KataGo JSON 分析引擎协议：每行一个 JSON 查询，引擎按行返回结果
+// 胜率、目差和归属按分析配置默认的 `reportAnalysisWinratesAs = BLACK` 解释，即黑方视角
+
+/// 分析查询
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalysisQuery {
+    pub id: String,
+    /// 按顺序的着法，如 `["B", "D4"]`
+    pub moves: Vec<(String, String)>,
+    pub rules: String,
+    pub komi: f32,
+    pub board_x_size: i32,
+    pub board_y_size: i32,
+    /// 要分析的手数，`moves.len()` 表示最后的局面
+    pub analyze_turns: Vec<usize>,
+    pub include_ownership: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_visits: Option<u32>,
+}
+
+impl AnalysisQuery {
+    /// 分析已下着法之后的局面
+    pub fn new(id: impl Into<String>, board_size: BoardSize, komi: f32, moves: &[Move]) -> Self {
+        let size = board_size.get_value();
+        Self {
+            id: id.into(),
+            moves: moves
+                .iter()
+                .map(|played| {
+                    let vertex = match played.position {
+                        Some((x, y)) => BoardCoordinates::board_to_human(x, y, board_size),
+                        None => "pass".to_string(),
+                    };
+                    (player_name(played.color).to_string(), vertex)
+                })
+                .collect(),
+            rules: "chinese".to_string(),
+            komi,
+            board_x_size: size,
+            board_y_size: size,
+            analyze_turns: vec![moves.len()],
+            include_ownership: true,
+            max_visits: None,
+        }
+    }
+
+    /// 序列化为发给引擎的一行
+    pub fn to_line(&self) -> String {
+        serde_json::to_string(self).expect("analysis query is always serializable")
+    }
+
+    /// 终止某个查询的请求行
+    pub fn terminate_line(id: &str, terminate_id: &str) -> String {
+        serde_json::json!({ "id": id, "action": "terminate", "terminateId": terminate_id })
+            .to_string()
+    }
+}
+
+/// 候选着法
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MoveInfo {
+    /// GTP 坐标或 `"pass"`
+    #[serde(rename = "move")]
+    pub vertex: String,
+    pub visits: u32,
+    pub winrate: f32,
+    pub score_lead: f32,
+    /// 引擎给出的排名，0 为最佳
+    pub order: u32,
+    #[serde(default)]
+    pub pv: Vec<String>,
+}
+
+impl MoveInfo {
+    /// 棋盘坐标，虚手时为 `None`
+    pub fn position(&self, board_size: BoardSize) -> Option<(i32, i32)> {
+        BoardCoordinates::human_to_board(&self.vertex, board_size)
+    }
+}
+
+/// 根节点（当前局面）的统计
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RootInfo {
+    pub winrate: f32,
+    pub score_lead: f32,
+    pub visits: u32,
+    /// 轮到谁，`"B"` 或 `"W"`
+    pub current_player: String,
+}
+
+/// 分析结果
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalysisResponse {
+    pub id: String,
+    pub turn_number: usize,
+    pub move_infos: Vec<MoveInfo>,
+    pub root_info: RootInfo,
+    /// 按行优先排列（第一行是棋盘最上方），正值归黑、负值归白，范围 [-1, 1]
+    #[serde(default)]
+    pub ownership: Vec<f32>,
+    /// 引擎仍在搜索中的中间结果
+    #[serde(default)]
+    pub is_during_search: bool,
+}
+
+impl AnalysisResponse {
+    /// 解析引擎输出的一行；警告和终止确认等非结果行返回 `None`
+    pub fn parse_line(line: &str) -> Result<Option<Self>, AnalysisError> {
+        let value: serde_json::Value = serde_json::from_str(line).map_err(AnalysisError::Json)?;
+        if let Some(error) = value.get("error") {
+            return Err(AnalysisError::Engine(error.to_string()));
+        }
+        if value.get("moveInfos").is_none() {
+            return Ok(None);
+        }
+        serde_json::from_value(value)
+            .map(Some)
+            .map_err(AnalysisError::Json)
+    }
+
+    /// 某一点的归属，正值归黑、负值归白
+    pub fn ownership_at(&self, x: i32, y: i32, board_size: BoardSize) -> Option<f32> {
+        let size = board_size.get_value();
+        if !(0..size).contains(&x) || !(0..size).contains(&y) {
+            return None;
+        }
+        self.ownership.get((y * size + x) as usize).copied()
+    }
+
+    /// 当前局面轮到的一方
+    pub fn current_player(&self) -> StoneColor {
+        if self.root_info.current_player == "W" {
+            StoneColor::White
+        } else {
+            StoneColor::Black
+        }
+    }
+}
+
+/// 分析引擎通信失败的原因
+#[derive(Debug)]
+pub enum AnalysisError {
+    /// 无法解析的 JSON
+    Json(serde_json::Error),
+    /// 引擎返回的错误
+    Engine(String),
+}
+
+impl fmt::Display for AnalysisError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnalysisError::Json(error) => write!(f, "invalid analysis response: {}", error),
+            AnalysisError::Engine(message) => write!(f, "analysis engine error: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for AnalysisError {}
+
+/// 协议中的棋手名称
+fn player_name(color: StoneColor) -> &'static str {
+    match color {
+        StoneColor::Black => "B",
+        StoneColor::White => "W",
+    }
+}
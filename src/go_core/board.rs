@@ -0,0 +1,258 @@
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+#[cfg(feature = "bevy")]
+use bevy::prelude::Component;
+
+/// 棋子颜色
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum StoneColor {
+    Black,
+    White,
+}
+
+impl StoneColor {
+    pub fn opposite(&self) -> Self {
+        match self {
+            StoneColor::Black => StoneColor::White,
+            StoneColor::White => StoneColor::Black,
+        }
+    }
+}
+
+/// 棋盘大小枚举
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BoardSize {
+    Nine = 9,
+    Thirteen = 13,
+    Nineteen = 19,
+}
+
+impl BoardSize {
+    pub fn get_value(&self) -> i32 {
+        *self as i32
+    }
+
+    /// 根据边长获取棋盘大小，只支持 9、13、19 路
+    pub fn from_value(value: i32) -> Option<Self> {
+        match value {
+            9 => Some(BoardSize::Nine),
+            13 => Some(BoardSize::Thirteen),
+            19 => Some(BoardSize::Nineteen),
+            _ => None,
+        }
+    }
+
+    /// 获取星位点坐标
+    pub fn get_star_points(&self) -> Vec<(i32, i32)> {
+        match self {
+            BoardSize::Nine => vec![(2, 2), (2, 6), (4, 4), (6, 2), (6, 6)],
+            BoardSize::Thirteen => vec![(3, 3), (3, 9), (6, 6), (9, 3), (9, 9)],
+            BoardSize::Nineteen => vec![
+                (3, 3),
+                (3, 9),
+                (3, 15),
+                (9, 3),
+                (9, 9),
+                (9, 15),
+                (15, 3),
+                (15, 9),
+                (15, 15),
+            ],
+        }
+    }
+}
+
+/// 棋盘状态
+///
+/// 启用 `bevy` 特性时作为组件挂在棋盘根节点上
+#[derive(Clone)]
+#[cfg_attr(feature = "bevy", derive(Component))]
+pub struct BoardState {
+    pub stones: [[Option<StoneColor>; 19]; 19],
+    pub move_numbers: [[Option<usize>; 19]; 19],
+    pub board_size: BoardSize,
+    pub move_count: usize,
+    pub captured_black: usize,
+    pub captured_white: usize,
+    pub ko_position: Option<(i32, i32)>,
+    pub last_move: Option<(i32, i32)>,
+    /// 连续虚手次数，落子后归零
+    pub consecutive_passes: usize,
+}
+
+impl Default for BoardState {
+    fn default() -> Self {
+        Self {
+            stones: [[None; 19]; 19],
+            move_numbers: [[None; 19]; 19],
+            board_size: BoardSize::Nineteen,
+            move_count: 0,
+            captured_black: 0,
+            captured_white: 0,
+            ko_position: None,
+            last_move: None,
+            consecutive_passes: 0,
+        }
+    }
+}
+
+impl BoardState {
+    /// 创建新的棋盘状态
+    pub fn new(board_size: BoardSize) -> Self {
+        Self {
+            board_size,
+            ..Default::default()
+        }
+    }
+
+    /// 局面哈希：只看棋盘大小和棋子分布，用于判断全局同形
+    pub fn position_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.board_size.get_value().hash(&mut hasher);
+        let size = self.board_size.get_value() as usize;
+        for column in &self.stones[..size] {
+            column[..size].hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+
+    /// 获取指定位置的棋子
+    pub fn get_stone(&self, x: i32, y: i32) -> Option<StoneColor> {
+        if (0..19).contains(&x) && (0..19).contains(&y) {
+            self.stones[x as usize][y as usize]
+        } else {
+            None
+        }
+    }
+
+    /// 放置棋子
+    pub fn place_stone(&mut self, x: i32, y: i32, color: StoneColor) -> bool {
+        let size = self.board_size.get_value();
+        if (0..size).contains(&x)
+            && (0..size).contains(&y)
+            && self.stones[x as usize][y as usize].is_none()
+        {
+            self.move_count += 1;
+            self.stones[x as usize][y as usize] = Some(color);
+            self.move_numbers[x as usize][y as usize] = Some(self.move_count);
+            self.last_move = Some((x, y));
+            self.consecutive_passes = 0;
+            return true;
+        }
+        false
+    }
+
+    /// 移除棋子
+    pub fn remove_stone(&mut self, x: i32, y: i32) {
+        if (0..19).contains(&x) && (0..19).contains(&y) {
+            self.stones[x as usize][y as usize] = None;
+            self.move_numbers[x as usize][y as usize] = None;
+        }
+    }
+
+    /// 清空棋盘
+    pub fn clear(&mut self) {
+        self.stones = [[None; 19]; 19];
+        self.move_numbers = [[None; 19]; 19];
+        self.move_count = 0;
+        self.captured_black = 0;
+        self.captured_white = 0;
+        self.ko_position = None;
+        self.last_move = None;
+        self.consecutive_passes = 0;
+    }
+
+    /// 获取相邻位置
+    pub fn get_neighbors(&self, x: i32, y: i32) -> Vec<(i32, i32)> {
+        let mut neighbors = Vec::new();
+        let directions = [(0, 1), (0, -1), (1, 0), (-1, 0)];
+
+        for (dx, dy) in directions {
+            let nx = x + dx;
+            let ny = y + dy;
+            if nx >= 0
+                && nx < self.board_size.get_value()
+                && ny >= 0
+                && ny < self.board_size.get_value()
+            {
+                neighbors.push((nx, ny));
+            }
+        }
+
+        neighbors
+    }
+}
+
+/// 游戏历史记录
+///
+/// 启用 `bevy` 特性时作为组件挂在棋盘根节点上
+#[derive(Default, Clone)]
+#[cfg_attr(feature = "bevy", derive(Component))]
+pub struct GameHistory {
+    pub moves: Vec<Move>,
+    pub current_index: usize,
+}
+
+impl GameHistory {
+    /// 记录新着法，撤销后再落子会丢弃原来的后续着法
+    pub fn record(&mut self, played: Move) {
+        self.moves.truncate(self.current_index);
+        self.moves.push(played);
+        self.current_index = self.moves.len();
+    }
+
+    /// 记录刚在 `board_state` 上下出的一手，手数和局面哈希取自落子后的局面
+    pub fn record_after(
+        &mut self,
+        board_state: &BoardState,
+        position: Option<(i32, i32)>,
+        color: StoneColor,
+        captured_stones: Vec<(i32, i32)>,
+    ) {
+        self.record(Move {
+            position,
+            color,
+            captured_stones,
+            move_number: board_state.move_count,
+            position_hash: board_state.position_hash(),
+        });
+    }
+
+    /// 撤销最后一手，没有可撤销的着法时返回 `false`
+    pub fn undo(&mut self) -> bool {
+        if self.current_index == 0 {
+            return false;
+        }
+        self.current_index -= 1;
+        true
+    }
+
+    /// 当前局面之前已执行的着法
+    pub fn played_moves(&self) -> &[Move] {
+        &self.moves[..self.current_index]
+    }
+
+    /// 局面是否在本局中出现过（含开局的空棋盘），用于全局同形禁着
+    pub fn repeats_position(&self, board_state: &BoardState) -> bool {
+        let hash = board_state.position_hash();
+        BoardState::new(board_state.board_size).position_hash() == hash
+            || self
+                .played_moves()
+                .iter()
+                .any(|played| played.position_hash == hash)
+    }
+}
+
+/// 单个着法记录
+#[derive(Clone)]
+pub struct Move {
+    /// 落子位置，虚手时为 `None`
+    pub position: Option<(i32, i32)>,
+    pub color: StoneColor,
+    pub captured_stones: Vec<(i32, i32)>,
+    pub move_number: usize,
+    /// 这一手之后的局面哈希，见 [`BoardState::position_hash`]
+    pub position_hash: u64,
+}
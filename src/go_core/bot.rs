@@ -0,0 +1,91 @@
+use super::board::{BoardState, GameHistory, StoneColor};
+use super::rules::GoBoardRules;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+
+/// 生成的着法
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeneratedMove {
+    Play(i32, i32),
+    Pass,
+    Resign,
+}
+
+/// 着法生成器，GTP 引擎的 `genmove` 和对局中的电脑棋手都通过它选点
+pub trait MoveGenerator: Send {
+    /// 生成器名称
+    fn name(&self) -> &str;
+
+    /// 为 `color` 方在当前局面选一手棋，`history` 是到当前局面为止的着法
+    ///
+    /// 内置棋手总是避开重复本局局面的点，不论对局是否采用全局同形禁着
+    fn generate_move(
+        &mut self,
+        board_state: &BoardState,
+        history: &GameHistory,
+        color: StoneColor,
+        komi: f32,
+    ) -> GeneratedMove;
+}
+
+/// 对手刚虚手且按当前局面数子已经领先时，跟着虚手结束对局
+pub fn should_pass_back(board_state: &BoardState, color: StoneColor, komi: f32) -> bool {
+    if board_state.consecutive_passes == 0 {
+        return false;
+    }
+    let (black_score, white_score) = GoBoardRules::area_score(board_state, komi);
+    let lead = match color {
+        StoneColor::Black => black_score - white_score,
+        StoneColor::White => white_score - black_score,
+    };
+    lead > 0.0
+}
+
+/// 随机着法生成器：在不填己方眼位和双活公气、不重复局面的合法点中随机选择，没有可下的点时虚手
+pub struct RandomMoveGenerator {
+    rng: StdRng,
+}
+
+impl RandomMoveGenerator {
+    /// 使用固定种子，结果可复现
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    /// 使用系统熵作为种子
+    pub fn from_entropy() -> Self {
+        Self {
+            rng: StdRng::from_entropy(),
+        }
+    }
+}
+
+impl MoveGenerator for RandomMoveGenerator {
+    fn name(&self) -> &str {
+        "random"
+    }
+
+    fn generate_move(
+        &mut self,
+        board_state: &BoardState,
+        history: &GameHistory,
+        color: StoneColor,
+        _komi: f32,
+    ) -> GeneratedMove {
+        let seki = GoBoardRules::seki_liberties(board_state);
+        let candidates: Vec<(i32, i32)> =
+            GoBoardRules::legal_moves_with_history(board_state, history, color)
+                .into_iter()
+                .filter(|&(x, y)| !GoBoardRules::is_eye_like(board_state, x, y, color))
+                .filter(|point| !seki.contains(point))
+                .collect();
+
+        match candidates.choose(&mut self.rng) {
+            Some(&(x, y)) => GeneratedMove::Play(x, y),
+            None => GeneratedMove::Pass,
+        }
+    }
+}
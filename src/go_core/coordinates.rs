@@ -0,0 +1,68 @@
+use super::board::BoardSize;
+
+/// 棋盘坐标与文本坐标（SGF、人类可读格式）之间的转换
+pub struct BoardCoordinates;
+
+impl BoardCoordinates {
+    /// 将棋盘坐标转换为SGF格式坐标
+    pub fn board_to_sgf(x: i32, y: i32) -> String {
+        let col = (b'a' + x as u8) as char;
+        let row = (b'a' + y as u8) as char;
+        format!("{}{}", col, row)
+    }
+
+    /// 将SGF格式坐标转换为棋盘坐标
+    pub fn sgf_to_board(sgf: &str) -> Option<(i32, i32)> {
+        if sgf.len() != 2 {
+            return None;
+        }
+
+        let chars: Vec<char> = sgf.chars().collect();
+        let x = chars[0] as i32 - 'a' as i32;
+        let y = chars[1] as i32 - 'a' as i32;
+
+        if (0..19).contains(&x) && (0..19).contains(&y) {
+            Some((x, y))
+        } else {
+            None
+        }
+    }
+
+    /// 将棋盘坐标转换为人类可读格式（如 "A1", "K10"）
+    pub fn board_to_human(x: i32, y: i32, board_size: BoardSize) -> String {
+        if (0..HUMAN_COLUMNS.len() as i32).contains(&x) {
+            let col = HUMAN_COLUMNS[x as usize];
+            let row = board_size.get_value() - y;
+            format!("{}{}", col, row)
+        } else {
+            format!("({},{})", x, y)
+        }
+    }
+
+    /// 将人类可读格式（如 "Q16"，不区分大小写）转换为棋盘坐标，与 `board_to_human` 互逆
+    pub fn human_to_board(text: &str, board_size: BoardSize) -> Option<(i32, i32)> {
+        let text = text.trim();
+        let mut chars = text.chars();
+        let column = chars.next()?.to_ascii_uppercase();
+        let x = HUMAN_COLUMNS.iter().position(|&c| c == column)? as i32;
+        // 行号只能是不以 0 开头的数字，`parse` 会接受 "+5" 和 "05"
+        let row = chars.as_str();
+        if row.starts_with('0') || !row.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let row: i32 = row.parse().ok()?;
+
+        let size = board_size.get_value();
+        let y = size - row;
+        if x < size && (0..size).contains(&y) {
+            Some((x, y))
+        } else {
+            None
+        }
+    }
+}
+
+/// 人类可读坐标的列字母（跳过 I）
+const HUMAN_COLUMNS: [char; 19] = [
+    'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T',
+];
@@ -0,0 +1,98 @@
+use super::board::{BoardSize, BoardState, Move, StoneColor};
+use super::rules::GoBoardRules;
+
+// 策略/价值网络的输入特征平面（AlphaGo Zero 格式）
+// 最近 8 个局面各两层（轮到方的棋子、对方的棋子），最后一层轮到黑方时全为 1
+
+/// 输入包含的局面数
+pub const HISTORY_LENGTH: usize = 8;
+
+/// 输入平面数
+pub const FEATURE_PLANES: usize = HISTORY_LENGTH * 2 + 1;
+
+/// 网络输入特征平面
+///
+/// 按 `[平面, y, x]` 行优先排列，形状为 `[1, FEATURE_PLANES, size, size]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeaturePlanes {
+    pub board_size: BoardSize,
+    pub data: Vec<f32>,
+}
+
+impl FeaturePlanes {
+    /// 从开局重放着法，取最近 [`HISTORY_LENGTH`] 个局面
+    pub fn from_moves(board_size: BoardSize, moves: &[Move], to_play: StoneColor) -> Self {
+        let mut board_state = BoardState::new(board_size);
+        let mut positions = vec![board_state.clone()];
+        for played in moves {
+            match played.position {
+                // 关闭提子或打劫规则时下出的棋按原样摆上
+                Some((x, y)) => {
+                    if GoBoardRules::play_move(&mut board_state, x, y, played.color).is_err() {
+                        board_state.place_stone(x, y, played.color);
+                    }
+                }
+                // 虚手也占一个历史局面
+                None => GoBoardRules::pass(&mut board_state),
+            }
+            positions.push(board_state.clone());
+        }
+
+        Self::from_positions(&positions, to_play)
+    }
+
+    /// 由按时间顺序排列的局面构造，取最后 [`HISTORY_LENGTH`] 个
+    ///
+    /// `positions` 不能为空
+    pub fn from_positions(positions: &[BoardState], to_play: StoneColor) -> Self {
+        let newest_first: Vec<&BoardState> = positions.iter().rev().take(HISTORY_LENGTH).collect();
+        Self::encode(
+            positions[positions.len() - 1].board_size,
+            &newest_first,
+            to_play,
+        )
+    }
+
+    /// 只有当前局面时，更早的历史平面全为 0
+    pub fn from_board(board_state: &BoardState, to_play: StoneColor) -> Self {
+        Self::encode(board_state.board_size, &[board_state], to_play)
+    }
+
+    /// 张量形状
+    pub fn shape(&self) -> [usize; 4] {
+        let size = self.board_size.get_value() as usize;
+        [1, FEATURE_PLANES, size, size]
+    }
+
+    /// 某一平面上某一点的值
+    pub fn get(&self, plane: usize, x: i32, y: i32) -> f32 {
+        let size = self.board_size.get_value() as usize;
+        self.data[plane * size * size + y as usize * size + x as usize]
+    }
+
+    /// `positions` 从最新的局面开始
+    fn encode(board_size: BoardSize, positions: &[&BoardState], to_play: StoneColor) -> Self {
+        let size = board_size.get_value() as usize;
+        let area = size * size;
+        let mut data = vec![0.0; FEATURE_PLANES * area];
+
+        for (age, position) in positions.iter().take(HISTORY_LENGTH).enumerate() {
+            for y in 0..size {
+                for x in 0..size {
+                    let plane = match position.stones[x][y] {
+                        Some(color) if color == to_play => age * 2,
+                        Some(_) => age * 2 + 1,
+                        None => continue,
+                    };
+                    data[plane * area + y * size + x] = 1.0;
+                }
+            }
+        }
+
+        if to_play == StoneColor::Black {
+            data[(FEATURE_PLANES - 1) * area..].fill(1.0);
+        }
+
+        Self { board_size, data }
+    }
+}
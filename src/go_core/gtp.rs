@@ -0,0 +1,372 @@
+use super::board::{BoardSize, BoardState, GameHistory, StoneColor};
+use super::bot::{GeneratedMove, MoveGenerator};
+use super::coordinates::BoardCoordinates;
+use super::rules::GoBoardRules;
+use super::scoring::DEFAULT_KOMI;
+use std::io::{self, BufRead, Write};
+
+/// 支持的 GTP 命令
+const KNOWN_COMMANDS: &[&str] = &[
+    "protocol_version",
+    "name",
+    "version",
+    "known_command",
+    "list_commands",
+    "quit",
+    "boardsize",
+    "clear_board",
+    "komi",
+    "play",
+    "genmove",
+    "undo",
+    "showboard",
+    "final_score",
+    "fixed_handicap",
+];
+
+/// GTP v2 引擎：用规则引擎维护棋盘，`genmove` 交给可替换的着法生成器
+///
+/// # 使用示例
+/// ```rust,ignore
+/// let mut engine = GtpEngine::new(Box::new(RandomMoveGenerator::new(42)));
+/// engine.run(io::stdin().lock(), io::stdout().lock())?;
+/// ```
+pub struct GtpEngine {
+    board_state: BoardState,
+    komi: f32,
+    undo_stack: Vec<BoardState>,
+    /// 与 `undo_stack` 同步的着法记录，交给着法生成器
+    history: GameHistory,
+    /// 是否采用全局同形禁着
+    superko: bool,
+    generator: Box<dyn MoveGenerator>,
+}
+
+/// 一条命令的响应
+pub struct GtpReply {
+    /// 完整的响应文本（含结尾空行）
+    pub text: String,
+    /// 是否收到 `quit`
+    pub quit: bool,
+}
+
+impl GtpEngine {
+    pub fn new(generator: Box<dyn MoveGenerator>) -> Self {
+        Self {
+            board_state: BoardState::new(BoardSize::Nineteen),
+            komi: DEFAULT_KOMI,
+            undo_stack: Vec::new(),
+            history: GameHistory::default(),
+            superko: false,
+            generator,
+        }
+    }
+
+    /// 采用全局同形禁着：`play` 拒绝重复本局局面的着法
+    pub fn with_superko_rule(mut self, enable: bool) -> Self {
+        self.superko = enable;
+        self
+    }
+
+    /// 当前棋盘
+    pub fn board_state(&self) -> &BoardState {
+        &self.board_state
+    }
+
+    /// 当前贴目
+    pub fn komi(&self) -> f32 {
+        self.komi
+    }
+
+    /// 从输入逐行读取命令并写出响应，直到 `quit` 或输入结束
+    pub fn run(&mut self, input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+        for line in input.lines() {
+            if let Some(reply) = self.handle_line(&line?) {
+                output.write_all(reply.text.as_bytes())?;
+                output.flush()?;
+                if reply.quit {
+                    break;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// 处理一行输入；空行和注释返回 `None`，只有 id 没有命令时按未知命令回复
+    pub fn handle_line(&mut self, line: &str) -> Option<GtpReply> {
+        let line = line.split('#').next().unwrap_or("");
+        let line: String = line
+            .chars()
+            .filter(|c| !c.is_control() || *c == '\t')
+            .map(|c| if c == '\t' { ' ' } else { c })
+            .collect();
+
+        let mut tokens = line.split_whitespace();
+        let first = tokens.next()?;
+        let (id, command) = match first.parse::<u32>() {
+            Ok(id) => (Some(id), tokens.next().unwrap_or("")),
+            Err(_) => (None, first),
+        };
+        let args: Vec<&str> = tokens.collect();
+
+        let result = self.execute(command, &args);
+        let id = id.map(|id| id.to_string()).unwrap_or_default();
+        let text = match result {
+            Ok(response) if response.is_empty() => format!("={}\n\n", id),
+            Ok(response) => format!("={} {}\n\n", id, response),
+            Err(message) => format!("?{} {}\n\n", id, message),
+        };
+
+        Some(GtpReply {
+            text,
+            quit: command == "quit",
+        })
+    }
+
+    /// 执行一条命令
+    fn execute(&mut self, command: &str, args: &[&str]) -> Result<String, String> {
+        match command {
+            "protocol_version" => Ok("2".to_string()),
+            "name" => Ok(format!("black-white-legends ({})", self.generator.name())),
+            "version" => Ok(env!("CARGO_PKG_VERSION").to_string()),
+            "known_command" => {
+                let known = args
+                    .first()
+                    .is_some_and(|name| KNOWN_COMMANDS.contains(name));
+                Ok(known.to_string())
+            }
+            "list_commands" => Ok(KNOWN_COMMANDS.join("\n")),
+            "quit" => Ok(String::new()),
+            "boardsize" => {
+                let size = args
+                    .first()
+                    .and_then(|value| value.parse::<i32>().ok())
+                    .ok_or("boardsize not an integer")?;
+                let board_size = BoardSize::from_value(size).ok_or("unacceptable size")?;
+                self.board_state = BoardState::new(board_size);
+                self.undo_stack.clear();
+                self.history = GameHistory::default();
+                Ok(String::new())
+            }
+            "clear_board" => {
+                self.board_state = BoardState::new(self.board_state.board_size);
+                self.undo_stack.clear();
+                self.history = GameHistory::default();
+                Ok(String::new())
+            }
+            "komi" => {
+                self.komi = args
+                    .first()
+                    .and_then(|value| value.parse::<f32>().ok())
+                    .ok_or("komi not a float")?;
+                Ok(String::new())
+            }
+            "play" => {
+                let (color, vertex) = match args {
+                    [color, vertex, ..] => (parse_color(color)?, *vertex),
+                    _ => return Err("invalid color or coordinate".to_string()),
+                };
+                self.play(color, vertex)
+            }
+            "genmove" => {
+                let color = parse_color(args.first().ok_or("invalid color")?)?;
+                Ok(self.genmove(color))
+            }
+            "undo" => {
+                let previous = self.undo_stack.pop().ok_or("cannot undo")?;
+                self.board_state = previous;
+                self.history.undo();
+                Ok(String::new())
+            }
+            "showboard" => Ok(format!("\n{}", self.render_board())),
+            "final_score" => {
+                let (black, white) = GoBoardRules::area_score(&self.board_state, self.komi);
+                Ok(format_score(black - white))
+            }
+            "fixed_handicap" => {
+                let stones = args
+                    .first()
+                    .and_then(|value| value.parse::<usize>().ok())
+                    .ok_or("handicap not an integer")?;
+                self.fixed_handicap(stones)
+            }
+            _ => Err("unknown command".to_string()),
+        }
+    }
+
+    /// `play` 命令
+    fn play(&mut self, color: StoneColor, vertex: &str) -> Result<String, String> {
+        let mut next = self.board_state.clone();
+
+        let (position, captured) = if vertex.eq_ignore_ascii_case("pass") {
+            GoBoardRules::pass(&mut next);
+            (None, Vec::new())
+        } else {
+            let (x, y) = BoardCoordinates::human_to_board(vertex, next.board_size)
+                .ok_or("invalid coordinate")?;
+            let captured = GoBoardRules::play_move(&mut next, x, y, color)
+                .and_then(|captured| {
+                    if self.superko {
+                        GoBoardRules::check_superko(&next, &self.history)?;
+                    }
+                    Ok(captured)
+                })
+                .map_err(|reason| format!("illegal move ({})", reason))?;
+            (Some((x, y)), captured)
+        };
+
+        self.history.record_after(&next, position, color, captured);
+        self.undo_stack
+            .push(std::mem::replace(&mut self.board_state, next));
+        Ok(String::new())
+    }
+
+    /// `genmove` 命令
+    fn genmove(&mut self, color: StoneColor) -> String {
+        let generated =
+            self.generator
+                .generate_move(&self.board_state, &self.history, color, self.komi);
+
+        let mut next = self.board_state.clone();
+        let played = match generated {
+            GeneratedMove::Resign => return "resign".to_string(),
+            GeneratedMove::Play(x, y) => GoBoardRules::play_move(&mut next, x, y, color)
+                .and_then(|captured| {
+                    if self.superko {
+                        GoBoardRules::check_superko(&next, &self.history)?;
+                    }
+                    Ok(captured)
+                })
+                .ok()
+                .map(|captured| ((x, y), captured)),
+            GeneratedMove::Pass => None,
+        };
+        let vertex = match played {
+            Some(((x, y), captured)) => {
+                self.history
+                    .record_after(&next, Some((x, y)), color, captured);
+                BoardCoordinates::board_to_human(x, y, next.board_size)
+            }
+            // 生成器给出不合法的点时按虚手处理
+            None => {
+                next = self.board_state.clone();
+                GoBoardRules::pass(&mut next);
+                self.history.record_after(&next, None, color, Vec::new());
+                "pass".to_string()
+            }
+        };
+
+        self.undo_stack
+            .push(std::mem::replace(&mut self.board_state, next));
+        vertex
+    }
+
+    /// `fixed_handicap` 命令：按 GTP 规定的位置摆放让子
+    fn fixed_handicap(&mut self, stones: usize) -> Result<String, String> {
+        let points = handicap_points(self.board_state.board_size, stones)
+            .ok_or("invalid number of stones")?;
+
+        if self.board_state.move_count > 0 || !self.undo_stack.is_empty() {
+            return Err("board not empty".to_string());
+        }
+
+        for &(x, y) in &points {
+            self.board_state.place_stone(x, y, StoneColor::Black);
+        }
+
+        Ok(points
+            .iter()
+            .map(|&(x, y)| BoardCoordinates::board_to_human(x, y, self.board_state.board_size))
+            .collect::<Vec<_>>()
+            .join(" "))
+    }
+
+    /// `showboard` 使用的文本棋盘
+    fn render_board(&self) -> String {
+        let size = self.board_state.board_size.get_value();
+        let header: String = (0..size)
+            .map(|x| {
+                let label = BoardCoordinates::board_to_human(x, 0, self.board_state.board_size);
+                format!(" {}", &label[..1])
+            })
+            .collect();
+
+        let mut text = format!("  {}\n", header);
+        for y in 0..size {
+            let row = size - y;
+            text.push_str(&format!("{:>2}", row));
+            for x in 0..size {
+                let symbol = match self.board_state.get_stone(x, y) {
+                    Some(StoneColor::Black) => 'X',
+                    Some(StoneColor::White) => 'O',
+                    None => '.',
+                };
+                text.push(' ');
+                text.push(symbol);
+            }
+            text.push_str(&format!(" {}\n", row));
+        }
+        text.push_str(&format!("  {}\n", header));
+        text.push_str(&format!(
+            "Captured: B {} W {}",
+            self.board_state.captured_white, self.board_state.captured_black
+        ));
+        text
+    }
+}
+
+/// 解析 GTP 颜色参数
+fn parse_color(value: &str) -> Result<StoneColor, String> {
+    match value.to_ascii_lowercase().as_str() {
+        "b" | "black" => Ok(StoneColor::Black),
+        "w" | "white" => Ok(StoneColor::White),
+        _ => Err("invalid color".to_string()),
+    }
+}
+
+/// GTP 计分结果格式，如 "B+3.5"、"W+0.5"、"0"
+fn format_score(margin: f32) -> String {
+    if margin > 0.0 {
+        format!("B+{}", margin)
+    } else if margin < 0.0 {
+        format!("W+{}", -margin)
+    } else {
+        "0".to_string()
+    }
+}
+
+/// GTP 规定的固定让子位置
+pub fn handicap_points(board_size: BoardSize, stones: usize) -> Option<Vec<(i32, i32)>> {
+    let size = board_size.get_value();
+    let max_stones = if size >= 9 { 9 } else { 4 };
+    if !(2..=max_stones).contains(&stones) {
+        return None;
+    }
+
+    let edge = if size >= 13 { 3 } else { 2 };
+    let low = edge;
+    let high = size - 1 - edge;
+    let mid = size / 2;
+
+    // GTP 规定的顺序（19 路）：D4 Q16 D16 Q4，然后 D10 Q10、K4 K16，奇数子最后放天元 K10
+    let mut points = vec![(low, high), (high, low)];
+    if stones >= 3 {
+        points.push((low, low));
+    }
+    if stones >= 4 {
+        points.push((high, high));
+    }
+    if stones >= 6 {
+        points.push((low, mid));
+        points.push((high, mid));
+    }
+    if stones >= 8 {
+        points.push((mid, high));
+        points.push((mid, low));
+    }
+    if stones % 2 == 1 && stones >= 5 {
+        points.push((mid, mid));
+    }
+
+    Some(points)
+}
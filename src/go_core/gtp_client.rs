@@ -0,0 +1,157 @@
+use super::board::{BoardSize, StoneColor};
+use super::bot::GeneratedMove;
+use super::coordinates::BoardCoordinates;
+use std::fmt;
+use std::io::{self, BufRead, BufReader, Write};
+use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+
+/// GTP 命令失败的原因
+#[derive(Debug)]
+pub enum GtpError {
+    /// 与引擎进程通信失败
+    Io(io::Error),
+    /// 引擎返回了 `?` 错误响应
+    Failure(String),
+    /// 引擎返回了无法解析的内容
+    Protocol(String),
+}
+
+impl fmt::Display for GtpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GtpError::Io(error) => write!(f, "engine I/O error: {}", error),
+            GtpError::Failure(message) => write!(f, "engine error: {}", message),
+            GtpError::Protocol(response) => write!(f, "unexpected engine response: {}", response),
+        }
+    }
+}
+
+impl std::error::Error for GtpError {}
+
+impl From<io::Error> for GtpError {
+    fn from(error: io::Error) -> Self {
+        GtpError::Io(error)
+    }
+}
+
+/// GTP 客户端：启动外部引擎进程（GNU Go、KataGo、Leela Zero 等）并按 GTP v2 与之通信
+///
+/// # 使用示例
+/// ```rust,ignore
+/// let mut client = GtpClient::spawn("gnugo", &["--mode".into(), "gtp".into()])?;
+/// client.send("boardsize 9")?;
+/// let reply = client.genmove(StoneColor::Black, BoardSize::Nine)?;
+/// ```
+pub struct GtpClient {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+}
+
+impl GtpClient {
+    /// 启动引擎进程，标准错误输出直接继承
+    pub fn spawn(program: &str, args: &[String]) -> io::Result<Self> {
+        let mut child = Command::new(program)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()?;
+
+        let stdin = child.stdin.take().expect("stdin is piped");
+        let stdout = BufReader::new(child.stdout.take().expect("stdout is piped"));
+        Ok(Self {
+            child,
+            stdin,
+            stdout,
+        })
+    }
+
+    /// 发送一条命令，返回 `=` 之后的响应内容
+    pub fn send(&mut self, command: &str) -> Result<String, GtpError> {
+        writeln!(self.stdin, "{}", command)?;
+        self.stdin.flush()?;
+
+        // 响应以空行结束，之前的空行忽略
+        let mut lines = Vec::new();
+        loop {
+            let mut line = String::new();
+            if self.stdout.read_line(&mut line)? == 0 {
+                return Err(GtpError::Io(io::ErrorKind::UnexpectedEof.into()));
+            }
+            let line = line.trim_end_matches(['\r', '\n']);
+            if line.is_empty() {
+                if lines.is_empty() {
+                    continue;
+                }
+                break;
+            }
+            lines.push(line.to_string());
+        }
+
+        let response = lines.join("\n");
+        let mut chars = response.chars();
+        let status = chars.next();
+        // 跳过可选的命令编号
+        let body = chars
+            .as_str()
+            .trim_start_matches(|c: char| c.is_ascii_digit())
+            .trim()
+            .to_string();
+        match status {
+            Some('=') => Ok(body),
+            Some('?') => Err(GtpError::Failure(body)),
+            _ => Err(GtpError::Protocol(response)),
+        }
+    }
+
+    /// `play` 命令，`position` 为 `None` 表示虚手
+    pub fn play(
+        &mut self,
+        color: StoneColor,
+        position: Option<(i32, i32)>,
+        board_size: BoardSize,
+    ) -> Result<(), GtpError> {
+        let vertex = match position {
+            Some((x, y)) => BoardCoordinates::board_to_human(x, y, board_size),
+            None => "pass".to_string(),
+        };
+        self.send(&format!("play {} {}", color_name(color), vertex))
+            .map(|_| ())
+    }
+
+    /// `genmove` 命令，把引擎的回答解析为着法
+    pub fn genmove(
+        &mut self,
+        color: StoneColor,
+        board_size: BoardSize,
+    ) -> Result<GeneratedMove, GtpError> {
+        let response = self.send(&format!("genmove {}", color_name(color)))?;
+        if response.eq_ignore_ascii_case("pass") {
+            return Ok(GeneratedMove::Pass);
+        }
+        if response.eq_ignore_ascii_case("resign") {
+            return Ok(GeneratedMove::Resign);
+        }
+        BoardCoordinates::human_to_board(&response, board_size)
+            .map(|(x, y)| GeneratedMove::Play(x, y))
+            .ok_or(GtpError::Protocol(response))
+    }
+}
+
+impl Drop for GtpClient {
+    fn drop(&mut self) {
+        // 先礼貌地请引擎退出，再等待进程结束
+        let _ = writeln!(self.stdin, "quit");
+        let _ = self.stdin.flush();
+        let _ = self.child.wait();
+    }
+}
+
+/// GTP 中的颜色名称
+fn color_name(color: StoneColor) -> &'static str {
+    match color {
+        StoneColor::Black => "black",
+        StoneColor::White => "white",
+    }
+}
@@ -0,0 +1,260 @@
+use super::board::{BoardState, GameHistory, StoneColor};
+use super::bot::{GeneratedMove, MoveGenerator, should_pass_back};
+use super::rules::GoBoardRules;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use std::collections::HashSet;
+
+// 入门电脑棋手：按难度逐级叠加的规则评分，同分时由固定种子决定
+// 每一级都包含前一级的全部规则，形成稳定的难度阶梯
+
+/// 入门电脑棋手的难度，由弱到强
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BotLevel {
+    /// 随机合法着法
+    RandomLegal,
+    /// 能提子就提，提得越多越好
+    GreedyCapture,
+    /// 另外会逃出被打吃的棋、打吃对方，并避免自己送吃
+    AtariAware,
+    /// 另外考虑布局位置和基本棋形
+    Shape,
+}
+
+impl BotLevel {
+    /// 全部难度，由弱到强
+    pub const ALL: [BotLevel; 4] = [
+        BotLevel::RandomLegal,
+        BotLevel::GreedyCapture,
+        BotLevel::AtariAware,
+        BotLevel::Shape,
+    ];
+
+    /// 名称，用于命令行和 GTP `name`
+    pub fn name(&self) -> &'static str {
+        match self {
+            BotLevel::RandomLegal => "random",
+            BotLevel::GreedyCapture => "capture",
+            BotLevel::AtariAware => "atari",
+            BotLevel::Shape => "shape",
+        }
+    }
+
+    /// 按名称查找难度
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|level| level.name() == name)
+    }
+}
+
+/// 提子每颗的得分
+const CAPTURE_WEIGHT: f32 = 10.0;
+/// 救出被打吃的棋每颗的得分
+const ESCAPE_WEIGHT: f32 = 8.0;
+/// 打吃对方每颗的得分
+const ATARI_WEIGHT: f32 = 2.0;
+/// 自己送吃每颗的扣分
+const SELF_ATARI_WEIGHT: f32 = 6.0;
+
+/// 规则评分的入门电脑棋手
+///
+/// # 使用示例
+/// ```rust,ignore
+/// let mut bot = HeuristicBot::new(BotLevel::AtariAware, 42);
+/// let generated = bot.generate_move(&board_state, &history, StoneColor::White, DEFAULT_KOMI);
+/// ```
+pub struct HeuristicBot {
+    level: BotLevel,
+    rng: StdRng,
+}
+
+impl HeuristicBot {
+    /// 使用固定种子，同一局面总是下同一手
+    pub fn new(level: BotLevel, seed: u64) -> Self {
+        Self {
+            level,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    pub fn level(&self) -> BotLevel {
+        self.level
+    }
+
+    /// 给一个候选点打分，分数越高越好
+    fn evaluate(&self, board_state: &BoardState, x: i32, y: i32, color: StoneColor) -> f32 {
+        if self.level == BotLevel::RandomLegal {
+            return 0.0;
+        }
+
+        let mut after = board_state.clone();
+        let Ok(captured) = GoBoardRules::play_move(&mut after, x, y, color) else {
+            return f32::MIN;
+        };
+        let mut score = captured.len() as f32 * CAPTURE_WEIGHT;
+
+        if self.level >= BotLevel::AtariAware {
+            score += atari_score(board_state, &after, x, y, color, !captured.is_empty());
+        }
+        if self.level >= BotLevel::Shape {
+            score += shape_score(board_state, x, y, color);
+        }
+        score
+    }
+}
+
+impl MoveGenerator for HeuristicBot {
+    fn name(&self) -> &str {
+        self.level.name()
+    }
+
+    fn generate_move(
+        &mut self,
+        board_state: &BoardState,
+        history: &GameHistory,
+        color: StoneColor,
+        komi: f32,
+    ) -> GeneratedMove {
+        if should_pass_back(board_state, color, komi) {
+            return GeneratedMove::Pass;
+        }
+
+        let seki = GoBoardRules::seki_liberties(board_state);
+        let mut candidates: Vec<(i32, i32)> =
+            GoBoardRules::legal_moves_with_history(board_state, history, color)
+                .into_iter()
+                .filter(|&(x, y)| !GoBoardRules::is_eye_like(board_state, x, y, color))
+                .filter(|point| !seki.contains(point))
+                .collect();
+        // 先打乱再取第一个最高分，同分时的选择只取决于种子
+        candidates.shuffle(&mut self.rng);
+
+        let mut best = None;
+        let mut best_score = f32::MIN;
+        for (x, y) in candidates {
+            let score = self.evaluate(board_state, x, y, color);
+            if best.is_none() || score > best_score {
+                best = Some((x, y));
+                best_score = score;
+            }
+        }
+
+        match best {
+            Some((x, y)) => GeneratedMove::Play(x, y),
+            None => GeneratedMove::Pass,
+        }
+    }
+}
+
+/// 打吃相关的得分：逃出被打吃的棋、打吃对方、避免自己送吃
+fn atari_score(
+    before: &BoardState,
+    after: &BoardState,
+    x: i32,
+    y: i32,
+    color: StoneColor,
+    captured: bool,
+) -> f32 {
+    let mut score = 0.0;
+
+    let own_group = GoBoardRules::get_group(after, x, y);
+    let own_liberties = GoBoardRules::count_liberties(after, &own_group);
+    if own_liberties == 1 && !captured {
+        score -= own_group.len() as f32 * SELF_ATARI_WEIGHT;
+    }
+
+    let mut seen = HashSet::new();
+    for (nx, ny) in before.get_neighbors(x, y) {
+        if seen.contains(&(nx, ny)) {
+            continue;
+        }
+        match before.get_stone(nx, ny) {
+            // 原本只剩一口气的己方棋串，落子后长出气来
+            Some(stone) if stone == color => {
+                let group = GoBoardRules::get_group(before, nx, ny);
+                if GoBoardRules::count_liberties(before, &group) == 1 && own_liberties >= 2 {
+                    score += group.len() as f32 * ESCAPE_WEIGHT;
+                }
+                seen.extend(group);
+            }
+            // 落子后只剩一口气的对方棋串
+            Some(_) if after.get_stone(nx, ny).is_some() => {
+                let group = GoBoardRules::get_group(after, nx, ny);
+                if GoBoardRules::count_liberties(after, &group) == 1 {
+                    score += group.len() as f32 * ATARI_WEIGHT;
+                }
+                seen.extend(group);
+            }
+            _ => {}
+        }
+    }
+    score
+}
+
+/// 棋形得分：布局时占三、四线，小飞、跳、尖等好形加分，空三角和一线扣分
+fn shape_score(board_state: &BoardState, x: i32, y: i32, color: StoneColor) -> f32 {
+    let size = board_state.board_size.get_value();
+    let line = x.min(y).min(size - 1 - x).min(size - 1 - y);
+    let opening = board_state.move_count < (size * 2) as usize;
+
+    let mut score = match line {
+        0 => -3.0,
+        1 => -1.0,
+        2 | 3 if opening => 2.0,
+        _ => 0.0,
+    };
+
+    let stone = |dx: i32, dy: i32| board_state.get_stone(x + dx, y + dy);
+    let inside = |dx: i32, dy: i32| (0..size).contains(&(x + dx)) && (0..size).contains(&(y + dy));
+
+    // 与己方棋子的关系：尖、跳、小飞
+    let relations: [(&[(i32, i32)], f32); 3] = [
+        (&[(1, 1), (1, -1), (-1, 1), (-1, -1)], 1.0),
+        (&[(2, 0), (-2, 0), (0, 2), (0, -2)], 1.0),
+        (
+            &[
+                (1, 2),
+                (2, 1),
+                (-1, 2),
+                (-2, 1),
+                (1, -2),
+                (2, -1),
+                (-1, -2),
+                (-2, -1),
+            ],
+            0.5,
+        ),
+    ];
+    for (offsets, bonus) in relations {
+        if offsets.iter().any(|&(dx, dy)| stone(dx, dy) == Some(color)) {
+            score += bonus;
+        }
+    }
+
+    // 空三角：所在的 2x2 方块里另有两颗己方棋子，第四个点为空
+    for (sx, sy) in [(1, 1), (1, -1), (-1, 1), (-1, -1)] {
+        if !inside(sx, sy) {
+            continue;
+        }
+        let corners = [stone(sx, 0), stone(0, sy), stone(sx, sy)];
+        let own = corners
+            .iter()
+            .filter(|&&corner| corner == Some(color))
+            .count();
+        let empty = corners.iter().filter(|corner| corner.is_none()).count();
+        if own == 2 && empty == 1 {
+            score -= 3.0;
+        }
+    }
+
+    // 布局之后优先在已有棋子附近下
+    if !opening {
+        let nearby = (-2..=2)
+            .flat_map(|dx| (-2..=2).map(move |dy| (dx, dy)))
+            .any(|(dx, dy)| stone(dx, dy).is_some());
+        if nearby {
+            score += 1.0;
+        }
+    }
+    score
+}
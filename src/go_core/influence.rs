@@ -0,0 +1,164 @@
+use super::board::{BoardSize, BoardState, StoneColor};
+use super::rules::GoBoardRules;
+
+// 形势判断：先提掉估计的死子，再用 Bouzy 5/21 膨胀和侵蚀算出影响图，
+// 按影响的正负给每个点定归属并数出目差
+
+/// 膨胀次数
+const DILATIONS: usize = 5;
+/// 侵蚀次数
+const EROSIONS: usize = 21;
+/// 棋子的初始影响值
+const STONE_INFLUENCE: i32 = 128;
+
+/// 形势判断的结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoreEstimate {
+    pub board_size: BoardSize,
+    /// 每个点的归属，黑为 1、白为 -1、未定为 0，按 `y * size + x` 排列；死子算作对方
+    pub ownership: Vec<f32>,
+    /// 归黑的点数（棋子加地）
+    pub black_area: usize,
+    /// 归白的点数
+    pub white_area: usize,
+    /// 黑减白的目差，已扣除贴目
+    pub margin: f32,
+}
+
+impl ScoreEstimate {
+    /// 某一点的归属
+    pub fn ownership_at(&self, x: i32, y: i32) -> Option<f32> {
+        let size = self.board_size.get_value();
+        if !(0..size).contains(&x) || !(0..size).contains(&y) {
+            return None;
+        }
+        self.ownership.get((y * size + x) as usize).copied()
+    }
+
+    /// 领先的一方，目差为零时为 `None`
+    pub fn leader(&self) -> Option<StoneColor> {
+        if self.margin > 0.0 {
+            Some(StoneColor::Black)
+        } else if self.margin < 0.0 {
+            Some(StoneColor::White)
+        } else {
+            None
+        }
+    }
+}
+
+impl GoBoardRules {
+    /// Bouzy 5/21 影响图，正值为黑、负值为白，按 `y * size + x` 排列
+    pub fn influence_map(board_state: &BoardState) -> Vec<i32> {
+        let size = board_state.board_size.get_value();
+        let mut influence = vec![0; (size * size) as usize];
+        for x in 0..size {
+            for y in 0..size {
+                influence[(y * size + x) as usize] = match board_state.get_stone(x, y) {
+                    Some(StoneColor::Black) => STONE_INFLUENCE,
+                    Some(StoneColor::White) => -STONE_INFLUENCE,
+                    None => 0,
+                };
+            }
+        }
+
+        for _ in 0..DILATIONS {
+            influence = dilate(board_state, &influence);
+        }
+        for _ in 0..EROSIONS {
+            influence = erode(board_state, &influence);
+        }
+        influence
+    }
+
+    /// 形势判断：估计的死子算作对方，其余点按影响图定归属
+    pub fn estimate_score(board_state: &BoardState, komi: f32) -> ScoreEstimate {
+        let size = board_state.board_size.get_value();
+        let dead = Self::suggest_dead_stones(board_state);
+        let mut cleaned = board_state.clone();
+        for &(x, y) in &dead {
+            cleaned.remove_stone(x, y);
+        }
+        let influence = Self::influence_map(&cleaned);
+
+        let mut ownership = vec![0.0; (size * size) as usize];
+        for x in 0..size {
+            for y in 0..size {
+                let index = (y * size + x) as usize;
+                ownership[index] = match cleaned.get_stone(x, y) {
+                    Some(StoneColor::Black) => 1.0,
+                    Some(StoneColor::White) => -1.0,
+                    None => influence[index].signum() as f32,
+                };
+            }
+        }
+
+        let black_area = ownership.iter().filter(|&&owner| owner > 0.0).count();
+        let white_area = ownership.iter().filter(|&&owner| owner < 0.0).count();
+        ScoreEstimate {
+            board_size: board_state.board_size,
+            ownership,
+            black_area,
+            white_area,
+            margin: black_area as f32 - white_area as f32 - komi,
+        }
+    }
+}
+
+/// 相邻点的影响值
+fn neighbor_values<'a>(
+    board_state: &'a BoardState,
+    influence: &'a [i32],
+    x: i32,
+    y: i32,
+) -> impl Iterator<Item = i32> + 'a {
+    let size = board_state.board_size.get_value();
+    board_state
+        .get_neighbors(x, y)
+        .into_iter()
+        .map(move |(nx, ny)| influence[(ny * size + nx) as usize])
+}
+
+/// 膨胀：没有对方影响的相邻点时，每个同号的相邻点加一
+fn dilate(board_state: &BoardState, influence: &[i32]) -> Vec<i32> {
+    let size = board_state.board_size.get_value();
+    let mut next = influence.to_vec();
+    for x in 0..size {
+        for y in 0..size {
+            let index = (y * size + x) as usize;
+            let value = influence[index];
+            let neighbors: Vec<i32> = neighbor_values(board_state, influence, x, y).collect();
+            if value >= 0 && neighbors.iter().all(|&n| n >= 0) {
+                next[index] += neighbors.iter().filter(|&&n| n > 0).count() as i32;
+            }
+            if value <= 0 && neighbors.iter().all(|&n| n <= 0) {
+                next[index] -= neighbors.iter().filter(|&&n| n < 0).count() as i32;
+            }
+        }
+    }
+    next
+}
+
+/// 侵蚀：每个不同号（含零）的相邻点减一，不越过零
+fn erode(board_state: &BoardState, influence: &[i32]) -> Vec<i32> {
+    let size = board_state.board_size.get_value();
+    let mut next = influence.to_vec();
+    for x in 0..size {
+        for y in 0..size {
+            let index = (y * size + x) as usize;
+            let value = influence[index];
+            if value > 0 {
+                let weaker = neighbor_values(board_state, influence, x, y)
+                    .filter(|&n| n <= 0)
+                    .count() as i32;
+                next[index] = (value - weaker).max(0);
+            } else if value < 0 {
+                let weaker = neighbor_values(board_state, influence, x, y)
+                    .filter(|&n| n >= 0)
+                    .count() as i32;
+                next[index] = (value + weaker).min(0);
+            }
+        }
+    }
+    next
+}
@@ -0,0 +1,412 @@
+use super::board::{BoardState, StoneColor};
+use super::rules::GoBoardRules;
+use std::collections::HashSet;
+
+// 征子和枷吃的战术计算：目标棋串只剩一口或两口气时，读出攻方能否吃掉它
+// 征子只在目标的气上叫吃；枷吃允许攻方先在气的外侧封锁，读的步数有限
+// 目标被叫吃后长出三口气即视为逃脱
+
+/// 吃掉目标的方法
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaptureMethod {
+    /// 征子：攻方每一手都是叫吃
+    Ladder,
+    /// 枷吃：攻方至少有一手不是叫吃，而是封住出路
+    Net,
+}
+
+/// 攻方吃掉目标的变化
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CaptureReading {
+    pub method: CaptureMethod,
+    /// 从轮到方开始的着法，守方走最顽强的一路，最后一手提子
+    pub moves: Vec<(StoneColor, (i32, i32))>,
+}
+
+/// 叠加层显示的征子路线
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LadderPath {
+    /// 目标棋串上的一点
+    pub target: (i32, i32),
+    pub moves: Vec<(StoneColor, (i32, i32))>,
+    /// 征子成立，最后一手提子
+    pub captured: bool,
+    /// 征子不成立时，逃出的棋串接上的守方棋子（引征）
+    pub breaker: Option<(i32, i32)>,
+}
+
+/// 征子和枷吃计算器
+///
+/// # 使用示例
+/// ```rust,ignore
+/// if let Some(reading) = TacticalReader::new().read(&board_state, (3, 3), StoneColor::Black) {
+///     println!("{:?}: {:?}", reading.method, reading.moves);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TacticalReader {
+    /// 每次计算的节点上限，超过时视为吃不掉
+    pub max_nodes: usize,
+    /// 枷吃时攻方的前几手可以不叫吃，之后只能征子
+    pub net_depth: usize,
+}
+
+impl Default for TacticalReader {
+    fn default() -> Self {
+        Self {
+            max_nodes: 20_000,
+            net_depth: 3,
+        }
+    }
+}
+
+/// 征子路线的最大长度
+const MAX_LADDER_LENGTH: usize = 120;
+
+impl TacticalReader {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_max_nodes(mut self, max_nodes: usize) -> Self {
+        self.max_nodes = max_nodes;
+        self
+    }
+
+    pub fn with_net_depth(mut self, net_depth: usize) -> Self {
+        self.net_depth = net_depth;
+        self
+    }
+
+    /// 计算 `target` 所在棋串能否被吃，`to_play` 可以是任意一方
+    ///
+    /// 目标没有棋子、气数不是一口或两口、能逃脱或超出节点上限时返回 `None`
+    pub fn read(
+        &self,
+        board_state: &BoardState,
+        target: (i32, i32),
+        to_play: StoneColor,
+    ) -> Option<CaptureReading> {
+        let (x, y) = target;
+        board_state.get_stone(x, y)?;
+        if !(1..=2).contains(&liberties(board_state, target).len()) {
+            return None;
+        }
+
+        if let Some(moves) = Reading::new(self, target, false).run(board_state, to_play) {
+            return Some(CaptureReading {
+                method: CaptureMethod::Ladder,
+                moves,
+            });
+        }
+        if self.net_depth == 0 {
+            return None;
+        }
+        Reading::new(self, target, true)
+            .run(board_state, to_play)
+            .map(|moves| CaptureReading {
+                method: CaptureMethod::Net,
+                moves,
+            })
+    }
+
+    /// 征子路线：成立时为 [`read`](Self::read) 的变化，
+    /// 不成立时为攻方一直叫吃、守方一直长出的路线，并找出引征的棋子
+    ///
+    /// 目标没有棋子或气数不是一口或两口时返回 `None`
+    pub fn ladder_path(
+        &self,
+        board_state: &BoardState,
+        target: (i32, i32),
+        to_play: StoneColor,
+    ) -> Option<LadderPath> {
+        let (x, y) = target;
+        let defender = board_state.get_stone(x, y)?;
+        if !(1..=2).contains(&liberties(board_state, target).len()) {
+            return None;
+        }
+
+        if let Some(moves) = Reading::new(self, target, false).run(board_state, to_play) {
+            return Some(LadderPath {
+                target,
+                moves,
+                captured: true,
+                breaker: None,
+            });
+        }
+
+        // 按征子的走法一直下到目标逃出
+        let original = GoBoardRules::get_group(board_state, x, y);
+        let mut board = board_state.clone();
+        let mut color = to_play;
+        let mut moves = Vec::new();
+        while moves.len() < MAX_LADDER_LENGTH && board.get_stone(x, y) == Some(defender) {
+            let libs = liberties(&board, target);
+            let attacker_to_move = color != defender;
+            if libs.len() >= 3 || (!attacker_to_move && libs.len() >= 2 && !moves.is_empty()) {
+                break;
+            }
+            let next = if attacker_to_move {
+                libs.iter()
+                    .copied()
+                    .filter(|&(lx, ly)| GoBoardRules::is_valid_move(&board, lx, ly, color))
+                    .min_by_key(|&point| chased_liberties(&board, target, point, color))
+            } else {
+                defender_moves(&board, target, color).into_iter().next()
+            };
+            let Some((nx, ny)) = next else {
+                break;
+            };
+            if GoBoardRules::play_move(&mut board, nx, ny, color).is_err() {
+                break;
+            }
+            moves.push((color, (nx, ny)));
+            color = color.opposite();
+        }
+
+        // 引征：逃出的棋串中既不是原来的、也不是路线上下的棋子；
+        // 没有接上时取离守方最后一手最近（三路以内）的其他守方棋子
+        let played: HashSet<(i32, i32)> = moves.iter().map(|&(_, point)| point).collect();
+        let chain = GoBoardRules::get_group(&board, x, y);
+        let mut joined: Vec<(i32, i32)> = chain
+            .iter()
+            .copied()
+            .filter(|point| !original.contains(point) && !played.contains(point))
+            .collect();
+        joined.sort();
+        let breaker = joined.first().copied().or_else(|| {
+            let &(_, (ex, ey)) = moves.iter().rev().find(|&&(color, _)| color == defender)?;
+            let size = board.board_size.get_value();
+            (0..size)
+                .flat_map(|nx| (0..size).map(move |ny| (nx, ny)))
+                .filter(|&(nx, ny)| {
+                    board.get_stone(nx, ny) == Some(defender) && !chain.contains(&(nx, ny))
+                })
+                .map(|(nx, ny)| ((nx - ex).abs() + (ny - ey).abs(), (nx, ny)))
+                .filter(|&(distance, _)| distance <= 3)
+                .min()
+                .map(|(_, point)| point)
+        });
+
+        Some(LadderPath {
+            target,
+            moves,
+            captured: false,
+            breaker,
+        })
+    }
+}
+
+/// 一次征子或枷吃计算
+struct Reading {
+    target: (i32, i32),
+    defender: StoneColor,
+    net: bool,
+    net_depth: usize,
+    max_nodes: usize,
+    nodes: usize,
+}
+
+impl Reading {
+    fn new(reader: &TacticalReader, target: (i32, i32), net: bool) -> Self {
+        Self {
+            target,
+            defender: StoneColor::Black,
+            net,
+            net_depth: reader.net_depth,
+            max_nodes: reader.max_nodes,
+            nodes: 0,
+        }
+    }
+
+    fn run(
+        &mut self,
+        board_state: &BoardState,
+        to_play: StoneColor,
+    ) -> Option<Vec<(StoneColor, (i32, i32))>> {
+        let (x, y) = self.target;
+        self.defender = board_state.get_stone(x, y)?;
+        if to_play == self.defender {
+            self.defend(board_state, 0)
+        } else {
+            self.attack(board_state, 0)
+        }
+    }
+
+    fn captured(&self, board_state: &BoardState) -> bool {
+        let (x, y) = self.target;
+        board_state.get_stone(x, y) != Some(self.defender)
+    }
+
+    fn exhausted(&mut self) -> bool {
+        self.nodes += 1;
+        self.nodes > self.max_nodes
+    }
+
+    /// 攻方先走，返回吃掉目标的变化；`ply` 为攻方已走的手数
+    fn attack(
+        &mut self,
+        board_state: &BoardState,
+        ply: usize,
+    ) -> Option<Vec<(StoneColor, (i32, i32))>> {
+        if self.captured(board_state) {
+            return Some(Vec::new());
+        }
+        if self.exhausted() {
+            return None;
+        }
+
+        let attacker = self.defender.opposite();
+        let libs = liberties(board_state, self.target);
+        let can_net = self.net && ply < self.net_depth;
+        let mut candidates: Vec<(i32, i32)> = match libs.len() {
+            1 => {
+                let (lx, ly) = libs[0];
+                return GoBoardRules::is_valid_move(board_state, lx, ly, attacker)
+                    .then(|| vec![(attacker, (lx, ly))]);
+            }
+            // 叫吃在前，枷吃的封锁点在后
+            2 => libs.clone(),
+            // 三口气时紧一口气也算封锁
+            3 if can_net => libs.clone(),
+            _ => return None,
+        };
+        if can_net && libs.len() == 2 {
+            let mut outer = HashSet::new();
+            for &(lx, ly) in &libs {
+                for (nx, ny) in board_state.get_neighbors(lx, ly) {
+                    if board_state.get_stone(nx, ny).is_none() && !libs.contains(&(nx, ny)) {
+                        outer.insert((nx, ny));
+                    }
+                }
+            }
+            let mut outer: Vec<(i32, i32)> = outer.into_iter().collect();
+            outer.sort();
+            candidates.extend(outer);
+        }
+
+        for (cx, cy) in candidates {
+            let mut next = board_state.clone();
+            if GoBoardRules::play_move(&mut next, cx, cy, attacker).is_err() {
+                continue;
+            }
+            if let Some(line) = self.defend(&next, ply + 1) {
+                let mut moves = vec![(attacker, (cx, cy))];
+                moves.extend(line);
+                return Some(moves);
+            }
+        }
+        None
+    }
+
+    /// 守方先走，所有逃法都被吃掉时返回最长的变化
+    fn defend(
+        &mut self,
+        board_state: &BoardState,
+        ply: usize,
+    ) -> Option<Vec<(StoneColor, (i32, i32))>> {
+        if self.captured(board_state) {
+            return Some(Vec::new());
+        }
+        if self.exhausted() {
+            return None;
+        }
+
+        let mut longest: Option<Vec<(StoneColor, (i32, i32))>> = None;
+        let mut tried = false;
+        for (dx, dy) in defender_moves(board_state, self.target, self.defender) {
+            let mut next = board_state.clone();
+            if GoBoardRules::play_move(&mut next, dx, dy, self.defender).is_err() {
+                continue;
+            }
+            tried = true;
+            let line = self.attack(&next, ply)?;
+            if longest
+                .as_ref()
+                .map_or(true, |longest| line.len() + 1 > longest.len())
+            {
+                let mut moves = vec![(self.defender, (dx, dy))];
+                moves.extend(line);
+                longest = Some(moves);
+            }
+        }
+
+        // 无处可走时攻方直接继续
+        if !tried {
+            return self.attack(board_state, ply);
+        }
+        longest
+    }
+}
+
+/// 目标棋串的气，按坐标排序
+fn liberties(board_state: &BoardState, target: (i32, i32)) -> Vec<(i32, i32)> {
+    let (x, y) = target;
+    let chain = GoBoardRules::get_group(board_state, x, y);
+    let mut libs: HashSet<(i32, i32)> = HashSet::new();
+    for &(cx, cy) in &chain {
+        for (nx, ny) in board_state.get_neighbors(cx, cy) {
+            if board_state.get_stone(nx, ny).is_none() {
+                libs.insert((nx, ny));
+            }
+        }
+    }
+    let mut libs: Vec<(i32, i32)> = libs.into_iter().collect();
+    libs.sort();
+    libs
+}
+
+/// 守方的着法：先提掉叫吃目标的攻方棋子，再在目标的气上长
+fn defender_moves(
+    board_state: &BoardState,
+    target: (i32, i32),
+    defender: StoneColor,
+) -> Vec<(i32, i32)> {
+    let (x, y) = target;
+    let chain = GoBoardRules::get_group(board_state, x, y);
+    let mut moves = Vec::new();
+    let mut seen = HashSet::new();
+    for &(cx, cy) in &chain {
+        for (nx, ny) in board_state.get_neighbors(cx, cy) {
+            if board_state.get_stone(nx, ny) != Some(defender.opposite()) || !seen.insert((nx, ny))
+            {
+                continue;
+            }
+            let group = GoBoardRules::get_group(board_state, nx, ny);
+            seen.extend(group.iter().copied());
+            let group_libs = liberties(board_state, (nx, ny));
+            if group_libs.len() == 1 && !moves.contains(&group_libs[0]) {
+                moves.push(group_libs[0]);
+            }
+        }
+    }
+    for point in liberties(board_state, target) {
+        if !moves.contains(&point) {
+            moves.push(point);
+        }
+    }
+    moves
+        .into_iter()
+        .filter(|&(mx, my)| GoBoardRules::is_valid_move(board_state, mx, my, defender))
+        .collect()
+}
+
+/// 攻方在 `point` 叫吃、守方在剩下的气上长出后目标的气数，越少越像征子
+fn chased_liberties(
+    board_state: &BoardState,
+    target: (i32, i32),
+    point: (i32, i32),
+    attacker: StoneColor,
+) -> usize {
+    let mut next = board_state.clone();
+    if GoBoardRules::play_move(&mut next, point.0, point.1, attacker).is_err() {
+        return usize::MAX;
+    }
+    let Some(&(ex, ey)) = liberties(&next, target).first() else {
+        return 0;
+    };
+    if GoBoardRules::play_move(&mut next, ex, ey, attacker.opposite()).is_err() {
+        return 0;
+    }
+    liberties(&next, target).len()
+}
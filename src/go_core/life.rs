@@ -0,0 +1,321 @@
+use super::board::{BoardState, StoneColor};
+use super::rules::GoBoardRules;
+use super::semeai::MAX_EYE_SIZE;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+// 棋块死活的静态估计：先用 Benson 算法找出无条件活棋，
+// 再按眼位、气和所在的包围圈把其余棋块分成活、弱和死
+
+/// 有一只眼时，气数达到这个值的棋块在开阔处视为活棋
+const SAFE_LIBERTIES: usize = 4;
+
+/// 棋块的死活状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GroupStatus {
+    /// 对方连下多少手都吃不掉（Benson）
+    UnconditionallyAlive,
+    /// 有两只眼、足够大的眼位、在双活中，或在开阔处有眼且气长
+    Alive,
+    /// 尚未安定
+    Weak,
+    /// 被对方围住且做不出两只眼
+    Dead,
+}
+
+/// 一个棋块（共用眼位的同色棋串）的死活估计
+#[derive(Debug, Clone)]
+pub struct GroupLife {
+    pub color: StoneColor,
+    pub stones: HashSet<(i32, i32)>,
+    pub liberties: usize,
+    /// 只被己方包围的空点区域个数
+    pub eyes: usize,
+    /// 这些区域的总大小
+    pub eye_space: usize,
+    pub status: GroupStatus,
+}
+
+impl GroupLife {
+    pub fn is_alive(&self) -> bool {
+        matches!(
+            self.status,
+            GroupStatus::UnconditionallyAlive | GroupStatus::Alive
+        )
+    }
+}
+
+impl GoBoardRules {
+    /// Benson 算法：双方所有无条件活的棋子
+    pub fn unconditionally_alive(board_state: &BoardState) -> HashSet<(i32, i32)> {
+        let mut alive = benson(board_state, StoneColor::Black);
+        alive.extend(benson(board_state, StoneColor::White));
+        alive
+    }
+
+    /// 局面中每个棋块的死活估计
+    ///
+    /// 先估计一遍，再提掉判为死子的棋子重新估计其余棋块，
+    /// 这样围住死子的一方能把这块地算作眼位
+    pub fn group_status(board_state: &BoardState) -> Vec<GroupLife> {
+        let first = classify(board_state);
+        if first.iter().all(|group| group.status != GroupStatus::Dead) {
+            return first;
+        }
+
+        let mut cleaned = board_state.clone();
+        let mut groups = Vec::new();
+        for group in first {
+            if group.status == GroupStatus::Dead {
+                for &(x, y) in &group.stones {
+                    cleaned.remove_stone(x, y);
+                }
+                groups.push(group);
+            }
+        }
+        groups.extend(classify(&cleaned));
+        groups
+    }
+
+    /// 数子阶段开始时建议的死子
+    pub fn suggest_dead_stones(board_state: &BoardState) -> HashSet<(i32, i32)> {
+        Self::group_status(board_state)
+            .into_iter()
+            .filter(|group| group.status == GroupStatus::Dead)
+            .flat_map(|group| group.stones)
+            .collect()
+    }
+}
+
+/// 某一方的所有棋串
+fn chains(board_state: &BoardState, color: StoneColor) -> Vec<HashSet<(i32, i32)>> {
+    let size = board_state.board_size.get_value();
+    let mut seen = HashSet::new();
+    let mut chains = Vec::new();
+    for x in 0..size {
+        for y in 0..size {
+            if board_state.get_stone(x, y) == Some(color) && !seen.contains(&(x, y)) {
+                let chain = GoBoardRules::get_group(board_state, x, y);
+                seen.extend(chain.iter().copied());
+                chains.push(chain);
+            }
+        }
+    }
+    chains
+}
+
+/// 不是 `color` 棋子的点连成的区域，即被 `color` 围住的区域
+fn regions(board_state: &BoardState, color: StoneColor) -> Vec<HashSet<(i32, i32)>> {
+    let size = board_state.board_size.get_value();
+    let mut seen = HashSet::new();
+    let mut regions = Vec::new();
+    for x in 0..size {
+        for y in 0..size {
+            if board_state.get_stone(x, y) == Some(color) || !seen.insert((x, y)) {
+                continue;
+            }
+            let mut region = HashSet::from([(x, y)]);
+            let mut stack = vec![(x, y)];
+            while let Some((cx, cy)) = stack.pop() {
+                for (nx, ny) in board_state.get_neighbors(cx, cy) {
+                    if board_state.get_stone(nx, ny) != Some(color) && seen.insert((nx, ny)) {
+                        region.insert((nx, ny));
+                        stack.push((nx, ny));
+                    }
+                }
+            }
+            regions.push(region);
+        }
+    }
+    regions
+}
+
+/// 每个点所属棋串的下标
+fn chain_index(chains: &[HashSet<(i32, i32)>]) -> HashMap<(i32, i32), usize> {
+    chains
+        .iter()
+        .enumerate()
+        .flat_map(|(index, chain)| chain.iter().map(move |&point| (point, index)))
+        .collect()
+}
+
+/// 与区域相邻的棋串
+fn bordering_chains(
+    board_state: &BoardState,
+    region: &HashSet<(i32, i32)>,
+    chain_of: &HashMap<(i32, i32), usize>,
+) -> HashSet<usize> {
+    region
+        .iter()
+        .flat_map(|&(x, y)| board_state.get_neighbors(x, y))
+        .filter_map(|point| chain_of.get(&point).copied())
+        .collect()
+}
+
+/// `color` 一方无条件活的棋子
+///
+/// 区域中每个空点都是某棋串的气时，该区域对这个棋串是要害区域；
+/// 反复去掉要害区域少于两个的棋串和与被去掉棋串相邻的区域，剩下的棋串无条件活
+fn benson(board_state: &BoardState, color: StoneColor) -> HashSet<(i32, i32)> {
+    let chains = chains(board_state, color);
+    let chain_of = chain_index(&chains);
+    let regions = regions(board_state, color);
+
+    let borders: Vec<HashSet<usize>> = regions
+        .iter()
+        .map(|region| bordering_chains(board_state, region, &chain_of))
+        .collect();
+    let vital: Vec<HashSet<usize>> = regions
+        .iter()
+        .zip(&borders)
+        .map(|(region, borders)| {
+            borders
+                .iter()
+                .copied()
+                .filter(|&chain| {
+                    region
+                        .iter()
+                        .filter(|&&(x, y)| board_state.get_stone(x, y).is_none())
+                        .all(|&(x, y)| {
+                            board_state
+                                .get_neighbors(x, y)
+                                .iter()
+                                .any(|point| chain_of.get(point) == Some(&chain))
+                        })
+                })
+                .collect()
+        })
+        .collect();
+
+    let mut live_chains: HashSet<usize> = (0..chains.len()).collect();
+    let mut live_regions: HashSet<usize> = (0..regions.len()).collect();
+    loop {
+        let before = (live_chains.len(), live_regions.len());
+        live_chains.retain(|chain| {
+            live_regions
+                .iter()
+                .filter(|&&region| vital[region].contains(chain))
+                .count()
+                >= 2
+        });
+        live_regions.retain(|&region| borders[region].is_subset(&live_chains));
+        if (live_chains.len(), live_regions.len()) == before {
+            break;
+        }
+    }
+
+    live_chains
+        .into_iter()
+        .flat_map(|chain| chains[chain].iter().copied())
+        .collect()
+}
+
+/// 并查集的根
+fn find(parents: &mut [usize], index: usize) -> usize {
+    let mut root = index;
+    while parents[root] != root {
+        root = parents[root];
+    }
+    parents[index] = root;
+    root
+}
+
+/// 估计一遍所有棋块的死活
+fn classify(board_state: &BoardState) -> Vec<GroupLife> {
+    let size = board_state.board_size.get_value();
+    let area = (size * size) as usize;
+    let unconditional = GoBoardRules::unconditionally_alive(board_state);
+    let seki = GoBoardRules::seki_liberties(board_state);
+    let mut groups = Vec::new();
+
+    for color in [StoneColor::Black, StoneColor::White] {
+        let chains = chains(board_state, color);
+        let chain_of = chain_index(&chains);
+        let mut parents: Vec<usize> = (0..chains.len()).collect();
+
+        // 只被己方包围、且不含双活公气的空点区域是眼，共用一只眼的棋串算作一块
+        let mut eyes: Vec<(usize, usize)> = Vec::new();
+        for region in regions(board_state, color) {
+            let enclosed = region
+                .iter()
+                .all(|&(x, y)| board_state.get_stone(x, y).is_none());
+            if !enclosed || region.iter().any(|point| seki.contains(point)) {
+                continue;
+            }
+            let borders: Vec<usize> = bordering_chains(board_state, &region, &chain_of)
+                .into_iter()
+                .collect();
+            let Some(&first) = borders.first() else {
+                continue;
+            };
+            for &chain in &borders[1..] {
+                let (a, b) = (find(&mut parents, first), find(&mut parents, chain));
+                parents[b] = a;
+            }
+            eyes.push((first, region.len()));
+        }
+
+        // 每个点所在的对方包围圈的大小
+        let mut enclosure = HashMap::new();
+        for region in regions(board_state, color.opposite()) {
+            for &point in &region {
+                enclosure.insert(point, region.len());
+            }
+        }
+
+        let mut members: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+        for chain in 0..chains.len() {
+            let root = find(&mut parents, chain);
+            members.entry(root).or_default().push(chain);
+        }
+        let mut eye_counts: HashMap<usize, (usize, usize)> = HashMap::new();
+        for (chain, eye_size) in eyes {
+            let entry = eye_counts.entry(find(&mut parents, chain)).or_default();
+            entry.0 += 1;
+            entry.1 += eye_size;
+        }
+
+        for (root, member_chains) in members {
+            let stones: HashSet<(i32, i32)> = member_chains
+                .iter()
+                .flat_map(|&chain| chains[chain].iter().copied())
+                .collect();
+            let liberties: HashSet<(i32, i32)> = stones
+                .iter()
+                .flat_map(|&(x, y)| board_state.get_neighbors(x, y))
+                .filter(|&(x, y)| board_state.get_stone(x, y).is_none())
+                .collect();
+            let (eye_count, eye_space) = eye_counts.get(&root).copied().unwrap_or_default();
+            let enclosed = stones
+                .iter()
+                .next()
+                .and_then(|point| enclosure.get(point))
+                .is_some_and(|&enclosure| enclosure * 2 <= area);
+
+            let status = if stones.iter().all(|point| unconditional.contains(point)) {
+                GroupStatus::UnconditionallyAlive
+            } else if eye_count >= 2
+                || eye_space > MAX_EYE_SIZE
+                || liberties.iter().any(|point| seki.contains(point))
+            {
+                GroupStatus::Alive
+            } else if enclosed {
+                GroupStatus::Dead
+            } else if eye_count >= 1 && liberties.len() >= SAFE_LIBERTIES {
+                GroupStatus::Alive
+            } else {
+                GroupStatus::Weak
+            };
+
+            groups.push(GroupLife {
+                color,
+                stones,
+                liberties: liberties.len(),
+                eyes: eye_count,
+                eye_space,
+                status,
+            });
+        }
+    }
+
+    groups
+}
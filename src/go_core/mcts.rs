@@ -0,0 +1,630 @@
+use super::board::{BoardState, GameHistory, StoneColor};
+use super::bot::{GeneratedMove, MoveGenerator, should_pass_back};
+use super::features::FeaturePlanes;
+use super::policy::PolicyEvaluator;
+use super::rules::GoBoardRules;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::time::{Duration, Instant};
+
+// 蒙特卡洛树搜索（UCT）电脑棋手
+// 模拟对局在紧凑的内部棋盘上进行，只在根局面和最终决策时使用 GoBoardRules
+
+/// 每手棋的搜索预算
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SearchBudget {
+    /// 固定模拟次数，结果可复现
+    Playouts(u32),
+    /// 固定思考时间
+    Time(Duration),
+}
+
+/// 模拟对局的走子策略
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayoutPolicy {
+    /// 随机选择不填己方眼位的合法点
+    Random,
+    /// 先提掉被打吃的棋、救出被打吃的棋，否则随机
+    Light,
+}
+
+/// MCTS 参数
+#[derive(Debug, Clone)]
+pub struct MctsConfig {
+    pub budget: SearchBudget,
+    pub policy: PlayoutPolicy,
+    /// UCT 探索常数
+    pub exploration: f32,
+    /// 最佳着法胜率低于此值时认输
+    pub resign_threshold: f32,
+    /// 随机种子，`None` 时使用系统熵
+    pub seed: Option<u64>,
+}
+
+impl Default for MctsConfig {
+    fn default() -> Self {
+        Self {
+            budget: SearchBudget::Time(Duration::from_secs(1)),
+            policy: PlayoutPolicy::Light,
+            exploration: 0.7,
+            resign_threshold: 0.1,
+            seed: None,
+        }
+    }
+}
+
+/// 认输前根节点至少需要的模拟次数，避免样本太少时误判
+const MIN_VISITS_TO_RESIGN: u32 = 200;
+
+/// 策略网络先验在 UCT 值中的权重，随访问次数衰减
+const PRIOR_WEIGHT: f32 = 2.0;
+
+/// MCTS 电脑棋手
+///
+/// # 使用示例
+/// ```rust,ignore
+/// let mut bot = MctsBot::new(MctsConfig {
+///     budget: SearchBudget::Playouts(5000),
+///     ..Default::default()
+/// });
+/// let generated = bot.generate_move(&board_state, &history, StoneColor::White, DEFAULT_KOMI);
+/// ```
+pub struct MctsBot {
+    config: MctsConfig,
+    rng: StdRng,
+    /// 可选的策略网络，给根节点的候选点提供先验
+    policy: Option<Box<dyn PolicyEvaluator>>,
+}
+
+impl MctsBot {
+    pub fn new(config: MctsConfig) -> Self {
+        let rng = match config.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        Self {
+            config,
+            rng,
+            policy: None,
+        }
+    }
+
+    /// 用策略网络引导根节点：先展开概率高的点，并在 UCT 值中加入先验
+    ///
+    /// 网络推理失败时退回普通搜索
+    pub fn with_policy(mut self, policy: Box<dyn PolicyEvaluator>) -> Self {
+        self.policy = Some(policy);
+        self
+    }
+
+    /// 根节点各点的先验概率，按 `y * size + x` 排列
+    ///
+    /// 历史平面由 `history` 重放得到；历史与当前局面对不上时（如直接摆上的让子）只用当前局面
+    fn root_priors(
+        &mut self,
+        board_state: &BoardState,
+        history: &GameHistory,
+        color: StoneColor,
+    ) -> Option<Vec<f32>> {
+        let policy = self.policy.as_mut()?;
+        let played = history.played_moves();
+        let planes = match played.last() {
+            Some(last) if last.position_hash == board_state.position_hash() => {
+                FeaturePlanes::from_moves(board_state.board_size, played, color)
+            }
+            _ => FeaturePlanes::from_board(board_state, color),
+        };
+        let mut output = policy.evaluate(&planes).ok()?;
+        output
+            .mask_illegal(board_state, color, Some(history))
+            .ok()?;
+        let area = board_state.board_size.get_value() as usize;
+        output.probabilities.truncate(area * area);
+        Some(output.probabilities)
+    }
+}
+
+impl MoveGenerator for MctsBot {
+    fn name(&self) -> &str {
+        "mcts"
+    }
+
+    fn generate_move(
+        &mut self,
+        board_state: &BoardState,
+        history: &GameHistory,
+        color: StoneColor,
+        komi: f32,
+    ) -> GeneratedMove {
+        if should_pass_back(board_state, color, komi) {
+            return GeneratedMove::Pass;
+        }
+
+        let priors = self.root_priors(board_state, history, color);
+        let root_board = PlayoutBoard::from_state(board_state);
+        let size = root_board.size;
+        let Some((point, winrate, root_visits)) = search(
+            &self.config,
+            &mut self.rng,
+            &root_board,
+            priors.as_deref(),
+            color,
+            komi,
+        ) else {
+            return GeneratedMove::Pass;
+        };
+
+        if winrate < self.config.resign_threshold && root_visits >= MIN_VISITS_TO_RESIGN {
+            return GeneratedMove::Resign;
+        }
+
+        let (x, y) = ((point % size) as i32, (point / size) as i32);
+        // 内部棋盘不处理多步劫和全局同形，最终再用规则引擎确认
+        if GoBoardRules::check_move_with_history(board_state, history, x, y, color).is_ok() {
+            GeneratedMove::Play(x, y)
+        } else {
+            GeneratedMove::Pass
+        }
+    }
+}
+
+/// 搜索树节点
+struct Node {
+    /// 到达该节点的着法
+    point: usize,
+    /// 下出该着法的一方
+    player: StoneColor,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    /// 尚未展开的候选点
+    untried: Vec<usize>,
+    visits: u32,
+    /// `player` 一方获胜的次数
+    wins: f32,
+    /// 策略网络给出的先验概率，只用于根节点的子节点
+    prior: f32,
+}
+
+/// 运行 UCT 搜索，返回访问最多的着法、它的胜率和根节点访问次数
+fn search(
+    config: &MctsConfig,
+    rng: &mut StdRng,
+    root_board: &PlayoutBoard,
+    priors: Option<&[f32]>,
+    color: StoneColor,
+    komi: f32,
+) -> Option<(usize, f32, u32)> {
+    let mut nodes = vec![Node {
+        point: usize::MAX,
+        player: color.opposite(),
+        parent: None,
+        children: Vec::new(),
+        untried: root_board.candidates(color),
+        visits: 0,
+        wins: 0.0,
+        prior: 0.0,
+    }];
+
+    let started = Instant::now();
+    let mut playouts = 0;
+    loop {
+        let done = match config.budget {
+            SearchBudget::Playouts(limit) => playouts >= limit,
+            SearchBudget::Time(limit) => started.elapsed() >= limit,
+        };
+        if done {
+            break;
+        }
+        playouts += 1;
+
+        let mut board = root_board.clone();
+        let mut current = 0;
+
+        // 选择：节点完全展开后沿 UCT 值最大的子节点向下
+        while nodes[current].untried.is_empty() && !nodes[current].children.is_empty() {
+            current = select_child(&nodes, current, config.exploration);
+            board.play(nodes[current].point, nodes[current].player);
+        }
+
+        // 展开：尝试一个未展开的候选点，不合法的直接丢弃
+        let to_play = nodes[current].player.opposite();
+        while !nodes[current].untried.is_empty() {
+            let untried = &nodes[current].untried;
+            let index = match priors {
+                // 根节点按先验从高到低展开
+                Some(priors) if current == 0 => (0..untried.len())
+                    .max_by(|&a, &b| priors[untried[a]].total_cmp(&priors[untried[b]]))
+                    .unwrap_or(0),
+                _ => rng.gen_range(0..untried.len()),
+            };
+            let point = nodes[current].untried.swap_remove(index);
+            if board.play(point, to_play) {
+                let child = nodes.len();
+                nodes.push(Node {
+                    point,
+                    player: to_play,
+                    parent: Some(current),
+                    children: Vec::new(),
+                    untried: board.candidates(to_play.opposite()),
+                    visits: 0,
+                    wins: 0.0,
+                    prior: match priors {
+                        Some(priors) if current == 0 => priors[point],
+                        _ => 0.0,
+                    },
+                });
+                nodes[current].children.push(child);
+                current = child;
+                break;
+            }
+        }
+
+        // 模拟
+        let next = if current == 0 {
+            color
+        } else {
+            nodes[current].player.opposite()
+        };
+        let margin = board.playout(next, config.policy, rng) - komi;
+        let winner = if margin > 0.0 {
+            StoneColor::Black
+        } else {
+            StoneColor::White
+        };
+
+        // 回传
+        let mut node = Some(current);
+        while let Some(index) = node {
+            nodes[index].visits += 1;
+            if nodes[index].player == winner {
+                nodes[index].wins += 1.0;
+            }
+            node = nodes[index].parent;
+        }
+    }
+
+    let best = nodes[0]
+        .children
+        .iter()
+        .copied()
+        .max_by_key(|&child| nodes[child].visits)?;
+    let winrate = nodes[best].wins / nodes[best].visits.max(1) as f32;
+    Some((nodes[best].point, winrate, nodes[0].visits))
+}
+
+/// UCT 选择
+fn select_child(nodes: &[Node], parent: usize, exploration: f32) -> usize {
+    let log_visits = (nodes[parent].visits.max(1) as f32).ln();
+    let uct = |child: usize| {
+        let node = &nodes[child];
+        let visits = node.visits.max(1) as f32;
+        node.wins / visits
+            + exploration * (log_visits / visits).sqrt()
+            + PRIOR_WEIGHT * node.prior / (1.0 + node.visits as f32)
+    };
+
+    nodes[parent]
+        .children
+        .iter()
+        .copied()
+        .max_by(|&a, &b| uct(a).total_cmp(&uct(b)))
+        .expect("parent has children")
+}
+
+/// 模拟对局用的紧凑棋盘：按 `y * size + x` 存放，带打劫点和上一手
+#[derive(Clone)]
+struct PlayoutBoard {
+    size: usize,
+    cells: Vec<Option<StoneColor>>,
+    ko: Option<usize>,
+    last_move: Option<usize>,
+    /// 空点列表，随机走子时从中挑选
+    empty: Vec<usize>,
+    /// 每个空点在 `empty` 中的下标
+    empty_index: Vec<usize>,
+    /// 遍历棋串时的访问标记
+    marks: Vec<u32>,
+    mark: u32,
+    /// 遍历棋串用的栈，复用以免反复分配
+    stack: Vec<usize>,
+}
+
+impl PlayoutBoard {
+    fn from_state(board_state: &BoardState) -> Self {
+        let size = board_state.board_size.get_value() as usize;
+        let index = |(x, y): (i32, i32)| y as usize * size + x as usize;
+
+        let mut cells = vec![None; size * size];
+        for y in 0..size {
+            for x in 0..size {
+                cells[y * size + x] = board_state.stones[x][y];
+            }
+        }
+
+        let mut empty = Vec::with_capacity(cells.len());
+        let mut empty_index = vec![0; cells.len()];
+        for (point, cell) in cells.iter().enumerate() {
+            if cell.is_none() {
+                empty_index[point] = empty.len();
+                empty.push(point);
+            }
+        }
+
+        Self {
+            size,
+            cells,
+            ko: board_state.ko_position.map(index),
+            last_move: board_state.last_move.map(index),
+            empty,
+            empty_index,
+            marks: vec![0; size * size],
+            mark: 0,
+            stack: Vec::new(),
+        }
+    }
+
+    fn neighbors(&self, point: usize) -> impl Iterator<Item = usize> + use<> {
+        neighbors(self.size, point)
+    }
+
+    /// 某一方可以考虑的点：空点且不是己方眼位
+    fn candidates(&self, color: StoneColor) -> Vec<usize> {
+        self.empty
+            .iter()
+            .copied()
+            .filter(|&point| !self.is_eye(point, color))
+            .collect()
+    }
+
+    /// 眼形判断，与 `GoBoardRules::is_eye_like` 相同
+    fn is_eye(&self, point: usize, color: StoneColor) -> bool {
+        if self
+            .neighbors(point)
+            .any(|neighbor| self.cells[neighbor] != Some(color))
+        {
+            return false;
+        }
+
+        let size = self.size as i32;
+        let (x, y) = ((point % self.size) as i32, (point / self.size) as i32);
+        let mut on_board = 0;
+        let mut opponent = 0;
+        for (dx, dy) in [(1, 1), (1, -1), (-1, 1), (-1, -1)] {
+            let (cx, cy) = (x + dx, y + dy);
+            if (0..size).contains(&cx) && (0..size).contains(&cy) {
+                on_board += 1;
+                if self.cells[(cy * size + cx) as usize] == Some(color.opposite()) {
+                    opponent += 1;
+                }
+            }
+        }
+
+        if on_board < 4 {
+            opponent == 0
+        } else {
+            opponent <= 1
+        }
+    }
+
+    /// 下一手棋，不合法时棋盘不变并返回 `false`
+    fn play(&mut self, point: usize, color: StoneColor) -> bool {
+        if self.cells[point].is_some() || self.ko == Some(point) {
+            return false;
+        }
+        // 先只改格子，确认合法后再更新空点列表
+        self.cells[point] = Some(color);
+
+        let mut captured = 0;
+        let mut captured_point = point;
+        for neighbor in self.neighbors(point) {
+            if self.cells[neighbor] == Some(color.opposite()) && !self.has_liberty(neighbor) {
+                captured += self.remove_group(neighbor);
+                captured_point = neighbor;
+            }
+        }
+
+        // 自杀
+        if captured == 0 && !self.has_liberty(point) {
+            self.cells[point] = None;
+            return false;
+        }
+        self.remove_empty(point);
+
+        // 单子提单子且只剩一口气时形成劫
+        let lone = self
+            .neighbors(point)
+            .all(|neighbor| self.cells[neighbor] != Some(color));
+        self.ko =
+            (captured == 1 && lone && self.liberties(point, 2).0 == 1).then_some(captured_point);
+        self.last_move = Some(point);
+        true
+    }
+
+    fn remove_empty(&mut self, point: usize) {
+        let index = self.empty_index[point];
+        self.empty.swap_remove(index);
+        if let Some(&moved) = self.empty.get(index) {
+            self.empty_index[moved] = index;
+        }
+    }
+
+    fn add_empty(&mut self, point: usize) {
+        self.empty_index[point] = self.empty.len();
+        self.empty.push(point);
+    }
+
+    /// 棋串是否还有气，相邻空点直接判断，否则遍历棋串
+    fn has_liberty(&mut self, start: usize) -> bool {
+        self.neighbors(start)
+            .any(|neighbor| self.cells[neighbor].is_none())
+            || self.liberties(start, 1).0 > 0
+    }
+
+    /// 棋串的气数（最多数到 `limit`）和找到的最后一口气
+    fn liberties(&mut self, start: usize, limit: usize) -> (usize, usize) {
+        let Some(color) = self.cells[start] else {
+            return (0, start);
+        };
+        self.mark += 1;
+        let mark = self.mark;
+
+        let mut count = 0;
+        let mut liberty = start;
+        let mut stack = std::mem::take(&mut self.stack);
+        stack.clear();
+        stack.push(start);
+        self.marks[start] = mark;
+        'search: while let Some(point) = stack.pop() {
+            for neighbor in self.neighbors(point) {
+                if self.marks[neighbor] == mark {
+                    continue;
+                }
+                match self.cells[neighbor] {
+                    None => {
+                        self.marks[neighbor] = mark;
+                        count += 1;
+                        liberty = neighbor;
+                        if count >= limit {
+                            break 'search;
+                        }
+                    }
+                    Some(other) if other == color => {
+                        self.marks[neighbor] = mark;
+                        stack.push(neighbor);
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+        self.stack = stack;
+        (count, liberty)
+    }
+
+    /// 提掉整个棋串，返回提子数
+    fn remove_group(&mut self, start: usize) -> usize {
+        let color = self.cells[start];
+        let mut removed = 0;
+        let mut stack = std::mem::take(&mut self.stack);
+        stack.clear();
+        stack.push(start);
+        self.cells[start] = None;
+        while let Some(point) = stack.pop() {
+            removed += 1;
+            self.add_empty(point);
+            for neighbor in self.neighbors(point) {
+                if self.cells[neighbor].is_some() && self.cells[neighbor] == color {
+                    self.cells[neighbor] = None;
+                    stack.push(neighbor);
+                }
+            }
+        }
+        self.stack = stack;
+        removed
+    }
+
+    /// 按策略下一手，没有可下的点时返回 `false`（虚手）
+    fn play_playout_move(
+        &mut self,
+        color: StoneColor,
+        policy: PlayoutPolicy,
+        rng: &mut StdRng,
+    ) -> bool {
+        if policy == PlayoutPolicy::Light && self.play_tactical_move(color) {
+            return true;
+        }
+
+        // 从随机位置开始依次尝试空点；失败的尝试不改变棋盘和空点列表
+        let count = self.empty.len();
+        if count == 0 {
+            return false;
+        }
+        let start = rng.gen_range(0..count);
+        for offset in 0..count {
+            let point = self.empty[(start + offset) % count];
+            if !self.is_eye(point, color) && self.play(point, color) {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// 上一手附近的战术着法：提掉被打吃的对方棋串，救出被打吃的己方棋串
+    fn play_tactical_move(&mut self, color: StoneColor) -> bool {
+        let Some(last) = self.last_move else {
+            return false;
+        };
+
+        if self.cells[last] == Some(color.opposite()) {
+            let (count, liberty) = self.liberties(last, 2);
+            if count == 1 && self.play(liberty, color) {
+                return true;
+            }
+        }
+
+        for neighbor in self.neighbors(last) {
+            if self.cells[neighbor] == Some(color) {
+                let (count, liberty) = self.liberties(neighbor, 2);
+                if count == 1 && self.play(liberty, color) {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    /// 模拟到双方都无棋可下，返回黑方减白方的数子差（不含贴目）
+    fn playout(&mut self, mut to_play: StoneColor, policy: PlayoutPolicy, rng: &mut StdRng) -> f32 {
+        let max_moves = self.cells.len() * 3;
+        let mut passes = 0;
+        for _ in 0..max_moves {
+            if self.play_playout_move(to_play, policy, rng) {
+                passes = 0;
+            } else {
+                passes += 1;
+                if passes >= 2 {
+                    break;
+                }
+            }
+            to_play = to_play.opposite();
+        }
+        self.area_margin()
+    }
+
+    /// 数子差：棋子加上四周只有一方棋子的空点
+    fn area_margin(&self) -> f32 {
+        let mut margin = 0.0;
+        for point in 0..self.cells.len() {
+            let owner = match self.cells[point] {
+                Some(color) => Some(color),
+                None => {
+                    let mut colors = self.neighbors(point).map(|neighbor| self.cells[neighbor]);
+                    let first = colors.next().flatten();
+                    if first.is_some() && colors.all(|color| color == first) {
+                        first
+                    } else {
+                        None
+                    }
+                }
+            };
+            match owner {
+                Some(StoneColor::Black) => margin += 1.0,
+                Some(StoneColor::White) => margin -= 1.0,
+                None => {}
+            }
+        }
+        margin
+    }
+}
+
+/// 上下左右的相邻点
+fn neighbors(size: usize, point: usize) -> impl Iterator<Item = usize> {
+    let (x, y) = (point % size, point / size);
+    [
+        (x > 0).then(|| point - 1),
+        (x + 1 < size).then(|| point + 1),
+        (y > 0).then(|| point - size),
+        (y + 1 < size).then(|| point + size),
+    ]
+    .into_iter()
+    .flatten()
+}
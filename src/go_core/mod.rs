@@ -0,0 +1,55 @@
+// 围棋核心 - 不依赖 Bevy 的规则、棋盘、坐标、计分、对杀与双活、死活估计、形势判断、GTP 引擎/客户端、分析协议、电脑棋手、策略网络、自对弈、比赛、死活题与征子计算
+// Go core - Bevy-free rules, board, coordinates, scoring, semeai and seki, group status, score estimation, GTP engine/client, analysis protocol, bots, policy network, self-play, tournaments, tsumego and ladder reading
+//
+// 使用 `default-features = false` 时只编译这一部分
+
+pub mod analysis;
+pub mod board;
+pub mod bot;
+pub mod coordinates;
+pub mod features;
+pub mod gtp;
+pub mod gtp_client;
+pub mod heuristic;
+pub mod influence;
+pub mod ladder;
+pub mod life;
+pub mod mcts;
+#[cfg(feature = "nn")]
+pub mod onnx;
+pub mod policy;
+pub mod rules;
+pub mod scoring;
+pub mod selfplay;
+pub mod semeai;
+pub mod sgf;
+pub mod tournament;
+pub mod training;
+pub mod tsumego;
+
+// Re-export main types for convenience
+pub use analysis::{AnalysisError, AnalysisQuery, AnalysisResponse, MoveInfo, RootInfo};
+pub use board::{BoardSize, BoardState, GameHistory, Move, StoneColor};
+pub use bot::{GeneratedMove, MoveGenerator, RandomMoveGenerator};
+pub use coordinates::BoardCoordinates;
+pub use features::{FEATURE_PLANES, FeaturePlanes, HISTORY_LENGTH};
+pub use gtp::GtpEngine;
+pub use gtp_client::{GtpClient, GtpError};
+pub use heuristic::{BotLevel, HeuristicBot};
+pub use influence::ScoreEstimate;
+pub use ladder::{CaptureMethod, CaptureReading, LadderPath, TacticalReader};
+pub use life::{GroupLife, GroupStatus};
+pub use mcts::{MctsBot, MctsConfig, PlayoutPolicy, SearchBudget};
+#[cfg(feature = "nn")]
+pub use onnx::OnnxPolicy;
+pub use policy::{PolicyError, PolicyEvaluator, PolicyOutput};
+pub use rules::{GoBoardRules, IllegalMove};
+pub use scoring::{DEFAULT_KOMI, EmptyRegion};
+pub use selfplay::{GameResult, SelfPlayConfig, SelfPlayGame};
+pub use semeai::{RaceLiberties, RaceOutcome, SemeaiAnalysis};
+pub use sgf::SgfRecord;
+pub use tournament::{Crosstable, TournamentFormat};
+pub use training::TrainingSamples;
+pub use tsumego::{
+    LifeStatus, TsumegoError, TsumegoGoal, TsumegoProblem, TsumegoSolution, TsumegoSolver,
+};
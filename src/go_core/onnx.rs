@@ -0,0 +1,104 @@
+use super::board::BoardSize;
+use super::features::{FEATURE_PLANES, FeaturePlanes};
+use super::policy::{PolicyError, PolicyEvaluator, PolicyOutput};
+use std::io::Read;
+use std::path::Path;
+use tract_onnx::prelude::*;
+
+// 用 tract 在 CPU 上运行 ONNX 策略/价值网络（`nn` 特性）
+//
+// 模型约定：
+// - 输入 `[1, 17, N, N]` 的 f32 特征平面，见 `FeaturePlanes`
+// - 第一个输出是 `[1, N * N + 1]` 的策略 logits，最后一项为虚手
+// - 第二个输出是轮到方视角的价值，取第一个元素，范围 [-1, 1]
+
+/// ONNX 策略/价值网络
+///
+/// 模型按固定的棋盘大小优化，不同大小的棋盘需要分别加载
+///
+/// # 使用示例
+/// ```rust,ignore
+/// let mut network = OnnxPolicy::load("policy_9x9.onnx", BoardSize::Nine)?;
+/// let planes = FeaturePlanes::from_board(&board_state, StoneColor::Black);
+/// let output = network.evaluate(&planes)?;
+/// println!("{:?} {:.1}%", output.top_moves(3), output.winrate * 100.0);
+/// ```
+pub struct OnnxPolicy {
+    plan: TypedRunnableModel<TypedModel>,
+    board_size: BoardSize,
+}
+
+impl OnnxPolicy {
+    /// 从文件加载模型
+    pub fn load(path: impl AsRef<Path>, board_size: BoardSize) -> Result<Self, PolicyError> {
+        let model = tract_onnx::onnx()
+            .model_for_path(path)
+            .map_err(model_error)?;
+        Self::from_model(model, board_size)
+    }
+
+    /// 从任意数据源加载模型
+    pub fn from_reader(reader: &mut dyn Read, board_size: BoardSize) -> Result<Self, PolicyError> {
+        let model = tract_onnx::onnx()
+            .model_for_read(reader)
+            .map_err(model_error)?;
+        Self::from_model(model, board_size)
+    }
+
+    pub fn board_size(&self) -> BoardSize {
+        self.board_size
+    }
+
+    fn from_model(model: InferenceModel, board_size: BoardSize) -> Result<Self, PolicyError> {
+        let size = board_size.get_value() as usize;
+        let plan = model
+            .with_input_fact(0, f32::fact([1, FEATURE_PLANES, size, size]).into())
+            .and_then(|model| model.into_optimized())
+            .and_then(|model| model.into_runnable())
+            .map_err(model_error)?;
+        Ok(Self { plan, board_size })
+    }
+}
+
+impl PolicyEvaluator for OnnxPolicy {
+    fn evaluate(&mut self, planes: &FeaturePlanes) -> Result<PolicyOutput, PolicyError> {
+        if planes.board_size != self.board_size {
+            return Err(PolicyError::Shape(format!(
+                "model expects a {}x{} board",
+                self.board_size.get_value(),
+                self.board_size.get_value()
+            )));
+        }
+
+        let input = Tensor::from_shape(&planes.shape(), &planes.data).map_err(model_error)?;
+        let outputs = self.plan.run(tvec!(input.into())).map_err(model_error)?;
+        if outputs.len() < 2 {
+            return Err(PolicyError::Shape(format!(
+                "expected policy and value outputs, got {}",
+                outputs.len()
+            )));
+        }
+
+        let size = self.board_size.get_value() as usize;
+        let logits = outputs[0].as_slice::<f32>().map_err(model_error)?;
+        if logits.len() != size * size + 1 {
+            return Err(PolicyError::Shape(format!(
+                "expected {} policy logits, got {}",
+                size * size + 1,
+                logits.len()
+            )));
+        }
+        let value = outputs[1]
+            .as_slice::<f32>()
+            .map_err(model_error)?
+            .first()
+            .copied()
+            .ok_or_else(|| PolicyError::Shape("empty value output".to_string()))?;
+
+        Ok(PolicyOutput::from_logits(self.board_size, logits, value))
+    }
+}
+
+fn model_error(error: TractError) -> PolicyError {
+    PolicyError::Model(format!("{:#}", error))
+}
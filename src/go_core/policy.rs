@@ -0,0 +1,136 @@
+use super::board::{BoardSize, BoardState, GameHistory, StoneColor};
+use super::features::FeaturePlanes;
+use super::rules::GoBoardRules;
+use std::fmt;
+
+// 策略/价值网络的输出和评估器接口
+// 具体实现见启用 `nn` 特性后的 `OnnxPolicy`
+
+/// 网络对一个局面的判断
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolicyOutput {
+    pub board_size: BoardSize,
+    /// 每个点的落子概率，按 `y * size + x` 排列，最后一项是虚手
+    pub probabilities: Vec<f32>,
+    /// 轮到方的胜率，范围 [0, 1]
+    pub winrate: f32,
+}
+
+impl PolicyOutput {
+    /// 由策略 logits 和价值（轮到方视角，范围 [-1, 1]）构造
+    pub fn from_logits(board_size: BoardSize, logits: &[f32], value: f32) -> Self {
+        let max = logits.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+        let exps: Vec<f32> = logits.iter().map(|logit| (logit - max).exp()).collect();
+        let sum: f32 = exps.iter().sum();
+
+        Self {
+            board_size,
+            probabilities: exps.into_iter().map(|value| value / sum).collect(),
+            winrate: ((value.clamp(-1.0, 1.0) + 1.0) / 2.0),
+        }
+    }
+
+    /// 某一点的落子概率
+    pub fn probability(&self, x: i32, y: i32) -> f32 {
+        let size = self.board_size.get_value();
+        if !(0..size).contains(&x) || !(0..size).contains(&y) {
+            return 0.0;
+        }
+        self.probabilities
+            .get((y * size + x) as usize)
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    /// 虚手的概率
+    pub fn pass_probability(&self) -> f32 {
+        let size = self.board_size.get_value();
+        self.probabilities
+            .get((size * size) as usize)
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    /// 把非法点的概率清零后重新归一化，给出 `history` 时同时去掉重复本局局面的点
+    ///
+    /// 概率个数不是棋盘点数加一（虚手）或棋盘大小与局面不符时返回错误，概率不变
+    pub fn mask_illegal(
+        &mut self,
+        board_state: &BoardState,
+        color: StoneColor,
+        history: Option<&GameHistory>,
+    ) -> Result<(), PolicyError> {
+        let size = self.board_size.get_value();
+        let expected = (size * size + 1) as usize;
+        if self.board_size != board_state.board_size || self.probabilities.len() != expected {
+            return Err(PolicyError::Shape(format!(
+                "{} probabilities for a {}x{} board, expected {}",
+                self.probabilities.len(),
+                board_state.board_size.get_value(),
+                board_state.board_size.get_value(),
+                expected
+            )));
+        }
+
+        for y in 0..size {
+            for x in 0..size {
+                let legal = match history {
+                    Some(history) => {
+                        GoBoardRules::check_move_with_history(board_state, history, x, y, color)
+                    }
+                    None => GoBoardRules::check_move(board_state, x, y, color),
+                };
+                if legal.is_err() {
+                    self.probabilities[(y * size + x) as usize] = 0.0;
+                }
+            }
+        }
+
+        let sum: f32 = self.probabilities.iter().sum();
+        if sum > 0.0 {
+            for probability in &mut self.probabilities {
+                *probability /= sum;
+            }
+        }
+        Ok(())
+    }
+
+    /// 概率最高的 `count` 个落点，不含虚手
+    pub fn top_moves(&self, count: usize) -> Vec<((i32, i32), f32)> {
+        let size = self.board_size.get_value();
+        let mut moves: Vec<((i32, i32), f32)> = (0..size)
+            .flat_map(|y| (0..size).map(move |x| (x, y)))
+            .map(|(x, y)| ((x, y), self.probability(x, y)))
+            .filter(|&(_, probability)| probability > 0.0)
+            .collect();
+        moves.sort_by(|a, b| b.1.total_cmp(&a.1));
+        moves.truncate(count);
+        moves
+    }
+}
+
+/// 策略/价值评估器
+pub trait PolicyEvaluator: Send {
+    /// 评估一个局面，特征平面的最后一层决定轮到哪一方
+    fn evaluate(&mut self, planes: &FeaturePlanes) -> Result<PolicyOutput, PolicyError>;
+}
+
+/// 网络加载或推理失败的原因
+#[derive(Debug)]
+pub enum PolicyError {
+    /// 模型文件无法读取或推理出错
+    Model(String),
+    /// 输入或输出的形状与约定不符
+    Shape(String),
+}
+
+impl fmt::Display for PolicyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PolicyError::Model(message) => write!(f, "policy network error: {}", message),
+            PolicyError::Shape(message) => write!(f, "policy network shape mismatch: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for PolicyError {}
@@ -0,0 +1,284 @@
+use super::board::{BoardState, GameHistory, StoneColor};
+use std::collections::HashSet;
+use std::fmt;
+
+/// 不合法着法的原因
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IllegalMove {
+    /// 超出棋盘
+    OutOfBounds,
+    /// 该位置已有棋子
+    Occupied,
+    /// 打劫：不能立即提回
+    Ko,
+    /// 自杀：落子后己方无气且不能提子
+    Suicide,
+    /// 全局同形：落子后重复本局出现过的局面
+    Superko,
+}
+
+impl fmt::Display for IllegalMove {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reason = match self {
+            IllegalMove::OutOfBounds => "out of bounds",
+            IllegalMove::Occupied => "point is occupied",
+            IllegalMove::Ko => "ko",
+            IllegalMove::Suicide => "suicide",
+            IllegalMove::Superko => "superko",
+        };
+        f.write_str(reason)
+    }
+}
+
+/// 围棋规则引擎
+pub struct GoBoardRules;
+
+impl GoBoardRules {
+    /// 检查落子是否合法
+    pub fn is_valid_move(board_state: &BoardState, x: i32, y: i32, color: StoneColor) -> bool {
+        Self::check_move(board_state, x, y, color).is_ok()
+    }
+
+    /// 检查落子是否合法，不合法时给出原因
+    pub fn check_move(
+        board_state: &BoardState,
+        x: i32,
+        y: i32,
+        color: StoneColor,
+    ) -> Result<(), IllegalMove> {
+        // 检查位置是否在棋盘内
+        let size = board_state.board_size.get_value();
+        if !(0..size).contains(&x) || !(0..size).contains(&y) {
+            return Err(IllegalMove::OutOfBounds);
+        }
+
+        // 检查位置是否已有棋子
+        if board_state.stones[x as usize][y as usize].is_some() {
+            return Err(IllegalMove::Occupied);
+        }
+
+        // 检查是否违反打劫规则
+        if board_state.ko_position == Some((x, y)) {
+            return Err(IllegalMove::Ko);
+        }
+
+        // 检查自杀规则
+        if Self::is_suicide(board_state, x, y, color) {
+            return Err(IllegalMove::Suicide);
+        }
+
+        Ok(())
+    }
+
+    /// 按完整规则检查落子，并按全局同形禁着检查落子后的局面是否在 `history` 中出现过
+    pub fn check_move_with_history(
+        board_state: &BoardState,
+        history: &GameHistory,
+        x: i32,
+        y: i32,
+        color: StoneColor,
+    ) -> Result<(), IllegalMove> {
+        let mut next = board_state.clone();
+        Self::play_move(&mut next, x, y, color)?;
+        Self::check_superko(&next, history)
+    }
+
+    /// 全局同形禁着：`next` 是落子后的局面，与本局出现过的局面（含开局的空棋盘）相同时不合法
+    pub fn check_superko(next: &BoardState, history: &GameHistory) -> Result<(), IllegalMove> {
+        if history.repeats_position(next) {
+            Err(IllegalMove::Superko)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// 按完整规则下一手棋：检查合法性、落子、提子并更新打劫点，返回被提的棋子
+    pub fn play_move(
+        board_state: &mut BoardState,
+        x: i32,
+        y: i32,
+        color: StoneColor,
+    ) -> Result<Vec<(i32, i32)>, IllegalMove> {
+        Self::check_move(board_state, x, y, color)?;
+        board_state.place_stone(x, y, color);
+        let captured = Self::capture_stones(board_state, x, y, color);
+        Self::update_ko(board_state, x, y, &captured);
+        Ok(captured)
+    }
+
+    /// 虚手：清除打劫点并记录连续虚手次数
+    pub fn pass(board_state: &mut BoardState) {
+        board_state.ko_position = None;
+        board_state.consecutive_passes += 1;
+    }
+
+    /// 落子提子后更新打劫点
+    ///
+    /// 只有单子提单子、且提子的棋子只剩被提位置这一口气时才形成劫
+    pub fn update_ko(board_state: &mut BoardState, x: i32, y: i32, captured: &[(i32, i32)]) {
+        board_state.ko_position = None;
+
+        if captured.len() != 1 {
+            return;
+        }
+
+        let group = Self::get_group(board_state, x, y);
+        if group.len() == 1 && Self::count_liberties(board_state, &group) == 1 {
+            board_state.ko_position = Some(captured[0]);
+        }
+    }
+
+    /// 某一方所有合法落点
+    pub fn legal_moves(board_state: &BoardState, color: StoneColor) -> Vec<(i32, i32)> {
+        let size = board_state.board_size.get_value();
+        let mut moves = Vec::new();
+        for x in 0..size {
+            for y in 0..size {
+                if Self::is_valid_move(board_state, x, y, color) {
+                    moves.push((x, y));
+                }
+            }
+        }
+        moves
+    }
+
+    /// 某一方所有合法落点，排除违反全局同形禁着的点
+    pub fn legal_moves_with_history(
+        board_state: &BoardState,
+        history: &GameHistory,
+        color: StoneColor,
+    ) -> Vec<(i32, i32)> {
+        Self::legal_moves(board_state, color)
+            .into_iter()
+            .filter(|&(x, y)| {
+                Self::check_move_with_history(board_state, history, x, y, color).is_ok()
+            })
+            .collect()
+    }
+
+    /// 是否为某一方的眼形：四周都是己方棋子，且斜角没有被对方占据过多
+    ///
+    /// 边角上斜角不能有对方棋子，中腹最多一个
+    pub fn is_eye_like(board_state: &BoardState, x: i32, y: i32, color: StoneColor) -> bool {
+        if board_state.get_stone(x, y).is_some() {
+            return false;
+        }
+
+        let neighbors = board_state.get_neighbors(x, y);
+        if neighbors
+            .iter()
+            .any(|&(nx, ny)| board_state.get_stone(nx, ny) != Some(color))
+        {
+            return false;
+        }
+
+        let size = board_state.board_size.get_value();
+        let mut on_board_diagonals = 0;
+        let mut opponent_diagonals = 0;
+        for (dx, dy) in [(1, 1), (1, -1), (-1, 1), (-1, -1)] {
+            let (cx, cy) = (x + dx, y + dy);
+            if (0..size).contains(&cx) && (0..size).contains(&cy) {
+                on_board_diagonals += 1;
+                if board_state.get_stone(cx, cy) == Some(color.opposite()) {
+                    opponent_diagonals += 1;
+                }
+            }
+        }
+
+        if on_board_diagonals < 4 {
+            opponent_diagonals == 0
+        } else {
+            opponent_diagonals <= 1
+        }
+    }
+
+    /// 获取一个棋串（相连的同色棋子）
+    pub fn get_group(board_state: &BoardState, x: i32, y: i32) -> HashSet<(i32, i32)> {
+        let mut group = HashSet::new();
+
+        if let Some(color) = board_state.get_stone(x, y) {
+            let mut stack = vec![(x, y)];
+            group.insert((x, y));
+
+            while let Some((cx, cy)) = stack.pop() {
+                for (nx, ny) in board_state.get_neighbors(cx, cy) {
+                    if !group.contains(&(nx, ny)) && board_state.get_stone(nx, ny) == Some(color) {
+                        group.insert((nx, ny));
+                        stack.push((nx, ny));
+                    }
+                }
+            }
+        }
+
+        group
+    }
+
+    /// 计算一个棋串的气数
+    pub fn count_liberties(board_state: &BoardState, group: &HashSet<(i32, i32)>) -> usize {
+        let mut liberties = HashSet::new();
+
+        for &(x, y) in group {
+            for (nx, ny) in board_state.get_neighbors(x, y) {
+                if board_state.get_stone(nx, ny).is_none() {
+                    liberties.insert((nx, ny));
+                }
+            }
+        }
+
+        liberties.len()
+    }
+
+    /// 检查并移除被提的棋子
+    pub fn capture_stones(
+        board_state: &mut BoardState,
+        x: i32,
+        y: i32,
+        color: StoneColor,
+    ) -> Vec<(i32, i32)> {
+        let mut captured = Vec::new();
+        let opponent = color.opposite();
+
+        // 检查相邻的对手棋子
+        for (nx, ny) in board_state.get_neighbors(x, y) {
+            if board_state.get_stone(nx, ny) == Some(opponent) {
+                let group = Self::get_group(board_state, nx, ny);
+                if Self::count_liberties(board_state, &group) == 0 {
+                    // 提子
+                    for &(gx, gy) in &group {
+                        board_state.remove_stone(gx, gy);
+                        captured.push((gx, gy));
+                    }
+                }
+            }
+        }
+
+        // 更新提子计数
+        match color {
+            StoneColor::Black => board_state.captured_white += captured.len(),
+            StoneColor::White => board_state.captured_black += captured.len(),
+        }
+
+        captured
+    }
+
+    /// 检查自杀规则
+    pub fn is_suicide(board_state: &BoardState, x: i32, y: i32, color: StoneColor) -> bool {
+        // 创建临时棋盘状态
+        let mut temp_board = board_state.clone();
+        temp_board.stones[x as usize][y as usize] = Some(color);
+
+        // 首先检查是否能提对手的子
+        for (nx, ny) in temp_board.get_neighbors(x, y) {
+            if temp_board.get_stone(nx, ny) == Some(color.opposite()) {
+                let group = Self::get_group(&temp_board, nx, ny);
+                if Self::count_liberties(&temp_board, &group) == 0 {
+                    return false; // 能提对手的子，不是自杀
+                }
+            }
+        }
+
+        // 检查自己这一串是否有气
+        let own_group = Self::get_group(&temp_board, x, y);
+        Self::count_liberties(&temp_board, &own_group) == 0
+    }
+}
@@ -0,0 +1,113 @@
+use super::board::{BoardState, StoneColor};
+use super::rules::GoBoardRules;
+use std::collections::HashSet;
+
+/// 默认贴目（中国规则）
+pub const DEFAULT_KOMI: f32 = 7.5;
+
+/// 一块相连的空点区域
+#[derive(Debug, Clone)]
+pub struct EmptyRegion {
+    pub points: HashSet<(i32, i32)>,
+    /// 只与一种颜色相邻时归该方，否则为公共区域
+    pub owner: Option<StoneColor>,
+}
+
+impl GoBoardRules {
+    /// 计算终局分数（中国规则）
+    pub fn calculate_score(board_state: &BoardState) -> (f32, f32) {
+        Self::area_score(board_state, DEFAULT_KOMI)
+    }
+
+    /// 数子法计分：棋子数加上只被一方包围的空点，白方加上贴目
+    ///
+    /// 双活的公气与双方都相邻，自然不算任何一方；双活棋块的眼按中国规则算作该方的地
+    pub fn area_score(board_state: &BoardState, komi: f32) -> (f32, f32) {
+        let mut black_score = 0.0;
+        let mut white_score = komi;
+
+        // 计算棋子数
+        let size = board_state.board_size.get_value();
+        for x in 0..size {
+            for y in 0..size {
+                match board_state.get_stone(x, y) {
+                    Some(StoneColor::Black) => black_score += 1.0,
+                    Some(StoneColor::White) => white_score += 1.0,
+                    None => {}
+                }
+            }
+        }
+
+        // 计算领地归属
+        for region in Self::empty_regions(board_state) {
+            match region.owner {
+                Some(StoneColor::Black) => black_score += region.points.len() as f32,
+                Some(StoneColor::White) => white_score += region.points.len() as f32,
+                None => {}
+            }
+        }
+
+        (black_score, white_score)
+    }
+
+    /// 提掉 `dead` 中的死子后按数子法计分，死子所在的点归围住它的一方
+    pub fn area_score_with_dead(
+        board_state: &BoardState,
+        komi: f32,
+        dead: &HashSet<(i32, i32)>,
+    ) -> (f32, f32) {
+        let mut cleaned = board_state.clone();
+        for &(x, y) in dead {
+            cleaned.remove_stone(x, y);
+        }
+        Self::area_score(&cleaned, komi)
+    }
+
+    /// 所有相连的空点区域及其归属
+    pub fn empty_regions(board_state: &BoardState) -> Vec<EmptyRegion> {
+        let size = board_state.board_size.get_value();
+        let mut visited = HashSet::new();
+        let mut regions = Vec::new();
+
+        for x in 0..size {
+            for y in 0..size {
+                if board_state.get_stone(x, y).is_some() || visited.contains(&(x, y)) {
+                    continue;
+                }
+
+                let mut region = HashSet::new();
+                let mut borders_black = false;
+                let mut borders_white = false;
+                let mut stack = vec![(x, y)];
+                visited.insert((x, y));
+
+                while let Some((cx, cy)) = stack.pop() {
+                    region.insert((cx, cy));
+                    for (nx, ny) in board_state.get_neighbors(cx, cy) {
+                        match board_state.get_stone(nx, ny) {
+                            Some(StoneColor::Black) => borders_black = true,
+                            Some(StoneColor::White) => borders_white = true,
+                            None => {
+                                if visited.insert((nx, ny)) {
+                                    stack.push((nx, ny));
+                                }
+                            }
+                        }
+                    }
+                }
+
+                let owner = match (borders_black, borders_white) {
+                    (true, false) => Some(StoneColor::Black),
+                    (false, true) => Some(StoneColor::White),
+                    _ => None,
+                };
+                regions.push(EmptyRegion {
+                    points: region,
+                    owner,
+                });
+            }
+        }
+
+        regions
+    }
+}
@@ -0,0 +1,197 @@
+use super::board::{BoardSize, BoardState, GameHistory, StoneColor};
+use super::bot::{GeneratedMove, MoveGenerator};
+use super::features::{FeaturePlanes, HISTORY_LENGTH};
+use super::rules::GoBoardRules;
+use super::scoring::DEFAULT_KOMI;
+use super::sgf::SgfRecord;
+use super::training::TrainingSamples;
+
+// 电脑棋手之间的自对弈，直接在规则引擎上进行，不经过 Bevy
+// 对局可以导出为 SGF 和训练样本
+
+/// 自对弈规则
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelfPlayConfig {
+    pub board_size: BoardSize,
+    pub komi: f32,
+    /// 超过此手数时直接数子结束，避免双方都不肯虚手
+    pub max_moves: usize,
+    /// 全局同形禁着，重复本局局面的着法按虚手处理
+    pub enable_superko_rule: bool,
+}
+
+impl Default for SelfPlayConfig {
+    fn default() -> Self {
+        Self {
+            board_size: BoardSize::Nine,
+            komi: DEFAULT_KOMI,
+            max_moves: 9 * 9 * 3,
+            enable_superko_rule: false,
+        }
+    }
+}
+
+/// 对局结果
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GameResult {
+    /// `None` 为和棋
+    pub winner: Option<StoneColor>,
+    /// 数子胜负的目数，认输时为 `None`
+    pub margin: Option<f32>,
+}
+
+impl GameResult {
+    /// SGF `RE` 属性，如 `B+3.5`、`W+R`、`0`
+    pub fn to_sgf(&self) -> String {
+        let Some(winner) = self.winner else {
+            return "0".to_string();
+        };
+        let color = match winner {
+            StoneColor::Black => 'B',
+            StoneColor::White => 'W',
+        };
+        match self.margin {
+            Some(margin) => format!("{}+{}", color, margin),
+            None => format!("{}+R", color),
+        }
+    }
+
+    /// `color` 方视角的结果：胜 1、负 -1、和 0
+    pub fn value_for(&self, color: StoneColor) -> i8 {
+        match self.winner {
+            Some(winner) if winner == color => 1,
+            Some(_) => -1,
+            None => 0,
+        }
+    }
+}
+
+/// 一局自对弈
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelfPlayGame {
+    pub config: SelfPlayConfig,
+    pub black_player: String,
+    pub white_player: String,
+    /// 按顺序排列的着法，`None` 为虚手，不含认输
+    pub moves: Vec<(StoneColor, Option<(i32, i32)>)>,
+    pub result: GameResult,
+}
+
+impl SelfPlayGame {
+    /// 下完一局：双方连续虚手、一方认输或达到手数上限时结束
+    ///
+    /// 棋手给出非法着法时按虚手处理
+    pub fn play(
+        black: &mut dyn MoveGenerator,
+        white: &mut dyn MoveGenerator,
+        config: &SelfPlayConfig,
+    ) -> Self {
+        let mut board_state = BoardState::new(config.board_size);
+        let mut history = GameHistory::default();
+        let mut color = StoneColor::Black;
+        let mut moves = Vec::new();
+        let mut resigned = None;
+
+        while board_state.consecutive_passes < 2 && moves.len() < config.max_moves {
+            let generator: &mut dyn MoveGenerator = match color {
+                StoneColor::Black => &mut *black,
+                StoneColor::White => &mut *white,
+            };
+            let played = match generator.generate_move(&board_state, &history, color, config.komi) {
+                GeneratedMove::Play(x, y) => {
+                    let mut next = board_state.clone();
+                    let played =
+                        GoBoardRules::play_move(&mut next, x, y, color).and_then(|captured| {
+                            if config.enable_superko_rule {
+                                GoBoardRules::check_superko(&next, &history)?;
+                            }
+                            Ok(captured)
+                        });
+                    played.ok().map(|captured| {
+                        board_state = next;
+                        ((x, y), captured)
+                    })
+                }
+                GeneratedMove::Resign => {
+                    resigned = Some(color);
+                    break;
+                }
+                GeneratedMove::Pass => None,
+            };
+            match played {
+                Some((position, captured)) => {
+                    history.record_after(&board_state, Some(position), color, captured);
+                    moves.push((color, Some(position)));
+                }
+                None => {
+                    GoBoardRules::pass(&mut board_state);
+                    history.record_after(&board_state, None, color, Vec::new());
+                    moves.push((color, None));
+                }
+            }
+            color = color.opposite();
+        }
+
+        let result = match resigned {
+            Some(loser) => GameResult {
+                winner: Some(loser.opposite()),
+                margin: None,
+            },
+            None => {
+                let (black_score, white_score) =
+                    GoBoardRules::area_score(&board_state, config.komi);
+                let winner = if black_score > white_score {
+                    Some(StoneColor::Black)
+                } else if white_score > black_score {
+                    Some(StoneColor::White)
+                } else {
+                    None
+                };
+                GameResult {
+                    winner,
+                    margin: Some((black_score - white_score).abs()),
+                }
+            }
+        };
+
+        Self {
+            config: config.clone(),
+            black_player: black.name().to_string(),
+            white_player: white.name().to_string(),
+            moves,
+            result,
+        }
+    }
+
+    /// 转换为 SGF 记录
+    pub fn to_sgf_record(&self) -> SgfRecord {
+        let mut record = SgfRecord::new(self.config.board_size, self.config.komi);
+        record.black_player = self.black_player.clone();
+        record.white_player = self.white_player.clone();
+        record.result = Some(self.result.to_sgf());
+        record.moves = self.moves.clone();
+        record
+    }
+
+    /// 把每一手之前的局面、实际下的点和最终结果加入样本
+    pub fn export_samples(&self, samples: &mut TrainingSamples) {
+        let mut positions = vec![BoardState::new(self.config.board_size)];
+        for &(color, played) in &self.moves {
+            let planes = FeaturePlanes::from_positions(&positions, color);
+            samples.push(&planes, played, self.result.value_for(color));
+
+            let mut board_state = positions[positions.len() - 1].clone();
+            match played {
+                Some((x, y)) => {
+                    // 对局中已经验证过，这里不会失败
+                    let _ = GoBoardRules::play_move(&mut board_state, x, y, color);
+                }
+                None => GoBoardRules::pass(&mut board_state),
+            }
+            if positions.len() == HISTORY_LENGTH {
+                positions.remove(0);
+            }
+            positions.push(board_state);
+        }
+    }
+}
@@ -0,0 +1,406 @@
+use super::board::{BoardState, StoneColor};
+use super::rules::GoBoardRules;
+use std::collections::{HashMap, HashSet};
+
+// 对杀和双活：把两块相邻的对方棋串的气分成外气、公气和眼内气，
+// 再按气数做一个小的博弈搜索，判断谁吃谁、谁需要先走，或者是双活
+
+/// 超过这个大小的眼位视为能做出两只眼
+pub(crate) const MAX_EYE_SIZE: usize = 6;
+
+/// 对杀中一方棋串的气
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RaceLiberties {
+    /// 棋串的棋子数
+    pub stones: usize,
+    /// 只属于这一方的外气
+    pub outside: usize,
+    /// 眼内的气，按眼位大小折算（1、2、3、5、8、12）
+    pub eye: usize,
+    /// 眼的个数
+    pub eyes: usize,
+    /// 有两只眼或足够大的眼位，不会被吃
+    pub alive: bool,
+}
+
+/// 对杀的结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RaceOutcome {
+    /// 该方吃掉对方
+    Wins(StoneColor),
+    /// 双方都不会被吃（双活，或双方本来就活）
+    Seki,
+}
+
+/// 两块相邻的对方棋串的对杀分析
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SemeaiAnalysis {
+    pub black: RaceLiberties,
+    pub white: RaceLiberties,
+    /// 双方共有的气
+    pub shared: usize,
+    /// 黑先时的结果
+    pub black_first: RaceOutcome,
+    /// 白先时的结果
+    pub white_first: RaceOutcome,
+}
+
+impl SemeaiAnalysis {
+    /// 某一方先走时的结果
+    pub fn outcome(&self, to_play: StoneColor) -> RaceOutcome {
+        match to_play {
+            StoneColor::Black => self.black_first,
+            StoneColor::White => self.white_first,
+        }
+    }
+
+    /// 无论谁先走都能吃掉对方的一方
+    pub fn winner(&self) -> Option<StoneColor> {
+        match (self.black_first, self.white_first) {
+            (RaceOutcome::Wins(first), RaceOutcome::Wins(second)) if first == second => Some(first),
+            _ => None,
+        }
+    }
+
+    /// 无论谁先走都是双活
+    pub fn is_seki(&self) -> bool {
+        self.black_first == RaceOutcome::Seki && self.white_first == RaceOutcome::Seki
+    }
+
+    /// 这一方先走的结果比对方先走好，需要抢先
+    pub fn needs_first_move(&self, color: StoneColor) -> bool {
+        rank(self.outcome(color), color) > rank(self.outcome(color.opposite()), color)
+    }
+}
+
+/// 结果对某一方的好坏：赢 2、双活 1、输 0
+fn rank(outcome: RaceOutcome, color: StoneColor) -> u8 {
+    match outcome {
+        RaceOutcome::Wins(winner) if winner == color => 2,
+        RaceOutcome::Seki => 1,
+        RaceOutcome::Wins(_) => 0,
+    }
+}
+
+impl GoBoardRules {
+    /// 分析 `a` 和 `b` 所在的两块棋的对杀
+    ///
+    /// 两点必须是颜色不同、相邻或有公气的棋串，否则返回 `None`。
+    /// 眼位按已被点到要点的形状折算气数
+    pub fn analyze_semeai(
+        board_state: &BoardState,
+        a: (i32, i32),
+        b: (i32, i32),
+    ) -> Option<SemeaiAnalysis> {
+        let color_a = board_state.get_stone(a.0, a.1)?;
+        let color_b = board_state.get_stone(b.0, b.1)?;
+        if color_a == color_b {
+            return None;
+        }
+
+        let chain_a = Self::get_group(board_state, a.0, a.1);
+        let chain_b = Self::get_group(board_state, b.0, b.1);
+        let liberties_a = chain_liberties(board_state, &chain_a);
+        let liberties_b = chain_liberties(board_state, &chain_b);
+        let shared: HashSet<(i32, i32)> = liberties_a.intersection(&liberties_b).copied().collect();
+        let touching = chain_a.iter().any(|&(x, y)| {
+            board_state
+                .get_neighbors(x, y)
+                .iter()
+                .any(|point| chain_b.contains(point))
+        });
+        if shared.is_empty() && !touching {
+            return None;
+        }
+
+        let race_a = race_liberties(board_state, &chain_a, &liberties_a, &shared, color_a);
+        let race_b = race_liberties(board_state, &chain_b, &liberties_b, &shared, color_b);
+        let (black, white) = if color_a == StoneColor::Black {
+            (race_a, race_b)
+        } else {
+            (race_b, race_a)
+        };
+
+        let mut race = Race {
+            sides: [side(&black), side(&white)],
+            memo: HashMap::new(),
+        };
+        let start = Counts {
+            outside: [black.outside, white.outside],
+            eye: [black.eye, white.eye],
+            shared: shared.len(),
+        };
+        let black_first = race.solve(start, 0, false);
+        let white_first = race.solve(start, 1, false);
+
+        Some(SemeaiAnalysis {
+            black,
+            white,
+            shared: shared.len(),
+            black_first,
+            white_first,
+        })
+    }
+
+    /// 局面中所有双活的公气
+    ///
+    /// 这些点谁填谁被吃，数子时不算任何一方，电脑棋手也不去填
+    pub fn seki_liberties(board_state: &BoardState) -> HashSet<(i32, i32)> {
+        let size = board_state.board_size.get_value();
+        let mut chains: Vec<(StoneColor, HashSet<(i32, i32)>)> = Vec::new();
+        let mut seen = HashSet::new();
+        for x in 0..size {
+            for y in 0..size {
+                if let Some(color) = board_state.get_stone(x, y)
+                    && !seen.contains(&(x, y))
+                {
+                    let chain = Self::get_group(board_state, x, y);
+                    seen.extend(chain.iter().copied());
+                    chains.push((color, chain));
+                }
+            }
+        }
+
+        let liberties: Vec<HashSet<(i32, i32)>> = chains
+            .iter()
+            .map(|(_, chain)| chain_liberties(board_state, chain))
+            .collect();
+
+        // 按气点索引白棋棋串，只分析确实有公气的黑白两块棋
+        let mut white_chains: HashMap<(i32, i32), Vec<usize>> = HashMap::new();
+        for (j, (color, _)) in chains.iter().enumerate() {
+            if *color == StoneColor::White {
+                for &point in &liberties[j] {
+                    white_chains.entry(point).or_default().push(j);
+                }
+            }
+        }
+
+        let mut seki = HashSet::new();
+        for (i, (color, chain)) in chains.iter().enumerate() {
+            if *color != StoneColor::Black {
+                continue;
+            }
+            let mut rivals: Vec<usize> = liberties[i]
+                .iter()
+                .filter_map(|point| white_chains.get(point))
+                .flatten()
+                .copied()
+                .collect();
+            rivals.sort_unstable();
+            rivals.dedup();
+            for j in rivals {
+                let shared: Vec<(i32, i32)> =
+                    liberties[i].intersection(&liberties[j]).copied().collect();
+                let a = *chain.iter().next().expect("chains are not empty");
+                let b = *chains[j].1.iter().next().expect("chains are not empty");
+                if Self::analyze_semeai(board_state, a, b).is_some_and(|race| race.is_seki()) {
+                    seki.extend(shared);
+                }
+            }
+        }
+        seki
+    }
+}
+
+/// 棋串的气
+fn chain_liberties(board_state: &BoardState, chain: &HashSet<(i32, i32)>) -> HashSet<(i32, i32)> {
+    chain
+        .iter()
+        .flat_map(|&(x, y)| board_state.get_neighbors(x, y))
+        .filter(|&(x, y)| board_state.get_stone(x, y).is_none())
+        .collect()
+}
+
+/// 把棋串的气分成外气和眼内气，公气另算
+fn race_liberties(
+    board_state: &BoardState,
+    chain: &HashSet<(i32, i32)>,
+    liberties: &HashSet<(i32, i32)>,
+    shared: &HashSet<(i32, i32)>,
+    color: StoneColor,
+) -> RaceLiberties {
+    let mut eye_points = HashSet::new();
+    let mut explored = HashSet::new();
+    let mut eye = 0;
+    let mut eyes = 0;
+    let mut alive = false;
+
+    for &start in liberties {
+        if explored.contains(&start) || shared.contains(&start) {
+            continue;
+        }
+
+        // 与气相连的空点区域，只被己方棋子包围时是眼
+        let mut region = HashSet::from([start]);
+        let mut stack = vec![start];
+        let mut enclosed = true;
+        while let Some((x, y)) = stack.pop() {
+            for (nx, ny) in board_state.get_neighbors(x, y) {
+                match board_state.get_stone(nx, ny) {
+                    None => {
+                        if region.insert((nx, ny)) {
+                            stack.push((nx, ny));
+                        }
+                    }
+                    Some(stone) if stone != color => enclosed = false,
+                    Some(_) => {}
+                }
+            }
+        }
+        explored.extend(region.iter().copied());
+        if !enclosed {
+            continue;
+        }
+
+        if region.len() > MAX_EYE_SIZE {
+            alive = true;
+        } else {
+            eye += eye_value(region.len());
+            eyes += 1;
+        }
+        eye_points.extend(region);
+    }
+
+    let outside = liberties
+        .iter()
+        .filter(|point| !shared.contains(point) && !eye_points.contains(point))
+        .count();
+
+    RaceLiberties {
+        stones: chain.len(),
+        outside,
+        eye,
+        eyes,
+        alive: alive || eyes >= 2,
+    }
+}
+
+/// 眼位大小折算的气数
+fn eye_value(size: usize) -> usize {
+    match size {
+        0..=3 => size,
+        4 => 5,
+        5 => 8,
+        _ => 12,
+    }
+}
+
+/// 一方在对杀搜索中的固定信息
+#[derive(Clone, Copy)]
+struct Side {
+    /// 有眼时最后一口眼内气只能作为提子的一手来填
+    has_eye: bool,
+    alive: bool,
+}
+
+fn side(liberties: &RaceLiberties) -> Side {
+    Side {
+        has_eye: liberties.eyes > 0,
+        alive: liberties.alive,
+    }
+}
+
+/// 对杀搜索的局面，下标 0 为黑、1 为白
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Counts {
+    outside: [usize; 2],
+    eye: [usize; 2],
+    shared: usize,
+}
+
+impl Counts {
+    fn total(&self, index: usize) -> usize {
+        self.outside[index] + self.eye[index] + self.shared
+    }
+}
+
+/// 只看气数的对杀博弈
+struct Race {
+    sides: [Side; 2],
+    memo: HashMap<(Counts, usize, bool), RaceOutcome>,
+}
+
+impl Race {
+    fn color(index: usize) -> StoneColor {
+        if index == 0 {
+            StoneColor::Black
+        } else {
+            StoneColor::White
+        }
+    }
+
+    fn captured(&self, counts: &Counts, index: usize) -> bool {
+        !self.sides[index].alive && counts.total(index) == 0
+    }
+
+    /// `mover` 先走的结果，`passed` 表示对方刚虚手
+    fn solve(&mut self, counts: Counts, mover: usize, passed: bool) -> RaceOutcome {
+        if let Some(&outcome) = self.memo.get(&(counts, mover, passed)) {
+            return outcome;
+        }
+
+        let opponent = 1 - mover;
+        let mut moves = Vec::new();
+
+        // 紧对方的外气
+        if counts.outside[opponent] > 0 {
+            let mut next = counts;
+            next.outside[opponent] -= 1;
+            moves.push(next);
+        }
+        // 紧公气，自己也少一口气，不能自杀
+        if counts.shared > 0 {
+            let mut next = counts;
+            next.shared -= 1;
+            if self.captured(&next, opponent) || self.sides[mover].alive || next.total(mover) > 0 {
+                moves.push(next);
+            }
+        }
+        // 填对方的眼，最后一口眼内气只有提子时才能填
+        if counts.eye[opponent] > 0 && !self.sides[opponent].alive {
+            let mut next = counts;
+            next.eye[opponent] -= 1;
+            let last_eye_point = self.sides[opponent].has_eye && next.eye[opponent] == 0;
+            if !last_eye_point || self.captured(&next, opponent) {
+                moves.push(next);
+            }
+        }
+
+        let mut best: Option<RaceOutcome> = None;
+        for next in moves {
+            let outcome = if self.captured(&next, opponent) {
+                RaceOutcome::Wins(Self::color(mover))
+            } else {
+                self.solve(next, opponent, false)
+            };
+            if improves(best, outcome, Self::color(mover)) {
+                best = Some(outcome);
+            }
+            if outcome == RaceOutcome::Wins(Self::color(mover)) {
+                break;
+            }
+        }
+
+        // 虚手，双方连续虚手时双方都活
+        if best != Some(RaceOutcome::Wins(Self::color(mover))) {
+            let outcome = if passed {
+                RaceOutcome::Seki
+            } else {
+                self.solve(counts, opponent, true)
+            };
+            if improves(best, outcome, Self::color(mover)) {
+                best = Some(outcome);
+            }
+        }
+
+        let outcome = best.unwrap_or(RaceOutcome::Seki);
+        self.memo.insert((counts, mover, passed), outcome);
+        outcome
+    }
+}
+
+/// `outcome` 对 `color` 是否比当前最好的结果更好
+fn improves(best: Option<RaceOutcome>, outcome: RaceOutcome, color: StoneColor) -> bool {
+    best.map_or(true, |best| rank(outcome, color) > rank(best, color))
+}
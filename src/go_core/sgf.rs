@@ -0,0 +1,90 @@
+use super::board::{BoardSize, StoneColor};
+use super::coordinates::BoardCoordinates;
+
+// SGF（FF[4]）棋谱写出，只包含主线，不含变化和注释
+
+/// 一局棋的 SGF 记录
+///
+/// # 使用示例
+/// ```rust,ignore
+/// let mut record = SgfRecord::new(BoardSize::Nine, DEFAULT_KOMI);
+/// record.push(StoneColor::Black, Some((4, 4)));
+/// record.push(StoneColor::White, None);
+/// record.result = Some("B+R".to_string());
+/// std::fs::write("game.sgf", record.to_sgf())?;
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SgfRecord {
+    pub board_size: BoardSize,
+    pub komi: f32,
+    pub black_player: String,
+    pub white_player: String,
+    /// SGF `RE` 属性，如 `B+3.5`、`W+R`、`0`（和棋）
+    pub result: Option<String>,
+    /// 按顺序排列的着法，`None` 为虚手
+    pub moves: Vec<(StoneColor, Option<(i32, i32)>)>,
+}
+
+impl SgfRecord {
+    pub fn new(board_size: BoardSize, komi: f32) -> Self {
+        Self {
+            board_size,
+            komi,
+            black_player: String::new(),
+            white_player: String::new(),
+            result: None,
+            moves: Vec::new(),
+        }
+    }
+
+    /// 追加一手棋
+    pub fn push(&mut self, color: StoneColor, position: Option<(i32, i32)>) {
+        self.moves.push((color, position));
+    }
+
+    /// 生成 SGF 文本，每行最多 10 手
+    pub fn to_sgf(&self) -> String {
+        let mut sgf = format!(
+            "(;GM[1]FF[4]CA[UTF-8]AP[black-white-legends]RU[Chinese]SZ[{}]KM[{}]",
+            self.board_size.get_value(),
+            self.komi
+        );
+        if !self.black_player.is_empty() {
+            sgf.push_str(&format!("PB[{}]", escape_text(&self.black_player)));
+        }
+        if !self.white_player.is_empty() {
+            sgf.push_str(&format!("PW[{}]", escape_text(&self.white_player)));
+        }
+        if let Some(result) = &self.result {
+            sgf.push_str(&format!("RE[{}]", escape_text(result)));
+        }
+
+        for (index, &(color, position)) in self.moves.iter().enumerate() {
+            if index % 10 == 0 {
+                sgf.push('\n');
+            }
+            let property = match color {
+                StoneColor::Black => 'B',
+                StoneColor::White => 'W',
+            };
+            let point = position
+                .map(|(x, y)| BoardCoordinates::board_to_sgf(x, y))
+                .unwrap_or_default();
+            sgf.push_str(&format!(";{}[{}]", property, point));
+        }
+        sgf.push_str(")\n");
+        sgf
+    }
+}
+
+/// 转义 SGF 文本中的 `]` 和 `\`
+fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if c == ']' || c == '\\' {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
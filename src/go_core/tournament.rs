@@ -0,0 +1,179 @@
+use super::board::StoneColor;
+
+// 电脑棋手之间的比赛：对阵编排、交叉表和 Elo 估计
+// 不依赖 Bevy，实际对局由 `go_board_component::tournament` 或其他驱动进行
+
+/// 比赛赛制
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TournamentFormat {
+    /// 每两个参赛者之间都对局
+    RoundRobin,
+    /// 第一个参赛者与其余每个参赛者对局
+    Gauntlet,
+}
+
+impl TournamentFormat {
+    /// 名称，用于命令行
+    pub fn name(&self) -> &'static str {
+        match self {
+            TournamentFormat::RoundRobin => "round-robin",
+            TournamentFormat::Gauntlet => "gauntlet",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        [TournamentFormat::RoundRobin, TournamentFormat::Gauntlet]
+            .into_iter()
+            .find(|format| format.name() == name)
+    }
+
+    /// 全部对局，参赛者按下标表示，`(黑, 白)`
+    ///
+    /// 每个对阵下 `games_per_pairing` 局，双方轮流执黑
+    pub fn pairings(&self, entrants: usize, games_per_pairing: usize) -> Vec<(usize, usize)> {
+        let opponents: Vec<(usize, usize)> = match self {
+            TournamentFormat::RoundRobin => (0..entrants)
+                .flat_map(|a| (a + 1..entrants).map(move |b| (a, b)))
+                .collect(),
+            TournamentFormat::Gauntlet => (1..entrants).map(|b| (0, b)).collect(),
+        };
+
+        // 按轮次排列，同一对阵的对局分散开
+        let mut pairings = Vec::with_capacity(opponents.len() * games_per_pairing);
+        for game in 0..games_per_pairing {
+            for &(a, b) in &opponents {
+                pairings.push(if game % 2 == 0 { (a, b) } else { (b, a) });
+            }
+        }
+        pairings
+    }
+}
+
+/// 交叉表：每对参赛者之间的得分，胜 1 分、和 0.5 分
+#[derive(Debug, Clone, PartialEq)]
+pub struct Crosstable {
+    pub names: Vec<String>,
+    /// `points[a][b]` 为 a 对 b 的得分
+    points: Vec<Vec<f32>>,
+    /// `games[a][b]` 为 a 与 b 的对局数
+    games: Vec<Vec<u32>>,
+}
+
+impl Crosstable {
+    pub fn new(names: Vec<String>) -> Self {
+        let count = names.len();
+        Self {
+            names,
+            points: vec![vec![0.0; count]; count],
+            games: vec![vec![0; count]; count],
+        }
+    }
+
+    /// 记录一局的结果，`winner` 为 `None` 表示和棋
+    pub fn record(&mut self, black: usize, white: usize, winner: Option<StoneColor>) {
+        let black_points = match winner {
+            Some(StoneColor::Black) => 1.0,
+            Some(StoneColor::White) => 0.0,
+            None => 0.5,
+        };
+        self.points[black][white] += black_points;
+        self.points[white][black] += 1.0 - black_points;
+        self.games[black][white] += 1;
+        self.games[white][black] += 1;
+    }
+
+    /// a 对 b 的得分和对局数
+    pub fn head_to_head(&self, a: usize, b: usize) -> (f32, u32) {
+        (self.points[a][b], self.games[a][b])
+    }
+
+    /// 总得分率，没有对局时为 `None`
+    pub fn score_rate(&self, player: usize) -> Option<f32> {
+        let games: u32 = self.games[player].iter().sum();
+        let points: f32 = self.points[player].iter().sum();
+        (games > 0).then(|| points / games as f32)
+    }
+
+    /// Elo 估计（Bradley-Terry 最大似然），平均为 0
+    ///
+    /// 每个对阵额外加一局虚拟和棋，全胜或全负时也能得到有限的值
+    pub fn elo(&self) -> Vec<f32> {
+        let count = self.names.len();
+        let points = |a: usize, b: usize| {
+            if self.games[a][b] > 0 {
+                self.points[a][b] + 0.5
+            } else {
+                0.0
+            }
+        };
+        let games = |a: usize, b: usize| {
+            if self.games[a][b] > 0 {
+                self.games[a][b] as f32 + 1.0
+            } else {
+                0.0
+            }
+        };
+
+        let mut strength = vec![1.0f64; count];
+        for _ in 0..1000 {
+            let mut next = strength.clone();
+            for a in 0..count {
+                let won: f32 = (0..count).map(|b| points(a, b)).sum();
+                let expected: f64 = (0..count)
+                    .map(|b| games(a, b) as f64 / (strength[a] + strength[b]))
+                    .sum();
+                if expected > 0.0 {
+                    next[a] = won as f64 / expected;
+                }
+            }
+            let converged = next
+                .iter()
+                .zip(&strength)
+                .all(|(new, old)| (new / old).ln().abs() < 1e-9);
+            strength = next;
+            if converged {
+                break;
+            }
+        }
+
+        let ratings: Vec<f64> = strength.iter().map(|gamma| 400.0 * gamma.log10()).collect();
+        let mean = ratings.iter().sum::<f64>() / count.max(1) as f64;
+        ratings
+            .iter()
+            .map(|rating| (rating - mean) as f32)
+            .collect()
+    }
+
+    /// 按 Elo 排序的文本表格
+    ///
+    /// 每行列出参赛者对其他人的得分（如 `3.5/4`）、总得分率和 Elo
+    pub fn to_table(&self) -> String {
+        let elo = self.elo();
+        let mut order: Vec<usize> = (0..self.names.len()).collect();
+        order.sort_by(|&a, &b| elo[b].total_cmp(&elo[a]));
+
+        let name_width = self.names.iter().map(String::len).max().unwrap_or(0).max(4);
+        let mut table = format!("{:>3}  {:<name_width$}", "#", "name");
+        for rank in 1..=order.len() {
+            table.push_str(&format!(" {:>8}", rank));
+        }
+        table.push_str(&format!(" {:>7} {:>7}\n", "score", "elo"));
+
+        for (rank, &a) in order.iter().enumerate() {
+            table.push_str(&format!("{:>3}  {:<name_width$}", rank + 1, self.names[a]));
+            for &b in &order {
+                let cell = match self.head_to_head(a, b) {
+                    (_, 0) => "-".to_string(),
+                    (points, games) => format!("{}/{}", points, games),
+                };
+                table.push_str(&format!(" {:>8}", cell));
+            }
+            let score = self
+                .score_rate(a)
+                .map(|rate| format!("{:.1}%", rate * 100.0))
+                .unwrap_or_else(|| "-".to_string());
+            table.push_str(&format!(" {:>7} {:>+7.0}\n", score, elo[a]));
+        }
+        table
+    }
+}
@@ -0,0 +1,196 @@
+use super::board::BoardSize;
+use super::features::{FEATURE_PLANES, FeaturePlanes};
+use std::io::{self, Write};
+
+// 训练样本（特征平面、策略目标、对局结果）和 NPZ 导出
+// NPZ 是不压缩的 zip，里面每个数组一个 .npy 文件，可以直接用 `numpy.load` 读取
+
+/// 一批同一棋盘大小的训练样本
+///
+/// 导出的数组：
+/// - `features.npy`：`uint8`，形状 `[N, 17, size, size]`，见 [`FeaturePlanes`]
+/// - `policy.npy`：`uint16`，形状 `[N]`，实际下的点 `y * size + x`，虚手为 `size * size`
+/// - `value.npy`：`int8`，形状 `[N]`，轮到方最终胜为 1、负为 -1、和棋为 0
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrainingSamples {
+    pub board_size: BoardSize,
+    features: Vec<u8>,
+    policy: Vec<u16>,
+    value: Vec<i8>,
+}
+
+impl TrainingSamples {
+    pub fn new(board_size: BoardSize) -> Self {
+        Self {
+            board_size,
+            features: Vec::new(),
+            policy: Vec::new(),
+            value: Vec::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.policy.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.policy.is_empty()
+    }
+
+    /// 添加一个样本，`played` 为 `None` 表示虚手
+    pub fn push(&mut self, planes: &FeaturePlanes, played: Option<(i32, i32)>, value: i8) {
+        assert_eq!(
+            planes.board_size, self.board_size,
+            "sample board size mismatch"
+        );
+        let size = self.board_size.get_value();
+        let policy = match played {
+            Some((x, y)) => y * size + x,
+            None => size * size,
+        };
+        self.features
+            .extend(planes.data.iter().map(|&value| value as u8));
+        self.policy.push(policy as u16);
+        self.value.push(value);
+    }
+
+    /// 某个样本的策略目标和结果
+    pub fn target(&self, index: usize) -> (u16, i8) {
+        (self.policy[index], self.value[index])
+    }
+
+    /// 写出 NPZ 文件
+    pub fn write_npz(&self, writer: impl Write) -> io::Result<()> {
+        let size = self.board_size.get_value() as usize;
+        let count = self.len();
+        let features = npy_array("|u1", &[count, FEATURE_PLANES, size, size], &self.features);
+        let policy_bytes: Vec<u8> = self.policy.iter().flat_map(|p| p.to_le_bytes()).collect();
+        let policy = npy_array("<u2", &[count], &policy_bytes);
+        let value_bytes: Vec<u8> = self.value.iter().map(|&v| v as u8).collect();
+        let value = npy_array("|i1", &[count], &value_bytes);
+
+        write_stored_zip(
+            writer,
+            &[
+                ("features.npy", &features),
+                ("policy.npy", &policy),
+                ("value.npy", &value),
+            ],
+        )
+    }
+}
+
+/// 按 NPY 1.0 格式编码一个 C 顺序数组
+fn npy_array(descr: &str, shape: &[usize], data: &[u8]) -> Vec<u8> {
+    let dims: Vec<String> = shape.iter().map(|dim| dim.to_string()).collect();
+    let shape = if dims.len() == 1 {
+        format!("({},)", dims[0])
+    } else {
+        format!("({})", dims.join(", "))
+    };
+    let mut header = format!(
+        "{{'descr': '{}', 'fortran_order': False, 'shape': {}, }}",
+        descr, shape
+    );
+    // 魔数、版本和长度共 10 字节，头部以换行结尾并补齐到 64 字节
+    let padding = 63 - (10 + header.len()) % 64;
+    header.push_str(&" ".repeat(padding));
+    header.push('\n');
+
+    let mut bytes = Vec::with_capacity(10 + header.len() + data.len());
+    bytes.extend_from_slice(b"\x93NUMPY\x01\x00");
+    bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    bytes.extend_from_slice(header.as_bytes());
+    bytes.extend_from_slice(data);
+    bytes
+}
+
+/// 写出不压缩的 zip，文件时间固定为 1980-01-01，同样的内容得到同样的字节
+fn write_stored_zip(mut writer: impl Write, files: &[(&str, &[u8])]) -> io::Result<()> {
+    const DOS_DATE: u16 = (1 << 5) | 1;
+    let mut offset: u32 = 0;
+    let mut central = Vec::new();
+
+    for &(name, data) in files {
+        let size = u32::try_from(data.len()).map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::Other,
+                format!("{} is too large for zip", name),
+            )
+        })?;
+        let crc = crc32(data);
+
+        let mut local = Vec::with_capacity(30 + name.len());
+        local.extend_from_slice(&0x0403_4b50u32.to_le_bytes());
+        local.extend_from_slice(&20u16.to_le_bytes()); // 所需版本
+        local.extend_from_slice(&0u16.to_le_bytes()); // 标志
+        local.extend_from_slice(&0u16.to_le_bytes()); // 不压缩
+        local.extend_from_slice(&0u16.to_le_bytes()); // 时间
+        local.extend_from_slice(&DOS_DATE.to_le_bytes());
+        local.extend_from_slice(&crc.to_le_bytes());
+        local.extend_from_slice(&size.to_le_bytes());
+        local.extend_from_slice(&size.to_le_bytes());
+        local.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        local.extend_from_slice(&0u16.to_le_bytes()); // 扩展字段
+        local.extend_from_slice(name.as_bytes());
+        writer.write_all(&local)?;
+        writer.write_all(data)?;
+
+        central.extend_from_slice(&0x0201_4b50u32.to_le_bytes());
+        central.extend_from_slice(&20u16.to_le_bytes()); // 创建版本
+        central.extend_from_slice(&local[4..30]);
+        central.extend_from_slice(&0u16.to_le_bytes()); // 注释
+        central.extend_from_slice(&0u16.to_le_bytes()); // 磁盘号
+        central.extend_from_slice(&0u16.to_le_bytes()); // 内部属性
+        central.extend_from_slice(&0u32.to_le_bytes()); // 外部属性
+        central.extend_from_slice(&offset.to_le_bytes());
+        central.extend_from_slice(name.as_bytes());
+
+        offset = u32::try_from(local.len() + data.len())
+            .ok()
+            .and_then(|written| offset.checked_add(written))
+            .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "samples are too large for zip"))?;
+    }
+
+    writer.write_all(&central)?;
+    let mut end = Vec::with_capacity(22);
+    end.extend_from_slice(&0x0605_4b50u32.to_le_bytes());
+    end.extend_from_slice(&0u16.to_le_bytes()); // 本磁盘号
+    end.extend_from_slice(&0u16.to_le_bytes()); // 目录所在磁盘
+    end.extend_from_slice(&(files.len() as u16).to_le_bytes());
+    end.extend_from_slice(&(files.len() as u16).to_le_bytes());
+    end.extend_from_slice(&(central.len() as u32).to_le_bytes());
+    end.extend_from_slice(&offset.to_le_bytes());
+    end.extend_from_slice(&0u16.to_le_bytes()); // 注释
+    writer.write_all(&end)?;
+    writer.flush()
+}
+
+/// zip 使用的 CRC-32（IEEE）
+fn crc32(data: &[u8]) -> u32 {
+    const TABLE: [u32; 256] = {
+        let mut table = [0u32; 256];
+        let mut i = 0;
+        while i < 256 {
+            let mut crc = i as u32;
+            let mut bit = 0;
+            while bit < 8 {
+                crc = if crc & 1 != 0 {
+                    0xEDB8_8320 ^ (crc >> 1)
+                } else {
+                    crc >> 1
+                };
+                bit += 1;
+            }
+            table[i] = crc;
+            i += 1;
+        }
+        table
+    };
+
+    let mut crc = !0u32;
+    for &byte in data {
+        crc = TABLE[((crc ^ byte as u32) & 0xFF) as usize] ^ (crc >> 8);
+    }
+    !crc
+}
@@ -0,0 +1,554 @@
+use super::board::{BoardState, StoneColor};
+use super::bot::GeneratedMove;
+use super::rules::GoBoardRules;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+// 死活题求解：只在标出的区域内落子，用规则引擎做与或树搜索
+// 劫按两种假设各搜索一次：攻方能赢所有劫争、守方能赢所有劫争，结果不同即为劫
+
+/// 题目要求
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TsumegoGoal {
+    /// 杀死目标棋块（目标是对方的棋）
+    Kill,
+    /// 让目标棋块活棋（目标是己方的棋）
+    Live,
+    /// 提掉目标棋串，不区分活棋和双活
+    Capture,
+}
+
+/// 死活题
+#[derive(Clone)]
+pub struct TsumegoProblem {
+    pub board_state: BoardState,
+    /// 允许落子的点，其余位置只能保持原样
+    pub region: Vec<(i32, i32)>,
+    pub to_play: StoneColor,
+    /// 目标棋串上的任意一点
+    pub target: (i32, i32),
+    pub goal: TsumegoGoal,
+}
+
+/// 目标棋块在轮到方先下时的结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LifeStatus {
+    Alive,
+    Dead,
+    /// 结果取决于劫争
+    Ko,
+    /// 双活
+    Seki,
+    /// 超出搜索上限
+    Unknown,
+}
+
+/// 求解结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct TsumegoSolution {
+    pub status: LifeStatus,
+    /// 轮到方能否达成题目要求（劫不算成功）
+    pub solved: bool,
+    /// 成功时为每个正解的主要变化，失败时为最顽强的一种下法被应对的变化
+    pub variations: Vec<Vec<GeneratedMove>>,
+    /// 搜索的节点数
+    pub nodes: usize,
+}
+
+/// 题目设置不正确的原因
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TsumegoError {
+    /// 目标点上没有棋子
+    NoTarget,
+    /// 要求与目标颜色不符：杀棋和提子的目标必须是对方的棋，活棋的目标必须是己方的棋
+    WrongSide,
+    /// 区域内没有空点
+    EmptyRegion,
+}
+
+impl fmt::Display for TsumegoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reason = match self {
+            TsumegoError::NoTarget => "no stone on the target point",
+            TsumegoError::WrongSide => "goal does not match the target colour",
+            TsumegoError::EmptyRegion => "region has no empty points",
+        };
+        f.write_str(reason)
+    }
+}
+
+impl std::error::Error for TsumegoError {}
+
+/// 死活题求解器
+///
+/// # 使用示例
+/// ```rust,ignore
+/// let solution = TsumegoSolver::new().solve(&problem)?;
+/// if let Some(answer) = solution.variations.first() {
+///     println!("{:?}: {:?}", solution.status, answer);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TsumegoSolver {
+    /// 每次搜索的节点上限，超过时结果为 [`LifeStatus::Unknown`]
+    pub max_nodes: usize,
+}
+
+impl Default for TsumegoSolver {
+    fn default() -> Self {
+        Self { max_nodes: 200_000 }
+    }
+}
+
+/// 主要变化的最大长度
+const MAX_VARIATION_LENGTH: usize = 40;
+
+impl TsumegoSolver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_max_nodes(mut self, max_nodes: usize) -> Self {
+        self.max_nodes = max_nodes;
+        self
+    }
+
+    pub fn solve(&self, problem: &TsumegoProblem) -> Result<TsumegoSolution, TsumegoError> {
+        let (x, y) = problem.target;
+        let target_color = problem
+            .board_state
+            .get_stone(x, y)
+            .ok_or(TsumegoError::NoTarget)?;
+        let attacks = problem.goal != TsumegoGoal::Live;
+        if attacks == (target_color == problem.to_play) {
+            return Err(TsumegoError::WrongSide);
+        }
+        if !problem
+            .region
+            .iter()
+            .any(|&(x, y)| problem.board_state.get_stone(x, y).is_none())
+        {
+            return Err(TsumegoError::EmptyRegion);
+        }
+
+        let attacker = target_color.opposite();
+        let mut attacker_favored = Search::new(problem, self.max_nodes, attacker);
+        let mut defender_favored = Search::new(problem, self.max_nodes, target_color);
+        let root = &problem.board_state;
+        let wins_with_ko = attacker_favored.attacker_wins(root, problem.to_play, 0);
+        let wins_without_ko = defender_favored.attacker_wins(root, problem.to_play, 0);
+        let nodes = attacker_favored.nodes + defender_favored.nodes;
+
+        let (Some(wins_with_ko), Some(wins_without_ko)) = (wins_with_ko, wins_without_ko) else {
+            return Ok(TsumegoSolution {
+                status: LifeStatus::Unknown,
+                solved: false,
+                variations: Vec::new(),
+                nodes,
+            });
+        };
+
+        let status = if wins_without_ko.attacker_wins {
+            LifeStatus::Dead
+        } else if wins_with_ko.attacker_wins {
+            LifeStatus::Ko
+        } else if problem.goal != TsumegoGoal::Capture
+            && attacker_favored.ends_in_seki(root, problem.to_play)
+        {
+            LifeStatus::Seki
+        } else {
+            LifeStatus::Alive
+        };
+
+        // 变化取自决定结果的那种劫争假设，劫则取对轮到方有利的假设
+        let search = match status {
+            LifeStatus::Dead => &mut defender_favored,
+            LifeStatus::Ko if problem.to_play == attacker => &mut attacker_favored,
+            LifeStatus::Ko => &mut defender_favored,
+            _ => &mut attacker_favored,
+        };
+        let variations = search.variations(root, problem.to_play);
+        let solved = match problem.goal {
+            TsumegoGoal::Kill | TsumegoGoal::Capture => status == LifeStatus::Dead,
+            TsumegoGoal::Live => matches!(status, LifeStatus::Alive | LifeStatus::Seki),
+        };
+
+        Ok(TsumegoSolution {
+            status,
+            solved,
+            variations,
+            nodes,
+        })
+    }
+}
+
+/// 一个节点的搜索结果
+#[derive(Debug, Clone, Copy)]
+struct Outcome {
+    attacker_wins: bool,
+    /// 结果依赖于搜索路径上的重复局面，不能放进置换表
+    cycle: bool,
+}
+
+/// 置换表的键：棋盘、轮到方、打劫点和连续虚手数
+type Key = (Vec<u8>, StoneColor, Option<(i32, i32)>, usize);
+
+/// 一种劫争假设下的搜索
+struct Search {
+    region: Vec<(i32, i32)>,
+    target: (i32, i32),
+    target_color: StoneColor,
+    /// 能无视打劫规则立即提回的一方
+    ko_winner: StoneColor,
+    max_nodes: usize,
+    max_depth: usize,
+    nodes: usize,
+    table: HashMap<Key, bool>,
+    path: Vec<Key>,
+}
+
+impl Search {
+    fn new(problem: &TsumegoProblem, max_nodes: usize, ko_winner: StoneColor) -> Self {
+        let (x, y) = problem.target;
+        Self {
+            region: problem.region.clone(),
+            target: problem.target,
+            target_color: problem
+                .board_state
+                .get_stone(x, y)
+                .unwrap_or(StoneColor::Black),
+            ko_winner,
+            max_nodes,
+            max_depth: problem.region.len() * 3 + 10,
+            nodes: 0,
+            table: HashMap::new(),
+            path: Vec::new(),
+        }
+    }
+
+    fn key(board_state: &BoardState, to_play: StoneColor) -> Key {
+        let size = board_state.board_size.get_value();
+        let cells = (0..size)
+            .flat_map(|x| (0..size).map(move |y| (x, y)))
+            .map(|(x, y)| match board_state.get_stone(x, y) {
+                None => 0,
+                Some(StoneColor::Black) => 1,
+                Some(StoneColor::White) => 2,
+            })
+            .collect();
+        (
+            cells,
+            to_play,
+            board_state.ko_position,
+            board_state.consecutive_passes.min(1),
+        )
+    }
+
+    /// 不需要继续搜索的局面：目标被提为攻方胜，目标有两只眼为守方胜
+    fn settled(&self, board_state: &BoardState) -> Option<bool> {
+        let (x, y) = self.target;
+        if board_state.get_stone(x, y) != Some(self.target_color) {
+            return Some(true);
+        }
+        if chain_eyes(board_state, x, y) >= 2 {
+            return Some(false);
+        }
+        None
+    }
+
+    /// 区域内的候选着法（不含虚手），提子和目标的气优先
+    fn candidates(&self, board_state: &BoardState, to_play: StoneColor) -> Vec<(i32, i32)> {
+        let (tx, ty) = self.target;
+        let target_chain = GoBoardRules::get_group(board_state, tx, ty);
+
+        let mut scored: Vec<((i32, i32), i32)> = self
+            .region
+            .iter()
+            .copied()
+            .filter(|&(x, y)| self.is_playable(board_state, x, y, to_play))
+            // 不填自己的真眼
+            .filter(|&(x, y)| !is_own_eye(board_state, x, y, to_play))
+            .map(|(x, y)| {
+                let mut score = 0;
+                for (nx, ny) in board_state.get_neighbors(x, y) {
+                    if target_chain.contains(&(nx, ny)) {
+                        score += 2;
+                    }
+                    if board_state.get_stone(nx, ny) == Some(to_play.opposite()) {
+                        let group = GoBoardRules::get_group(board_state, nx, ny);
+                        if GoBoardRules::count_liberties(board_state, &group) == 1 {
+                            score += 10;
+                        }
+                    }
+                }
+                ((x, y), score)
+            })
+            .collect();
+        scored.sort_by_key(|&(_, score)| std::cmp::Reverse(score));
+        scored.into_iter().map(|(point, _)| point).collect()
+    }
+
+    fn is_playable(&self, board_state: &BoardState, x: i32, y: i32, color: StoneColor) -> bool {
+        if color == self.ko_winner && board_state.ko_position == Some((x, y)) {
+            let mut without_ko = board_state.clone();
+            without_ko.ko_position = None;
+            return GoBoardRules::is_valid_move(&without_ko, x, y, color);
+        }
+        GoBoardRules::is_valid_move(board_state, x, y, color)
+    }
+
+    /// 走一步，虚手用 `None`
+    fn apply(
+        &self,
+        board_state: &BoardState,
+        played: Option<(i32, i32)>,
+        color: StoneColor,
+    ) -> BoardState {
+        let mut next = board_state.clone();
+        match played {
+            Some((x, y)) => {
+                if color == self.ko_winner && next.ko_position == Some((x, y)) {
+                    next.ko_position = None;
+                }
+                let _ = GoBoardRules::play_move(&mut next, x, y, color);
+            }
+            None => GoBoardRules::pass(&mut next),
+        }
+        next
+    }
+
+    /// 攻方能否提掉目标，超出节点上限时返回 `None`
+    fn attacker_wins(
+        &mut self,
+        board_state: &BoardState,
+        to_play: StoneColor,
+        depth: usize,
+    ) -> Option<Outcome> {
+        if let Some(attacker_wins) = self.settled(board_state) {
+            return Some(Outcome {
+                attacker_wins,
+                cycle: false,
+            });
+        }
+
+        let key = Self::key(board_state, to_play);
+        if self.path.contains(&key) {
+            // 循环劫：能无视打劫的一方获胜
+            return Some(Outcome {
+                attacker_wins: self.ko_winner != self.target_color,
+                cycle: true,
+            });
+        }
+        if let Some(&attacker_wins) = self.table.get(&key) {
+            return Some(Outcome {
+                attacker_wins,
+                cycle: false,
+            });
+        }
+        self.nodes += 1;
+        if self.nodes > self.max_nodes || depth > self.max_depth {
+            return None;
+        }
+
+        let attacker_to_move = to_play != self.target_color;
+        let mut moves: Vec<Option<(i32, i32)>> = self
+            .candidates(board_state, to_play)
+            .into_iter()
+            .map(Some)
+            .collect();
+        moves.push(None);
+
+        self.path.push(key.clone());
+        let mut result = Outcome {
+            attacker_wins: !attacker_to_move,
+            cycle: false,
+        };
+        for played in moves {
+            // 双方连续虚手，目标还在，守方胜
+            let outcome = if played.is_none() && board_state.consecutive_passes > 0 {
+                Outcome {
+                    attacker_wins: false,
+                    cycle: false,
+                }
+            } else {
+                let next = self.apply(board_state, played, to_play);
+                match self.attacker_wins(&next, to_play.opposite(), depth + 1) {
+                    Some(outcome) => outcome,
+                    None => {
+                        self.path.pop();
+                        return None;
+                    }
+                }
+            };
+
+            if outcome.attacker_wins == attacker_to_move {
+                result = outcome;
+                break;
+            }
+            result.cycle |= outcome.cycle;
+        }
+        self.path.pop();
+
+        if !result.cycle {
+            self.table.insert(key, result.attacker_wins);
+        }
+        Some(result)
+    }
+
+    /// 轮到方下某一手后对方是否失败；搜索中止时视为失败
+    fn move_wins(
+        &mut self,
+        board_state: &BoardState,
+        played: Option<(i32, i32)>,
+        color: StoneColor,
+    ) -> bool {
+        let attacker_to_move = color != self.target_color;
+        if played.is_none() && board_state.consecutive_passes > 0 {
+            return !attacker_to_move;
+        }
+        let next = self.apply(board_state, played, color);
+        self.attacker_wins(&next, color.opposite(), 0)
+            .is_some_and(|outcome| outcome.attacker_wins == attacker_to_move)
+    }
+
+    /// 从某个局面开始，双方都按搜索结果下的主要变化
+    fn principal_variation(
+        &mut self,
+        board_state: &BoardState,
+        to_play: StoneColor,
+    ) -> Vec<GeneratedMove> {
+        let mut board_state = board_state.clone();
+        let mut color = to_play;
+        let mut variation = Vec::new();
+        let mut seen = HashSet::new();
+
+        while variation.len() < MAX_VARIATION_LENGTH && self.settled(&board_state).is_none() {
+            if !seen.insert(Self::key(&board_state, color)) {
+                break;
+            }
+            let mut moves: Vec<Option<(i32, i32)>> = self
+                .candidates(&board_state, color)
+                .into_iter()
+                .map(Some)
+                .collect();
+            moves.push(None);
+            // 能赢就走赢的一手，否则虚手，不做无谓的抵抗
+            let chosen = moves
+                .into_iter()
+                .find(|&played| self.move_wins(&board_state, played, color))
+                .flatten();
+
+            variation.push(match chosen {
+                Some((x, y)) => GeneratedMove::Play(x, y),
+                None => GeneratedMove::Pass,
+            });
+            if chosen.is_none() && board_state.consecutive_passes > 0 {
+                break;
+            }
+            board_state = self.apply(&board_state, chosen, color);
+            color = color.opposite();
+        }
+        variation
+    }
+
+    /// 正解的变化；没有正解时给出第一候选被应对的变化
+    fn variations(
+        &mut self,
+        board_state: &BoardState,
+        to_play: StoneColor,
+    ) -> Vec<Vec<GeneratedMove>> {
+        let mut moves: Vec<Option<(i32, i32)>> = self
+            .candidates(board_state, to_play)
+            .into_iter()
+            .map(Some)
+            .collect();
+        moves.push(None);
+
+        let correct: Vec<Option<(i32, i32)>> = moves
+            .iter()
+            .copied()
+            .filter(|&played| self.move_wins(board_state, played, to_play))
+            .collect();
+        let first_moves = if correct.is_empty() {
+            vec![moves[0]]
+        } else {
+            correct
+        };
+
+        first_moves
+            .into_iter()
+            .map(|played| {
+                let first = match played {
+                    Some((x, y)) => GeneratedMove::Play(x, y),
+                    None => GeneratedMove::Pass,
+                };
+                let next = self.apply(board_state, played, to_play);
+                let mut variation = vec![first];
+                variation.extend(self.principal_variation(&next, to_play.opposite()));
+                variation
+            })
+            .collect()
+    }
+
+    /// 守方活下来的主要变化是否以双活结束：目标没有两只眼，但和攻方共用气
+    fn ends_in_seki(&mut self, board_state: &BoardState, to_play: StoneColor) -> bool {
+        let attacker = self.target_color.opposite();
+        let mut end = board_state.clone();
+        let mut color = to_play;
+        for played in self.principal_variation(board_state, to_play) {
+            let played = match played {
+                GeneratedMove::Play(x, y) => Some((x, y)),
+                _ => None,
+            };
+            end = self.apply(&end, played, color);
+            color = color.opposite();
+        }
+
+        let (x, y) = self.target;
+        if end.get_stone(x, y) != Some(self.target_color) || chain_eyes(&end, x, y) >= 2 {
+            return false;
+        }
+        let chain = GoBoardRules::get_group(&end, x, y);
+        chain.iter().any(|&(cx, cy)| {
+            end.get_neighbors(cx, cy).into_iter().any(|(lx, ly)| {
+                end.get_stone(lx, ly).is_none()
+                    && end
+                        .get_neighbors(lx, ly)
+                        .into_iter()
+                        .any(|(nx, ny)| end.get_stone(nx, ny) == Some(attacker))
+            })
+        })
+    }
+}
+
+/// 棋串的眼数：四周全是该棋串棋子的空点，两只这样的眼的棋串不会被提
+fn chain_eyes(board_state: &BoardState, x: i32, y: i32) -> usize {
+    let chain = GoBoardRules::get_group(board_state, x, y);
+    let mut eyes = HashSet::new();
+    for &(cx, cy) in &chain {
+        for (lx, ly) in board_state.get_neighbors(cx, cy) {
+            if board_state.get_stone(lx, ly).is_none()
+                && board_state
+                    .get_neighbors(lx, ly)
+                    .into_iter()
+                    .all(|point| chain.contains(&point))
+            {
+                eyes.insert((lx, ly));
+            }
+        }
+    }
+    eyes.len()
+}
+
+/// 四周都是同一棋串的空点
+fn is_own_eye(board_state: &BoardState, x: i32, y: i32, color: StoneColor) -> bool {
+    let neighbors = board_state.get_neighbors(x, y);
+    let Some(&(nx, ny)) = neighbors.first() else {
+        return false;
+    };
+    if board_state.get_stone(nx, ny) != Some(color) {
+        return false;
+    }
+    let chain = GoBoardRules::get_group(board_state, nx, ny);
+    neighbors.iter().all(|point| chain.contains(point))
+}
@@ -1,4 +1,8 @@
+pub mod go_core;
+
+#[cfg(feature = "bevy")]
 pub mod go_board_component;
 
 // Re-export for convenience
+#[cfg(feature = "bevy")]
 pub use go_board_component::prelude;
@@ -1,15 +1,42 @@
 // 示例应用 - 展示如何使用围棋棋盘组件
 // Example app - demonstrating how to use the Go Board component
 
-mod go_board_component;
-
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use bevy::window::{MonitorSelection, PrimaryWindow, WindowResizeConstraints};
+use black_white_legends::go_board_component;
+use black_white_legends::go_core::{BotLevel, MctsConfig, SearchBudget};
 use go_board_component::plugin::GoBoardPluginBuilder;
 use go_board_component::prelude::*;
-use go_board_component::utils::CoordinateUtils;
+use std::time::Duration;
 
 fn main() {
+    // 可选的对手引擎和分析引擎，如
+    // `--engine "gnugo --mode gtp" --engine-color white --analysis "katago analysis -config ..."`
+    // 或使用内置棋手 `--mcts --think-ms 2000`、`--bot atari --seed 42`；
+    // 启用 `nn` 特性时 `--policy model.onnx` 显示落子提示并引导 `--mcts`
+    let engines = EngineArgs::from_args();
+    let mut plugin = GoBoardPluginBuilder::new()
+        .with_board_size(BoardSize::Nineteen)
+        .with_coordinates(true)
+        .with_move_numbers(false)
+        .with_captures(true)
+        .with_ko_rule(true)
+        .with_scoring_phase(true)
+        .with_keyboard_cursor(true)
+        .with_touch_controls(true)
+        .with_fit_to_window(true);
+    if let Some((color, backend)) = engines.opponent {
+        plugin = plugin.with_computer_player(color, backend);
+    }
+    if let Some(command) = engines.analysis {
+        plugin = plugin.with_analysis_engine(command);
+    }
+    #[cfg(feature = "nn")]
+    if let Some(path) = engines.policy {
+        plugin = plugin.with_policy_model(path);
+    }
+
     App::new()
         .add_plugins(DefaultPlugins.set(WindowPlugin {
             primary_window: Some(Window {
@@ -26,38 +53,113 @@ fn main() {
         }))
         .insert_resource(ClearColor(Color::srgb(0.4, 0.4, 0.4)))
         // 使用围棋棋盘插件
-        .add_plugins(
-            GoBoardPluginBuilder::new()
-                .with_board_size(BoardSize::Nineteen)
-                .with_coordinates(true)
-                .with_move_numbers(false)
-                .with_captures(true)
-                .with_ko_rule(true)
-                .build(),
-        )
+        .add_plugins(plugin.build())
         // 添加示例应用的系统
-        .add_systems(Startup, (setup_camera, setup_ui, initial_board_draw))
+        .init_resource::<ActiveBoard>()
+        .add_systems(Startup, (setup_camera, setup_ui))
         .add_systems(
             Update,
             (
-                handle_keyboard_input,
-                handle_window_resize,
-                handle_mouse_hover,
-                handle_mouse_click,
+                handle_keyboard_input.before(go_board_component::keyboard::handle_keyboard_play),
+                handle_board_press,
                 update_turn_display,
             ),
         )
         .run();
 }
 
+/// 命令行指定的外部引擎
+struct EngineArgs {
+    /// 对手和它执的颜色（默认执白）
+    opponent: Option<(StoneColor, PlayerBackend)>,
+    /// KataGo 分析引擎
+    analysis: Option<EngineCommand>,
+    /// 19 路 ONNX 策略网络
+    #[cfg(feature = "nn")]
+    policy: Option<std::path::PathBuf>,
+}
+
+impl EngineArgs {
+    fn from_args() -> Self {
+        let mut args = std::env::args().skip(1);
+        let mut opponent = None;
+        let mut color = StoneColor::White;
+        let mut analysis = None;
+        let mut mcts = MctsConfig::default();
+        let mut seed = 0;
+        #[cfg(feature = "nn")]
+        let mut policy = None;
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--engine" => {
+                    opponent = args
+                        .next()
+                        .as_deref()
+                        .and_then(EngineCommand::parse)
+                        .map(PlayerBackend::Gtp)
+                }
+                "--mcts" => opponent = Some(PlayerBackend::Mcts(MctsConfig::default())),
+                "--bot" => {
+                    if let Some(level) = args.next().as_deref().and_then(BotLevel::from_name) {
+                        opponent = Some(PlayerBackend::Heuristic { level, seed: 0 });
+                    }
+                }
+                "--seed" => {
+                    seed = args
+                        .next()
+                        .and_then(|value| value.parse().ok())
+                        .unwrap_or(0)
+                }
+                "--think-ms" => {
+                    if let Some(millis) = args.next().and_then(|value| value.parse().ok()) {
+                        mcts.budget = SearchBudget::Time(Duration::from_millis(millis));
+                    }
+                }
+                "--engine-color" => {
+                    color = match args.next().as_deref() {
+                        Some("black") => StoneColor::Black,
+                        _ => StoneColor::White,
+                    }
+                }
+                "--analysis" => analysis = args.next().as_deref().and_then(EngineCommand::parse),
+                #[cfg(feature = "nn")]
+                "--policy" => policy = args.next().map(std::path::PathBuf::from),
+                _ => {}
+            }
+        }
+        // 思考时间和种子可以写在 `--mcts`、`--bot` 前后
+        match &mut opponent {
+            Some(PlayerBackend::Mcts(config)) => *config = mcts,
+            Some(PlayerBackend::Heuristic { seed: bot_seed, .. }) => *bot_seed = seed,
+            _ => {}
+        }
+        // 有策略网络时由它引导 MCTS 棋手，示例应用的棋盘是 19 路
+        #[cfg(feature = "nn")]
+        if let (Some(PlayerBackend::Mcts(config)), Some(model)) = (&opponent, &policy) {
+            let guided = PlayerBackend::PolicyMcts {
+                config: config.clone(),
+                model: model.clone(),
+                board_size: BoardSize::Nineteen,
+            };
+            opponent = Some(guided);
+        }
+        Self {
+            opponent: opponent.map(|backend| (color, backend)),
+            analysis,
+            #[cfg(feature = "nn")]
+            policy,
+        }
+    }
+}
+
 fn setup_camera(mut commands: Commands) {
-    commands.spawn((Camera2d::default(), Name::new("Main Camera")));
+    commands.spawn((Camera2d, Name::new("Main Camera")));
 }
 
 fn setup_ui(mut commands: Commands) {
     // 帮助文字 - 使用英文避免字体问题
     commands.spawn((
-        Text::new("Go Game - Black's Turn\\n1-3: Board sizes | C: Toggle coords | M: Toggle move numbers\\nF: Fullscreen | ESC: Exit | Click to place stones | R: Reset board"),
+        Text::new("Go Game - Black's Turn\\n1-3: Board sizes | C: Toggle coords | M: Toggle move numbers\\nF: Fullscreen | ESC: Exit | Click or Enter to place stones | P: Pass | R: Reset board\\nArrows/HJKL: Move cursor | /: Type a coordinate | ,/.: Previous/next move | Home/End: First/last move\\nTouch: tap to place, pinch to zoom, two fingers to pan, double-tap to reset\\nA: Toggle candidate moves | O: Toggle ownership | T: Toggle hints | D: Toggle ladders | E: Score estimate\\nG: Toggle liberties | W: Toggle atari warnings | N: Toggle move confirmation\\nAfter two passes: click or Enter on stones to mark dead | S: Confirm score"),
         TextFont {
             font_size: 18.0,
             ..default()
@@ -75,48 +177,58 @@ fn setup_ui(mut commands: Commands) {
 #[derive(Component)]
 struct HelpText;
 
-#[derive(Component)]
-struct HoverIndicator;
+/// 键盘操作作用的棋盘（最近一次点击的棋盘）
+#[derive(Resource, Default)]
+struct ActiveBoard(Option<Entity>);
 
-fn initial_board_draw(
-    mut redraw_events: EventWriter<go_board_component::events::RedrawBoardEvent>,
-) {
-    redraw_events.write(go_board_component::events::RedrawBoardEvent);
+impl ActiveBoard {
+    /// 返回当前棋盘，尚未点击过时使用第一个棋盘
+    fn resolve(&self, boards: impl IntoIterator<Item = Entity>) -> Option<Entity> {
+        self.0.or_else(|| boards.into_iter().next())
+    }
 }
 
 fn handle_keyboard_input(
-    _commands: Commands,
     keyboard: Res<ButtonInput<KeyCode>>,
     mut config_events: EventWriter<go_board_component::events::UpdateBoardConfigEvent>,
     mut clear_events: EventWriter<go_board_component::events::ClearBoardEvent>,
     mut windows: Query<&mut Window, With<PrimaryWindow>>,
-    current_config: Res<go_board_component::resources::CurrentGoBoardConfig>,
-    mut board_state: ResMut<go_board_component::resources::BoardState>,
+    boards: Query<(Entity, &CurrentGoBoardConfig, Option<&BoardCursor>), With<GoBoardRoot>>,
+    active_board: Res<ActiveBoard>,
 ) {
+    // 输入坐标时按键交给键盘光标
+    if boards
+        .iter()
+        .any(|(_, _, cursor)| cursor.is_some_and(|cursor| cursor.typed.is_some()))
+    {
+        return;
+    }
+
+    let Some(board) = active_board.resolve(boards.iter().map(|(entity, _, _)| entity)) else {
+        return;
+    };
+    let Ok((_, current_config, _)) = boards.get(board) else {
+        return;
+    };
+
     let mut new_config = None;
-    let mut should_clear_board = false;
 
     if keyboard.just_pressed(KeyCode::Digit1) {
+        // 改变棋盘大小会重置对局
         new_config = Some(GoBoardConfig {
             board_size: BoardSize::Nine,
             ..current_config.0.clone()
         });
-        board_state.board_size = BoardSize::Nine;
-        should_clear_board = true;
     } else if keyboard.just_pressed(KeyCode::Digit2) {
         new_config = Some(GoBoardConfig {
             board_size: BoardSize::Thirteen,
             ..current_config.0.clone()
         });
-        board_state.board_size = BoardSize::Thirteen;
-        should_clear_board = true;
     } else if keyboard.just_pressed(KeyCode::Digit3) {
         new_config = Some(GoBoardConfig {
             board_size: BoardSize::Nineteen,
             ..current_config.0.clone()
         });
-        board_state.board_size = BoardSize::Nineteen;
-        should_clear_board = true;
     } else if keyboard.just_pressed(KeyCode::KeyC) {
         new_config = Some(GoBoardConfig {
             show_coordinates: !current_config.0.show_coordinates,
@@ -132,183 +244,167 @@ fn handle_keyboard_input(
             use_3d_stones: !current_config.0.use_3d_stones,
             ..current_config.0.clone()
         });
+    } else if keyboard.just_pressed(KeyCode::KeyA) {
+        new_config = Some(GoBoardConfig {
+            show_candidate_moves: !current_config.0.show_candidate_moves,
+            ..current_config.0.clone()
+        });
+    } else if keyboard.just_pressed(KeyCode::KeyO) {
+        new_config = Some(GoBoardConfig {
+            show_ownership: !current_config.0.show_ownership,
+            ..current_config.0.clone()
+        });
+    } else if keyboard.just_pressed(KeyCode::KeyT) {
+        new_config = Some(GoBoardConfig {
+            show_hints: !current_config.0.show_hints,
+            ..current_config.0.clone()
+        });
+    } else if keyboard.just_pressed(KeyCode::KeyD) {
+        new_config = Some(GoBoardConfig {
+            show_ladders: !current_config.0.show_ladders,
+            ..current_config.0.clone()
+        });
+    } else if keyboard.just_pressed(KeyCode::KeyE) {
+        new_config = Some(GoBoardConfig {
+            show_score_estimate: !current_config.0.show_score_estimate,
+            ..current_config.0.clone()
+        });
+    } else if keyboard.just_pressed(KeyCode::KeyG) {
+        new_config = Some(GoBoardConfig {
+            show_liberties: !current_config.0.show_liberties,
+            ..current_config.0.clone()
+        });
+    } else if keyboard.just_pressed(KeyCode::KeyW) {
+        new_config = Some(GoBoardConfig {
+            show_atari_warnings: !current_config.0.show_atari_warnings,
+            ..current_config.0.clone()
+        });
+    } else if keyboard.just_pressed(KeyCode::KeyN) {
+        new_config = Some(GoBoardConfig {
+            confirm_moves: !current_config.0.confirm_moves,
+            ..current_config.0.clone()
+        });
     } else if keyboard.just_pressed(KeyCode::KeyR) {
         // 重置棋盘
-        clear_events.write(go_board_component::events::ClearBoardEvent);
+        clear_events.write(go_board_component::events::ClearBoardEvent { board });
     } else if keyboard.just_pressed(KeyCode::KeyF) {
         if let Ok(mut window) = windows.single_mut() {
             window.mode = bevy::window::WindowMode::BorderlessFullscreen(MonitorSelection::Current);
         }
-    } else if keyboard.just_pressed(KeyCode::Escape) {
-        if let Ok(mut window) = windows.single_mut() {
-            window.mode = bevy::window::WindowMode::Windowed;
-        }
+    } else if keyboard.just_pressed(KeyCode::Escape)
+        && let Ok(mut window) = windows.single_mut()
+    {
+        window.mode = bevy::window::WindowMode::Windowed;
     }
 
     if let Some(config) = new_config {
-        if should_clear_board {
-            clear_events.write(go_board_component::events::ClearBoardEvent);
-        }
-        config_events.write(go_board_component::events::UpdateBoardConfigEvent { config });
+        config_events.write(go_board_component::events::UpdateBoardConfigEvent { board, config });
     }
 }
 
-fn handle_window_resize(
-    mut resize_events: EventReader<bevy::window::WindowResized>,
-    mut redraw_events: EventWriter<go_board_component::events::RedrawBoardEvent>,
-) {
-    if !resize_events.is_empty() {
-        resize_events.clear();
-        redraw_events.write(go_board_component::events::RedrawBoardEvent);
-    }
+/// 点击时需要读取的棋盘组件
+type ClickedBoard<'a> = (&'a BoardState, &'a CurrentTurn, Has<ScoringPhase>);
+
+/// 点击棋盘产生的事件：对局中选点落子，数子阶段切换死子
+#[derive(SystemParam)]
+struct ClickEvents<'w> {
+    select: EventWriter<'w, SelectPointEvent>,
+    toggle: EventWriter<'w, go_board_component::events::ToggleDeadStonesEvent>,
 }
 
-fn handle_mouse_hover(
-    mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<ColorMaterial>>,
-    windows: Query<&Window, With<PrimaryWindow>>,
-    camera_query: Query<(&Camera, &GlobalTransform)>,
-    hover_query: Query<Entity, With<HoverIndicator>>,
-    current_config: Res<go_board_component::resources::CurrentGoBoardConfig>,
-    board_state: Res<go_board_component::resources::BoardState>,
-    current_turn: Res<go_board_component::resources::CurrentTurn>,
+/// 鼠标左键点击棋盘的交叉点，坐标由插件通过 bevy_picking 换算；手指轻点由触控插件处理
+fn handle_board_press(
+    mut intersection_clicks: EventReader<IntersectionClickEvent>,
+    boards: Query<ClickedBoard, With<GoBoardRoot>>,
+    computer_players: Query<&ComputerPlayer>,
+    mut click_events: ClickEvents,
+    mut active_board: ResMut<ActiveBoard>,
 ) {
-    // 移除现有悬停指示器
-    for entity in hover_query.iter() {
-        commands.entity(entity).despawn();
-    }
-
-    if !current_config.0.enable_hover_indicator {
-        return;
-    }
-
-    let Ok(window) = windows.single() else {
-        return;
-    };
-
-    let Some(cursor_position) = window.cursor_position() else {
-        return;
-    };
-
-    let Ok((camera, camera_transform)) = camera_query.single() else {
-        return;
-    };
-
-    let Ok(world_position) = camera.viewport_to_world_2d(camera_transform, cursor_position) else {
-        return;
-    };
-
-    let window_size = window.resolution.width().min(window.resolution.height());
-
-    if let Some((board_x, board_y)) = CoordinateUtils::world_to_board(
-        world_position,
-        current_config.0.board_size,
-        window_size,
-        current_config.0.adaptive_padding,
-    ) {
-        // 检查位置是否为空
-        if board_state.get_stone(board_x, board_y).is_some() {
-            return;
+    for click in intersection_clicks.read() {
+        if click.button != PointerButton::Primary || click.pointer.is_touch() {
+            continue;
         }
-
-        // 绘制悬停指示器
-        let world_pos = CoordinateUtils::board_to_world(
-            (board_x, board_y),
-            current_config.0.board_size,
-            window_size,
-            current_config.0.adaptive_padding,
-        );
-
-        let padding = if current_config.0.adaptive_padding && window_size > 1400.0 {
-            50.0
-        } else {
-            100.0
+        let Ok((board_state, current_turn, scoring)) = boards.get(click.board) else {
+            continue;
         };
-        let board_background_size = window_size - padding;
-        let cell_size =
-            board_background_size / (current_config.0.board_size.get_value() as f32 + 1.0);
-        let stone_radius = cell_size * 0.45;
-
-        let hover_color = match current_turn.0 {
-            StoneColor::Black => Color::srgba(0.1, 0.1, 0.1, 0.5),
-            StoneColor::White => Color::srgba(0.95, 0.95, 0.95, 0.5),
-        };
-
-        commands.spawn((
-            Mesh2d(meshes.add(Circle::new(stone_radius))),
-            MeshMaterial2d(materials.add(hover_color)),
-            Transform::from_translation(Vec3::new(world_pos.x, world_pos.y, 4.5)),
-            HoverIndicator,
-        ));
-    }
-}
-
-fn handle_mouse_click(
-    mouse_button: Res<ButtonInput<MouseButton>>,
-    windows: Query<&Window, With<PrimaryWindow>>,
-    camera_query: Query<(&Camera, &GlobalTransform)>,
-    mut stone_events: EventWriter<go_board_component::events::PlaceStoneEvent>,
-    mut current_turn: ResMut<go_board_component::resources::CurrentTurn>,
-    current_config: Res<go_board_component::resources::CurrentGoBoardConfig>,
-    board_state: Res<go_board_component::resources::BoardState>,
-) {
-    if !mouse_button.just_pressed(MouseButton::Left) {
-        return;
-    }
-
-    let Ok(window) = windows.single() else {
-        return;
-    };
-
-    let Some(cursor_position) = window.cursor_position() else {
-        return;
-    };
-
-    let Ok((camera, camera_transform)) = camera_query.single() else {
-        return;
-    };
-
-    let Ok(world_position) = camera.viewport_to_world_2d(camera_transform, cursor_position) else {
-        return;
-    };
+        let board = click.board;
+        let (board_x, board_y) = click.position;
+
+        active_board.0 = Some(board);
+
+        // 数子阶段点棋子切换整块棋的死活
+        if scoring {
+            click_events
+                .toggle
+                .write(go_board_component::events::ToggleDeadStonesEvent {
+                    board,
+                    position: (board_x, board_y),
+                });
+            continue;
+        }
 
-    let window_size = window.resolution.width().min(window.resolution.height());
+        // 轮到电脑时不接受点击
+        if computer_players
+            .iter()
+            .any(|player| player.board == board && player.color == current_turn.0)
+        {
+            continue;
+        }
 
-    if let Some((board_x, board_y)) = CoordinateUtils::world_to_board(
-        world_position,
-        current_config.0.board_size,
-        window_size,
-        current_config.0.adaptive_padding,
-    ) {
         // 检查位置是否为空
         if board_state.get_stone(board_x, board_y).is_some() {
-            return;
+            continue;
         }
 
-        // 发送落子事件
-        stone_events.write(go_board_component::events::PlaceStoneEvent {
+        // 发送选点事件，插件按 `confirm_moves` 直接落子或先放置待确认的棋子
+        click_events.select.write(SelectPointEvent {
+            board,
             position: (board_x, board_y),
-            color: current_turn.0,
         });
-
-        // 切换回合
-        current_turn.0 = current_turn.0.opposite();
     }
 }
 
+/// 回合提示需要读取的棋盘组件
+type TurnDisplay<'a> = (
+    Entity,
+    Ref<'a, CurrentTurn>,
+    Option<Ref<'a, BoardScoreEstimate>>,
+);
+
 fn update_turn_display(
-    current_turn: Res<go_board_component::resources::CurrentTurn>,
+    boards: Query<TurnDisplay, With<GoBoardRoot>>,
+    mut removed_estimates: RemovedComponents<BoardScoreEstimate>,
+    active_board: Res<ActiveBoard>,
     mut query: Query<&mut Text, With<HelpText>>,
 ) {
-    if current_turn.is_changed() {
+    let Some(board) = active_board.resolve(boards.iter().map(|(entity, _, _)| entity)) else {
+        return;
+    };
+    let Ok((_, current_turn, estimate)) = boards.get(board) else {
+        return;
+    };
+
+    let estimate_changed = estimate
+        .as_ref()
+        .is_some_and(|estimate| estimate.is_changed())
+        || removed_estimates.read().count() > 0;
+    if current_turn.is_changed() || active_board.is_changed() || estimate_changed {
         for mut text in query.iter_mut() {
-            let turn_text = match current_turn.0 {
-                StoneColor::Black => "Black's Turn",
-                StoneColor::White => "White's Turn",
+            let mut turn_text = match current_turn.0 {
+                StoneColor::Black => "Black's Turn".to_string(),
+                StoneColor::White => "White's Turn".to_string(),
             };
+            // 形势判断打开时附上估计的目差
+            if let Some(estimate) = &estimate {
+                let margin = estimate.0.margin.abs();
+                turn_text.push_str(&match estimate.0.leader() {
+                    Some(StoneColor::Black) => format!(" | Estimate: B+{:.1}", margin),
+                    Some(StoneColor::White) => format!(" | Estimate: W+{:.1}", margin),
+                    None => " | Estimate: Even".to_string(),
+                });
+            }
             text.0 = format!(
-                "Go Game - {}\\n1-3: Board sizes | C: Toggle coords | M: Toggle move numbers\\nF: Fullscreen | ESC: Exit | Click to place stones | R: Reset board",
+                "Go Game - {}\\n1-3: Board sizes | C: Toggle coords | M: Toggle move numbers\\nF: Fullscreen | ESC: Exit | Click or Enter to place stones | P: Pass | R: Reset board\\nArrows/HJKL: Move cursor | /: Type a coordinate | ,/.: Previous/next move | Home/End: First/last move\\nTouch: tap to place, pinch to zoom, two fingers to pan, double-tap to reset\\nA: Toggle candidate moves | O: Toggle ownership | T: Toggle hints | D: Toggle ladders | E: Score estimate\\nG: Toggle liberties | W: Toggle atari warnings | N: Toggle move confirmation\\nAfter two passes: click or Enter on stones to mark dead | S: Confirm score",
                 turn_text
             );
         }
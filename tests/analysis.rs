@@ -0,0 +1,215 @@
+// 分析引擎、落子提示、征子路线与形势判断测试，用桩进程和桩网络代替 KataGo 与策略网络
+// Analysis engine, move hint, ladder and score estimate tests, using a stub process and a stub policy network
+
+mod common;
+
+use bevy::prelude::*;
+use black_white_legends::go_board_component::events::UpdateBoardConfigEvent;
+use black_white_legends::go_core::{
+    AnalysisQuery, FeaturePlanes, Move, PolicyError, PolicyEvaluator, PolicyOutput,
+};
+use black_white_legends::prelude::*;
+use common::{pass, place, play, update_until};
+
+fn stub_engine() -> EngineCommand {
+    EngineCommand::new("sh").arg(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/analysis_stub.sh"
+    ))
+}
+
+fn headless_app() -> (App, Entity) {
+    let mut app = common::headless_app(AnalysisPlugin);
+    let board = common::spawn_board(
+        &mut app,
+        GoBoardConfig {
+            board_size: BoardSize::Nine,
+            ..default()
+        },
+    );
+    let engine = AnalysisEngine::spawn(board, &stub_engine()).unwrap();
+    app.world_mut().spawn(engine);
+    app.update();
+    (app, board)
+}
+
+fn has_analysis(app: &App, board: Entity) -> bool {
+    app.world().get::<BoardAnalysis>(board).is_some()
+}
+
+#[test]
+fn query_uses_katago_field_names() {
+    let moves = [
+        Move {
+            position: Some((3, 5)),
+            color: StoneColor::Black,
+            captured_stones: Vec::new(),
+            move_number: 1,
+            position_hash: 0,
+        },
+        Move {
+            position: None,
+            color: StoneColor::White,
+            captured_stones: Vec::new(),
+            move_number: 1,
+            position_hash: 0,
+        },
+    ];
+    let line = AnalysisQuery::new("q1", BoardSize::Nine, 7.5, &moves).to_line();
+
+    assert!(line.contains(r#""id":"q1""#));
+    assert!(line.contains(r#""moves":[["B","D4"],["W","pass"]]"#));
+    assert!(line.contains(r#""boardXSize":9"#));
+    assert!(line.contains(r#""analyzeTurns":[2]"#));
+    assert!(line.contains(r#""includeOwnership":true"#));
+    assert!(!line.contains("maxVisits"));
+}
+
+#[test]
+fn analysis_is_attached_to_the_board() {
+    let (mut app, board) = headless_app();
+    update_until(&mut app, |app| has_analysis(app, board));
+
+    let analysis = &app.world().get::<BoardAnalysis>(board).unwrap().0;
+    assert_eq!(analysis.move_infos.len(), 3);
+    assert_eq!(
+        analysis.move_infos[0].position(BoardSize::Nine),
+        Some((2, 6))
+    );
+    assert_eq!(analysis.move_infos[2].position(BoardSize::Nine), None);
+    assert_eq!(analysis.current_player(), StoneColor::White);
+    assert_eq!(analysis.ownership_at(0, 0, BoardSize::Nine), Some(0.92));
+    assert_eq!(analysis.ownership_at(8, 8, BoardSize::Nine), Some(-0.88));
+}
+
+#[test]
+fn new_position_replaces_the_analysis() {
+    let (mut app, board) = headless_app();
+    update_until(&mut app, |app| has_analysis(app, board));
+    let first_id = app
+        .world()
+        .get::<BoardAnalysis>(board)
+        .unwrap()
+        .0
+        .id
+        .clone();
+
+    place(&mut app, board, (4, 4), StoneColor::Black);
+    assert!(!has_analysis(&app, board));
+
+    update_until(&mut app, |app| has_analysis(app, board));
+    let second_id = &app.world().get::<BoardAnalysis>(board).unwrap().0.id;
+    assert_ne!(*second_id, first_id);
+}
+
+/// 把所有概率集中在天元的桩网络
+struct CenterPolicy;
+
+impl PolicyEvaluator for CenterPolicy {
+    fn evaluate(&mut self, planes: &FeaturePlanes) -> Result<PolicyOutput, PolicyError> {
+        let mut logits = vec![0.0; 82];
+        logits[4 * 9 + 4] = 10.0;
+        Ok(PolicyOutput::from_logits(planes.board_size, &logits, 0.0))
+    }
+}
+
+fn hinted_app() -> (App, Entity) {
+    let mut app = common::headless_app(HintPlugin);
+    let board = common::spawn_board(
+        &mut app,
+        GoBoardConfig {
+            board_size: BoardSize::Nine,
+            ..default()
+        },
+    );
+    let engine = HintEngine::spawn(board, Box::new(CenterPolicy)).unwrap();
+    app.world_mut().spawn(engine);
+    app.update();
+    (app, board)
+}
+
+fn has_hints(app: &App, board: Entity) -> bool {
+    app.world().get::<MoveHints>(board).is_some()
+}
+
+#[test]
+fn hints_skip_occupied_points() {
+    let (mut app, board) = hinted_app();
+    update_until(&mut app, |app| has_hints(app, board));
+    let hints = &app.world().get::<MoveHints>(board).unwrap().0;
+    assert_eq!(hints.top_moves(1)[0].0, (4, 4));
+
+    place(&mut app, board, (4, 4), StoneColor::Black);
+    update_until(&mut app, |app| {
+        app.world()
+            .get::<MoveHints>(board)
+            .is_some_and(|hints| hints.0.probability(4, 4) == 0.0)
+    });
+}
+
+#[test]
+fn ladders_follow_the_position() {
+    let mut app = common::headless_app(());
+    let config = GoBoardConfig {
+        board_size: BoardSize::Nine,
+        show_ladders: true,
+        ..default()
+    };
+    let board = common::spawn_board(&mut app, config.clone());
+    assert!(app.world().get::<BoardLadders>(board).unwrap().0.is_empty());
+
+    // 白子只剩两口气，黑棋斜下方有子，轮到黑棋时可以征子
+    play(
+        &mut app,
+        board,
+        &[(4, 3), (4, 4), (3, 4), (7, 7), (5, 5), (1, 7)],
+    );
+    let ladders = &app.world().get::<BoardLadders>(board).unwrap().0;
+    assert_eq!(ladders.len(), 1);
+    assert_eq!(ladders[0].target, (4, 4));
+    assert!(ladders[0].captured);
+    assert_eq!(ladders[0].moves[0].0, StoneColor::Black);
+
+    app.world_mut().send_event(UpdateBoardConfigEvent {
+        board,
+        config: GoBoardConfig {
+            show_ladders: false,
+            ..config
+        },
+    });
+    app.update();
+    assert!(app.world().get::<BoardLadders>(board).is_none());
+}
+
+#[test]
+fn score_estimate_follows_the_position() {
+    let mut app = common::headless_app(());
+    let config = GoBoardConfig {
+        board_size: BoardSize::Nine,
+        komi: 0.5,
+        show_score_estimate: true,
+        ..default()
+    };
+    let board = common::spawn_board(&mut app, config.clone());
+    let estimate = &app.world().get::<BoardScoreEstimate>(board).unwrap().0;
+    assert_eq!(estimate.margin, -0.5);
+
+    // 黑棋第 4 列一道墙，左边的地归黑；白棋每手虚手
+    for y in 0..9 {
+        place(&mut app, board, (4, y), StoneColor::Black);
+        pass(&mut app, board, StoneColor::White);
+    }
+    let estimate = &app.world().get::<BoardScoreEstimate>(board).unwrap().0;
+    assert_eq!(estimate.ownership_at(0, 0), Some(1.0));
+    assert_eq!(estimate.leader(), Some(StoneColor::Black));
+
+    app.world_mut().send_event(UpdateBoardConfigEvent {
+        board,
+        config: GoBoardConfig {
+            show_score_estimate: false,
+            ..config
+        },
+    });
+    app.update();
+    assert!(app.world().get::<BoardScoreEstimate>(board).is_none());
+}
@@ -0,0 +1,159 @@
+// 集成测试共用的无窗口应用、棋盘和指针事件辅助函数，各测试文件用 `mod common;` 引入
+// Shared headless app, board and pointer event helpers for the integration tests, pulled in with `mod common;`
+
+#![allow(dead_code)]
+
+use bevy::app::Plugins;
+use bevy::asset::AssetPlugin;
+use bevy::picking::backend::HitData;
+use bevy::picking::pointer::{Location, PointerId};
+use bevy::prelude::*;
+use bevy::render::camera::NormalizedRenderTarget;
+use bevy::window::WindowRef;
+use black_white_legends::go_board_component::events::{StoneActionEvent, StoneActionType};
+use black_white_legends::go_board_component::picking::BoardPointers;
+use black_white_legends::go_board_component::utils::CoordinateUtils;
+use black_white_legends::prelude::*;
+use std::time::{Duration, Instant};
+
+/// 只有棋盘逻辑和 `plugins` 的无窗口应用
+pub fn headless_app<M>(plugins: impl Plugins<M>) -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, GoBoardLogicPlugin))
+        .add_plugins(plugins);
+    app
+}
+
+/// 再加上渲染插件的无窗口应用，只注册网格和材质资源，不需要渲染器
+pub fn render_app() -> App {
+    let mut app = headless_app((AssetPlugin::default(), GoBoardRenderPlugin));
+    app.init_asset::<Mesh>().init_asset::<ColorMaterial>();
+    app
+}
+
+/// 注册 bevy_picking 的指针事件和交叉点事件，测试直接发送指针事件，不需要拾取后端
+pub fn add_pointer_events(app: &mut App) -> &mut App {
+    app.init_resource::<BoardPointers>()
+        .add_event::<Pointer<Over>>()
+        .add_event::<Pointer<Move>>()
+        .add_event::<Pointer<Out>>()
+        .add_event::<Pointer<Cancel>>()
+        .add_event::<Pointer<Click>>()
+        .add_event::<Pointer<DragStart>>()
+        .add_event::<Pointer<DragEnd>>()
+        .add_event::<IntersectionHoverEvent>()
+        .add_event::<IntersectionClickEvent>()
+        .add_event::<IntersectionDragEvent>()
+}
+
+/// 生成棋盘并更新一帧
+pub fn spawn_board(app: &mut App, config: GoBoardConfig) -> Entity {
+    let board = app.world_mut().spawn(GoBoardBundle::new(config)).id();
+    app.update();
+    board
+}
+
+/// 反复更新直到条件成立，引擎和电脑棋手在另一个进程或线程中思考
+pub fn update_until(app: &mut App, condition: impl Fn(&App) -> bool) {
+    let deadline = Instant::now() + Duration::from_secs(60);
+    while !condition(app) {
+        assert!(Instant::now() < deadline, "condition not met in time");
+        app.update();
+        std::thread::sleep(Duration::from_millis(1));
+    }
+}
+
+/// 更新若干帧
+pub fn wait(app: &mut App, frames: usize) {
+    for _ in 0..frames {
+        app.update();
+    }
+}
+
+/// 以指定颜色落子
+pub fn place(app: &mut App, board: Entity, position: (i32, i32), color: StoneColor) {
+    app.world_mut().send_event(PlaceStoneEvent {
+        board,
+        position,
+        color,
+    });
+    app.update();
+}
+
+/// 黑白交替落子，从当前轮到的一方开始
+pub fn play(app: &mut App, board: Entity, moves: &[(i32, i32)]) {
+    for &position in moves {
+        let color = turn(app, board);
+        place(app, board, position, color);
+    }
+}
+
+pub fn pass(app: &mut App, board: Entity, color: StoneColor) {
+    app.world_mut().send_event(StoneActionEvent {
+        board,
+        action_type: StoneActionType::Pass,
+        position: None,
+        color,
+    });
+    app.update();
+}
+
+pub fn state(app: &App, board: Entity) -> &BoardState {
+    app.world().get::<BoardState>(board).unwrap()
+}
+
+pub fn stone(app: &App, board: Entity, position: (i32, i32)) -> Option<StoneColor> {
+    state(app, board).get_stone(position.0, position.1)
+}
+
+pub fn turn(app: &App, board: Entity) -> StoneColor {
+    app.world().get::<CurrentTurn>(board).unwrap().0
+}
+
+pub fn move_count(app: &App, board: Entity) -> usize {
+    state(app, board).move_count
+}
+
+/// 交叉点在棋盘当前变换下的世界坐标
+pub fn world_point(app: &App, board: Entity, position: (i32, i32)) -> Vec3 {
+    let world = app.world();
+    let config = &world.get::<CurrentGoBoardConfig>(board).unwrap().0;
+    CoordinateUtils::board_to_world(
+        position,
+        world.get::<GlobalTransform>(board).unwrap(),
+        config.board_size,
+        config.board_extent,
+    )
+}
+
+/// 命中交叉点的拾取结果
+pub fn hit(app: &App, board: Entity, position: (i32, i32)) -> HitData {
+    HitData::new(
+        Entity::PLACEHOLDER,
+        0.0,
+        Some(world_point(app, board, position)),
+        None,
+    )
+}
+
+/// 以鼠标指针向 `target` 发送指针事件
+pub fn send_pointer<E: std::fmt::Debug + Clone + Reflect>(app: &mut App, target: Entity, event: E) {
+    let location = Location {
+        target: NormalizedRenderTarget::Window(
+            WindowRef::Entity(Entity::PLACEHOLDER)
+                .normalize(None)
+                .unwrap(),
+        ),
+        position: Vec2::ZERO,
+    };
+    app.world_mut()
+        .send_event(Pointer::new(PointerId::Mouse, location, target, event));
+}
+
+/// 取出目前发出的事件
+pub fn drain<E: Event>(app: &mut App) -> Vec<E> {
+    app.world_mut()
+        .resource_mut::<Events<E>>()
+        .drain()
+        .collect()
+}
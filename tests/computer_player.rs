@@ -0,0 +1,128 @@
+// 电脑棋手测试，用本仓库的 gtp_engine 作为外部引擎，另测内置 MCTS 棋手
+// Computer player tests, using this crate's gtp_engine binary as the external engine plus the built-in MCTS bot
+
+mod common;
+
+use bevy::prelude::*;
+use black_white_legends::go_board_component::components::GameOver;
+use black_white_legends::go_board_component::events::{
+    GameEndEvent, StoneActionEvent, StoneActionType, UndoMoveEvent,
+};
+use black_white_legends::go_core::{MctsConfig, SearchBudget};
+use black_white_legends::prelude::*;
+use common::{move_count, pass, place, turn, update_until};
+
+fn mock_engine() -> PlayerBackend {
+    PlayerBackend::Gtp(
+        EngineCommand::new(env!("CARGO_BIN_EXE_gtp_engine"))
+            .arg("--seed")
+            .arg("7"),
+    )
+}
+
+fn headless_app() -> (App, Entity) {
+    headless_app_with(&mock_engine())
+}
+
+fn headless_app_with(backend: &PlayerBackend) -> (App, Entity) {
+    let mut app = common::headless_app(ComputerPlayerPlugin);
+    let board = common::spawn_board(
+        &mut app,
+        GoBoardConfig {
+            board_size: BoardSize::Nine,
+            ..default()
+        },
+    );
+    let player = ComputerPlayer::spawn(board, StoneColor::White, backend).unwrap();
+    app.world_mut().spawn(player);
+    app.update();
+    (app, board)
+}
+
+#[test]
+fn engine_answers_on_its_turn() {
+    let (mut app, board) = headless_app();
+
+    place(&mut app, board, (4, 4), StoneColor::Black);
+    assert_eq!(turn(&app, board), StoneColor::White);
+    update_until(&mut app, |app| turn(app, board) == StoneColor::Black);
+    assert_eq!(move_count(&app, board), 2);
+
+    // 引擎的棋盘与我们的棋盘保持同步，继续对局也能应答
+    place(&mut app, board, (2, 2), StoneColor::Black);
+    update_until(&mut app, |app| {
+        turn(app, board) == StoneColor::Black && move_count(app, board) == 4
+    });
+}
+
+#[test]
+fn passes_are_replayed_to_the_engine() {
+    let log = std::env::temp_dir().join(format!("gtp_stub_{}.log", std::process::id()));
+    let stub = PlayerBackend::Gtp(
+        EngineCommand::new("sh")
+            .arg(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/tests/fixtures/gtp_stub.sh"
+            ))
+            .arg(log.to_str().unwrap()),
+    );
+    let (mut app, board) = headless_app_with(&stub);
+
+    // 黑棋虚手后引擎要先收到这手虚手再思考；桩也虚手，对局结束
+    pass(&mut app, board, StoneColor::Black);
+    update_until(&mut app, |app| app.world().get::<GameOver>(board).is_some());
+
+    let commands = std::fs::read_to_string(&log).unwrap();
+    let _ = std::fs::remove_file(&log);
+    let commands: Vec<_> = commands.lines().collect();
+    let pass = commands.iter().position(|line| *line == "play black pass");
+    let genmove = commands.iter().position(|line| *line == "genmove white");
+    assert!(pass.is_some() && pass < genmove, "{:?}", commands);
+}
+
+#[test]
+fn engine_resyncs_after_undo() {
+    let (mut app, board) = headless_app();
+
+    place(&mut app, board, (4, 4), StoneColor::Black);
+    update_until(&mut app, |app| move_count(app, board) == 2);
+
+    // 撤销引擎的一手后又轮到它，引擎需要重新同步后再下
+    app.world_mut().send_event(UndoMoveEvent { board });
+    app.update();
+    assert_eq!(move_count(&app, board), 1);
+    update_until(&mut app, |app| move_count(app, board) == 2);
+    assert_eq!(turn(&app, board), StoneColor::Black);
+}
+
+#[test]
+fn resignation_ends_the_game() {
+    let (mut app, board) = headless_app();
+
+    app.world_mut().send_event(StoneActionEvent {
+        board,
+        action_type: StoneActionType::Resign,
+        position: None,
+        color: StoneColor::Black,
+    });
+    app.update();
+
+    assert!(app.world().get::<GameOver>(board).is_some());
+    let events = app.world().resource::<Events<GameEndEvent>>();
+    let winner = events.iter_current_update_events().next().unwrap().winner;
+    assert_eq!(winner, Some(StoneColor::White));
+}
+
+#[test]
+fn mcts_bot_answers_on_its_turn() {
+    let (mut app, board) = headless_app_with(&PlayerBackend::Mcts(MctsConfig {
+        budget: SearchBudget::Playouts(200),
+        seed: Some(7),
+        ..default()
+    }));
+
+    place(&mut app, board, (4, 4), StoneColor::Black);
+    assert_eq!(turn(&app, board), StoneColor::White);
+    update_until(&mut app, |app| turn(app, board) == StoneColor::Black);
+    assert_eq!(move_count(&app, board), 2);
+}
@@ -0,0 +1,18 @@
+#!/bin/sh
+# 分析引擎桩：对每个查询回放同一份录制好的结果，只替换其中的 id
+# Analysis engine stub: replays a canned response for every query, substituting the id
+
+response="$(dirname "$0")/analysis_response.json"
+
+while IFS= read -r line; do
+    id=$(printf '%s\n' "$line" | sed -n 's/.*"id":"\([^"]*\)".*/\1/p')
+    case "$line" in
+        *'"action":"terminate"'*)
+            printf '{"action":"terminate","id":"%s","turnNumbers":[]}\n' "$id"
+            ;;
+        *)
+            sed "s/@ID@/$id/" "$response" | tr -d '\n'
+            echo
+            ;;
+    esac
+done
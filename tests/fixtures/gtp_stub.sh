@@ -0,0 +1,15 @@
+#!/bin/sh
+# GTP 引擎桩：把收到的命令逐行记到第一个参数指定的文件，genmove 总是虚手
+# GTP engine stub: logs every command to the file given as the first argument and always passes on genmove
+
+log="$1"
+: > "$log"
+
+while IFS= read -r line; do
+    printf '%s\n' "$line" >> "$log"
+    case "$line" in
+        genmove*) printf '= pass\n\n' ;;
+        quit*) printf '=\n\n'; exit 0 ;;
+        *) printf '=\n\n' ;;
+    esac
+done
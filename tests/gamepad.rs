@@ -0,0 +1,204 @@
+// 手柄下棋测试：直接发送手柄连接和按键事件，不需要真实设备
+// Gamepad play tests: feed connection and button events directly, no device needed
+
+mod common;
+
+use bevy::input::InputPlugin;
+use bevy::input::gamepad::{
+    GamepadConnection, GamepadConnectionEvent, RawGamepadButtonChangedEvent, RawGamepadEvent,
+};
+use bevy::prelude::*;
+use black_white_legends::go_board_component::{GamepadPlayPlugin, KeyboardPlayPlugin};
+use black_white_legends::prelude::*;
+use common::stone;
+
+fn headless_app() -> (App, Entity) {
+    let mut app = common::headless_app((InputPlugin, KeyboardPlayPlugin, GamepadPlayPlugin));
+    let board = common::spawn_board(
+        &mut app,
+        GoBoardConfig {
+            board_size: BoardSize::Nine,
+            keyboard_cursor: true,
+            ..default()
+        },
+    );
+    (app, board)
+}
+
+fn connect(app: &mut App) -> Entity {
+    let gamepad = app.world_mut().spawn_empty().id();
+    reconnect(app, gamepad);
+    gamepad
+}
+
+/// 已有的手柄实体重新连接
+fn reconnect(app: &mut App, gamepad: Entity) {
+    app.world_mut().send_event(GamepadConnectionEvent::new(
+        gamepad,
+        GamepadConnection::Connected {
+            name: "Test Pad".to_string(),
+            vendor_id: None,
+            product_id: None,
+        },
+    ));
+    app.update();
+    app.update();
+}
+
+fn disconnect(app: &mut App, gamepad: Entity) {
+    app.world_mut().send_event(GamepadConnectionEvent::new(
+        gamepad,
+        GamepadConnection::Disconnected,
+    ));
+    app.update();
+}
+
+fn tap(app: &mut App, gamepad: Entity, button: GamepadButton) {
+    for value in [1.0, 0.0] {
+        app.world_mut()
+            .send_event(RawGamepadEvent::Button(RawGamepadButtonChangedEvent::new(
+                gamepad, button, value,
+            )));
+        app.update();
+    }
+}
+
+fn cursor(app: &App, board: Entity) -> (i32, i32) {
+    app.world().get::<BoardCursor>(board).unwrap().position
+}
+
+#[test]
+fn connected_gamepads_take_black_then_white() {
+    let (mut app, board) = headless_app();
+    let first = connect(&mut app);
+    let second = connect(&mut app);
+
+    let seat = |app: &App, gamepad| *app.world().get::<GamepadPlayer>(gamepad).unwrap();
+    assert_eq!(
+        seat(&app, first),
+        GamepadPlayer {
+            board,
+            color: Some(StoneColor::Black)
+        }
+    );
+    assert_eq!(seat(&app, second).color, Some(StoneColor::White));
+
+    // 断开后保留座位，新手柄不会占用，黑白都有人时不固定颜色
+    disconnect(&mut app, first);
+    let third = connect(&mut app);
+    assert_eq!(seat(&app, first).color, Some(StoneColor::Black));
+    assert_eq!(seat(&app, third).color, None);
+
+    // 另一块棋盘不会分走之后连接的手柄
+    common::spawn_board(
+        &mut app,
+        GoBoardConfig {
+            keyboard_cursor: true,
+            ..default()
+        },
+    );
+    let fourth = connect(&mut app);
+    assert_eq!(seat(&app, fourth), GamepadPlayer { board, color: None });
+}
+
+#[test]
+fn dpad_moves_cursor_and_buttons_play() {
+    let (mut app, board) = headless_app();
+    let gamepad = connect(&mut app);
+    assert_eq!(cursor(&app, board), (4, 4));
+
+    tap(&mut app, gamepad, GamepadButton::DPadUp);
+    tap(&mut app, gamepad, GamepadButton::DPadLeft);
+    assert_eq!(cursor(&app, board), (3, 3));
+
+    tap(&mut app, gamepad, GamepadButton::South);
+    assert_eq!(stone(&app, board, (3, 3)), Some(StoneColor::Black));
+
+    // 对方没有手柄时可以替对方落子
+    tap(&mut app, gamepad, GamepadButton::DPadRight);
+    tap(&mut app, gamepad, GamepadButton::South);
+    assert_eq!(stone(&app, board, (4, 3)), Some(StoneColor::White));
+
+    tap(&mut app, gamepad, GamepadButton::West);
+    assert_eq!(stone(&app, board, (4, 3)), None);
+    tap(&mut app, gamepad, GamepadButton::RightTrigger);
+    assert_eq!(stone(&app, board, (4, 3)), Some(StoneColor::White));
+}
+
+#[test]
+fn seated_gamepad_waits_for_its_turn() {
+    let (mut app, board) = headless_app();
+    let black = connect(&mut app);
+    let white = connect(&mut app);
+
+    tap(&mut app, white, GamepadButton::South);
+    assert_eq!(stone(&app, board, (4, 4)), None);
+
+    tap(&mut app, black, GamepadButton::South);
+    assert_eq!(stone(&app, board, (4, 4)), Some(StoneColor::Black));
+
+    tap(&mut app, black, GamepadButton::DPadDown);
+    tap(&mut app, black, GamepadButton::South);
+    assert_eq!(stone(&app, board, (4, 5)), None);
+    tap(&mut app, white, GamepadButton::South);
+    assert_eq!(stone(&app, board, (4, 5)), Some(StoneColor::White));
+}
+
+#[test]
+fn disconnected_gamepad_frees_its_turn_until_it_reconnects() {
+    let (mut app, board) = headless_app();
+    let black = connect(&mut app);
+    let white = connect(&mut app);
+
+    // 执黑的手柄断开后，执白的手柄可以替黑棋落子，断开的手柄不再操作
+    disconnect(&mut app, black);
+    tap(&mut app, black, GamepadButton::South);
+    assert_eq!(stone(&app, board, (4, 4)), None);
+    tap(&mut app, white, GamepadButton::South);
+    assert_eq!(stone(&app, board, (4, 4)), Some(StoneColor::Black));
+
+    // 重新连接后回到原来的座位，执白的手柄又只能下白棋
+    tap(&mut app, white, GamepadButton::DPadDown);
+    tap(&mut app, white, GamepadButton::South);
+    assert_eq!(stone(&app, board, (4, 5)), Some(StoneColor::White));
+    reconnect(&mut app, black);
+    assert_eq!(
+        app.world().get::<GamepadPlayer>(black).unwrap().color,
+        Some(StoneColor::Black)
+    );
+    tap(&mut app, white, GamepadButton::DPadDown);
+    tap(&mut app, white, GamepadButton::South);
+    assert_eq!(stone(&app, board, (4, 6)), None);
+    tap(&mut app, black, GamepadButton::South);
+    assert_eq!(stone(&app, board, (4, 6)), Some(StoneColor::Black));
+}
+
+#[test]
+fn reassigned_seats_take_effect_immediately() {
+    let (mut app, board) = headless_app();
+    let first = connect(&mut app);
+    let second = connect(&mut app);
+
+    // 交换两个手柄执的颜色
+    for (gamepad, color) in [(first, StoneColor::White), (second, StoneColor::Black)] {
+        app.world_mut().entity_mut(gamepad).insert(GamepadPlayer {
+            board,
+            color: Some(color),
+        });
+    }
+    tap(&mut app, first, GamepadButton::South);
+    assert_eq!(stone(&app, board, (4, 4)), None);
+    tap(&mut app, second, GamepadButton::South);
+    assert_eq!(stone(&app, board, (4, 4)), Some(StoneColor::Black));
+
+    // 改成不固定颜色后替轮到的一方落子
+    app.world_mut()
+        .entity_mut(first)
+        .insert(GamepadPlayer { board, color: None });
+    tap(&mut app, first, GamepadButton::DPadRight);
+    tap(&mut app, first, GamepadButton::South);
+    assert_eq!(stone(&app, board, (5, 4)), Some(StoneColor::White));
+    tap(&mut app, first, GamepadButton::DPadRight);
+    tap(&mut app, first, GamepadButton::South);
+    assert_eq!(stone(&app, board, (6, 4)), Some(StoneColor::Black));
+}
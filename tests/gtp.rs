@@ -0,0 +1,259 @@
+// GTP 引擎命令处理测试，只依赖 go_core
+// GTP engine command handling tests, go_core only
+
+use black_white_legends::go_core::{BoardSize, GtpEngine, RandomMoveGenerator, StoneColor};
+
+fn engine() -> GtpEngine {
+    GtpEngine::new(Box::new(RandomMoveGenerator::new(7)))
+}
+
+/// 发送一行命令，返回完整响应文本
+fn reply(engine: &mut GtpEngine, line: &str) -> String {
+    engine.handle_line(line).expect("no response").text
+}
+
+#[test]
+fn numeric_ids_are_echoed() {
+    let mut engine = engine();
+
+    assert_eq!(reply(&mut engine, "1 protocol_version"), "=1 2\n\n");
+    assert_eq!(reply(&mut engine, "42 boardsize 9"), "=42\n\n");
+    assert_eq!(
+        reply(&mut engine, "7 boardsize 8"),
+        "?7 unacceptable size\n\n"
+    );
+    // 只有 id 没有命令也要回复，否则控制端会一直等待
+    assert_eq!(reply(&mut engine, "5"), "?5 unknown command\n\n");
+    assert_eq!(reply(&mut engine, "5 # comment"), "?5 unknown command\n\n");
+
+    // 空行和注释没有响应
+    assert!(engine.handle_line("").is_none());
+    assert!(engine.handle_line("   # comment").is_none());
+}
+
+#[test]
+fn unknown_commands_are_errors() {
+    let mut engine = engine();
+
+    assert_eq!(reply(&mut engine, "frobnicate"), "? unknown command\n\n");
+    assert_eq!(reply(&mut engine, "3 frobnicate"), "?3 unknown command\n\n");
+    assert!(!engine.handle_line("frobnicate").unwrap().quit);
+    assert!(engine.handle_line("quit").unwrap().quit);
+}
+
+#[test]
+fn known_command_and_list_commands() {
+    let mut engine = engine();
+
+    assert_eq!(reply(&mut engine, "known_command play"), "= true\n\n");
+    assert_eq!(
+        reply(&mut engine, "known_command frobnicate"),
+        "= false\n\n"
+    );
+    assert_eq!(reply(&mut engine, "known_command"), "= false\n\n");
+
+    let listed = reply(&mut engine, "list_commands");
+    let commands: Vec<&str> = listed.trim_start_matches("= ").trim_end().lines().collect();
+    for command in ["play", "genmove", "undo", "final_score", "fixed_handicap"] {
+        assert!(commands.contains(&command), "{:?}", commands);
+        assert_eq!(
+            reply(&mut engine, &format!("known_command {}", command)),
+            "= true\n\n"
+        );
+    }
+}
+
+#[test]
+fn illegal_plays_leave_the_board_unchanged() {
+    let mut engine = engine();
+    reply(&mut engine, "boardsize 9");
+
+    assert_eq!(reply(&mut engine, "play b E5"), "=\n\n");
+    assert_eq!(
+        engine.board_state().get_stone(4, 4),
+        Some(StoneColor::Black)
+    );
+
+    // 已有棋子的点
+    let occupied = reply(&mut engine, "play w E5");
+    assert!(occupied.starts_with("? illegal move"), "{}", occupied);
+    assert_eq!(
+        engine.board_state().get_stone(4, 4),
+        Some(StoneColor::Black)
+    );
+
+    // 无效的坐标和颜色
+    for line in [
+        "play w I5",
+        "play w Z1",
+        "play w A0",
+        "play w A10",
+        "play w A+5",
+        "play w A05",
+        "play w +5",
+        "play w E",
+    ] {
+        assert_eq!(
+            reply(&mut engine, line),
+            "? invalid coordinate\n\n",
+            "{}",
+            line
+        );
+    }
+    assert_eq!(reply(&mut engine, "play red E4"), "? invalid color\n\n");
+    assert_eq!(
+        reply(&mut engine, "play b"),
+        "? invalid color or coordinate\n\n"
+    );
+    assert_eq!(engine.board_state().move_count, 1);
+
+    // 非法着法不进入撤销栈，撤销一次就回到空棋盘
+    assert_eq!(reply(&mut engine, "undo"), "=\n\n");
+    assert_eq!(engine.board_state().get_stone(4, 4), None);
+}
+
+#[test]
+fn superko_rejects_repeated_positions() {
+    // 白提劫后双方虚手，打劫点已清除，黑提回会重复白提劫之前的局面
+    let ko_fight = [
+        "play b E5",
+        "play w D5",
+        "play b G5",
+        "play w E6",
+        "play b F6",
+        "play w E4",
+        "play b F4",
+        "play w F5",
+        "play b pass",
+        "play w pass",
+    ];
+
+    let play_ko_fight = |engine: &mut GtpEngine| {
+        reply(engine, "boardsize 9");
+        for line in ko_fight {
+            assert_eq!(reply(engine, line), "=\n\n", "{}", line);
+        }
+    };
+
+    let mut relaxed = engine();
+    play_ko_fight(&mut relaxed);
+    assert_eq!(reply(&mut relaxed, "play b E5"), "=\n\n");
+
+    let mut engine = engine().with_superko_rule(true);
+    play_ko_fight(&mut engine);
+    assert_eq!(
+        reply(&mut engine, "play b E5"),
+        "? illegal move (superko)\n\n"
+    );
+    assert_eq!(engine.board_state().get_stone(4, 4), None);
+
+    // 撤销后历史也随之回退，白方虚手之前提回不算重复
+    assert_eq!(reply(&mut engine, "undo"), "=\n\n");
+    assert_eq!(reply(&mut engine, "undo"), "=\n\n");
+    assert_eq!(reply(&mut engine, "play b E5"), "? illegal move (ko)\n\n");
+}
+
+#[test]
+fn undo_on_an_empty_stack_fails() {
+    let mut engine = engine();
+
+    assert_eq!(reply(&mut engine, "undo"), "? cannot undo\n\n");
+    reply(&mut engine, "play b D4");
+    reply(&mut engine, "play w pass");
+    assert_eq!(reply(&mut engine, "undo"), "=\n\n");
+    assert_eq!(reply(&mut engine, "undo"), "=\n\n");
+    assert_eq!(reply(&mut engine, "undo"), "? cannot undo\n\n");
+
+    // 清空棋盘后也不能撤销之前的着法
+    reply(&mut engine, "play b D4");
+    reply(&mut engine, "clear_board");
+    assert_eq!(reply(&mut engine, "undo"), "? cannot undo\n\n");
+}
+
+#[test]
+fn fixed_handicap_follows_the_gtp_order() {
+    let mut engine = engine();
+
+    // GTP v2 规范 4.1.1 节给出的 19 路顺序
+    let expected = [
+        (2, "D4 Q16"),
+        (3, "D4 Q16 D16"),
+        (4, "D4 Q16 D16 Q4"),
+        (5, "D4 Q16 D16 Q4 K10"),
+        (6, "D4 Q16 D16 Q4 D10 Q10"),
+        (7, "D4 Q16 D16 Q4 D10 Q10 K10"),
+        (8, "D4 Q16 D16 Q4 D10 Q10 K4 K16"),
+        (9, "D4 Q16 D16 Q4 D10 Q10 K4 K16 K10"),
+    ];
+    for (stones, vertices) in expected {
+        reply(&mut engine, "clear_board");
+        assert_eq!(
+            reply(&mut engine, &format!("fixed_handicap {}", stones)),
+            format!("= {}\n\n", vertices)
+        );
+        let placed = engine
+            .board_state()
+            .stones
+            .iter()
+            .flatten()
+            .filter(|stone| **stone == Some(StoneColor::Black))
+            .count();
+        assert_eq!(placed, stones);
+    }
+
+    // 小棋盘用三三和四四之间的位置
+    reply(&mut engine, "boardsize 9");
+    assert_eq!(
+        reply(&mut engine, "fixed_handicap 9"),
+        "= C3 G7 C7 G3 C5 G5 E3 E7 E5\n\n"
+    );
+    reply(&mut engine, "boardsize 13");
+    assert_eq!(reply(&mut engine, "fixed_handicap 3"), "= D4 K10 D10\n\n");
+}
+
+#[test]
+fn fixed_handicap_limits() {
+    let mut engine = engine();
+
+    for size in [BoardSize::Nine, BoardSize::Thirteen, BoardSize::Nineteen] {
+        reply(&mut engine, &format!("boardsize {}", size.get_value()));
+        for stones in ["0", "1", "10"] {
+            assert_eq!(
+                reply(&mut engine, &format!("fixed_handicap {}", stones)),
+                "? invalid number of stones\n\n"
+            );
+        }
+        assert_eq!(
+            reply(&mut engine, "fixed_handicap two"),
+            "? handicap not an integer\n\n"
+        );
+    }
+
+    // 棋盘上已有棋子时不能摆让子
+    reply(&mut engine, "play b E5");
+    assert_eq!(
+        reply(&mut engine, "fixed_handicap 2"),
+        "? board not empty\n\n"
+    );
+}
+
+#[test]
+fn final_score_uses_area_scoring_and_komi() {
+    let mut engine = engine();
+    reply(&mut engine, "boardsize 9");
+    reply(&mut engine, "komi 6.5");
+
+    // 黑棋一道墙占左边五列，白棋一道墙占右边三列
+    for y in 1..=9 {
+        reply(&mut engine, &format!("play b E{}", y));
+        reply(&mut engine, &format!("play w F{}", y));
+    }
+    // 黑 45 子地，白 36，贴 6.5
+    assert_eq!(reply(&mut engine, "final_score"), "= B+2.5\n\n");
+
+    reply(&mut engine, "komi 9");
+    assert_eq!(reply(&mut engine, "final_score"), "= 0\n\n");
+    reply(&mut engine, "komi 10");
+    assert_eq!(reply(&mut engine, "final_score"), "= W+1\n\n");
+    assert_eq!(reply(&mut engine, "komi many"), "? komi not a float\n\n");
+}
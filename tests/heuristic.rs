@@ -0,0 +1,167 @@
+// 入门电脑棋手测试，只依赖 go_core
+// Beginner bot tests, go_core only
+
+use black_white_legends::go_core::{
+    BoardSize, BoardState, BotLevel, GameHistory, GeneratedMove, GoBoardRules, HeuristicBot,
+    IllegalMove, MoveGenerator, StoneColor,
+};
+
+fn board_with(stones: &[((i32, i32), StoneColor)]) -> BoardState {
+    let mut board_state = BoardState::new(BoardSize::Nine);
+    for &((x, y), color) in stones {
+        GoBoardRules::play_move(&mut board_state, x, y, color).unwrap();
+    }
+    board_state
+}
+
+/// 两个棋手下完一局，返回所有着法
+fn play_game(black: &mut dyn MoveGenerator, white: &mut dyn MoveGenerator) -> Vec<GeneratedMove> {
+    let mut board_state = BoardState::new(BoardSize::Nine);
+    let mut history = GameHistory::default();
+    let mut color = StoneColor::Black;
+    let mut moves = Vec::new();
+
+    while board_state.consecutive_passes < 2 && moves.len() < 300 {
+        let generated = match color {
+            StoneColor::Black => black.generate_move(&board_state, &history, color, 7.5),
+            StoneColor::White => white.generate_move(&board_state, &history, color, 7.5),
+        };
+        match generated {
+            GeneratedMove::Play(x, y) => {
+                let captured = GoBoardRules::play_move(&mut board_state, x, y, color).unwrap();
+                history.record_after(&board_state, Some((x, y)), color, captured);
+            }
+            GeneratedMove::Pass => {
+                GoBoardRules::pass(&mut board_state);
+                history.record_after(&board_state, None, color, Vec::new());
+            }
+            GeneratedMove::Resign => break,
+        }
+        moves.push(generated);
+        color = color.opposite();
+    }
+    moves
+}
+
+#[test]
+fn level_names_round_trip() {
+    for level in BotLevel::ALL {
+        assert_eq!(BotLevel::from_name(level.name()), Some(level));
+    }
+    assert_eq!(BotLevel::from_name("dan"), None);
+}
+
+#[test]
+fn greedy_capture_takes_the_bigger_group() {
+    // 左边白一子、右边白两子都只剩一口气
+    let board_state = board_with(&[
+        ((1, 1), StoneColor::White),
+        ((0, 1), StoneColor::Black),
+        ((1, 0), StoneColor::Black),
+        ((2, 1), StoneColor::Black),
+        ((6, 4), StoneColor::White),
+        ((7, 4), StoneColor::White),
+        ((5, 4), StoneColor::Black),
+        ((8, 4), StoneColor::Black),
+        ((6, 3), StoneColor::Black),
+        ((7, 3), StoneColor::Black),
+        ((6, 5), StoneColor::Black),
+    ]);
+
+    let mut bot = HeuristicBot::new(BotLevel::GreedyCapture, 1);
+    let generated = bot.generate_move(
+        &board_state,
+        &GameHistory::default(),
+        StoneColor::Black,
+        7.5,
+    );
+    assert_eq!(generated, GeneratedMove::Play(7, 5));
+}
+
+#[test]
+fn atari_aware_escapes_atari() {
+    // 白两子只剩 (4, 5) 一口气，长出后有三口气
+    let board_state = board_with(&[
+        ((3, 4), StoneColor::White),
+        ((4, 4), StoneColor::White),
+        ((2, 4), StoneColor::Black),
+        ((5, 4), StoneColor::Black),
+        ((3, 3), StoneColor::Black),
+        ((4, 3), StoneColor::Black),
+        ((3, 5), StoneColor::Black),
+    ]);
+
+    let mut bot = HeuristicBot::new(BotLevel::AtariAware, 1);
+    let generated = bot.generate_move(
+        &board_state,
+        &GameHistory::default(),
+        StoneColor::White,
+        7.5,
+    );
+    assert_eq!(generated, GeneratedMove::Play(4, 5));
+}
+
+#[test]
+fn same_seed_replays_the_same_game() {
+    for level in BotLevel::ALL {
+        let first = play_game(
+            &mut HeuristicBot::new(level, 3),
+            &mut HeuristicBot::new(level, 4),
+        );
+        let second = play_game(
+            &mut HeuristicBot::new(level, 3),
+            &mut HeuristicBot::new(level, 4),
+        );
+        assert_eq!(first, second, "{:?} is not deterministic", level);
+    }
+}
+
+#[test]
+fn bots_avoid_repeating_earlier_positions() {
+    // 白提劫后双方虚手，黑提回能吃一子，但会重复白提劫之前的局面
+    let mut board_state = BoardState::new(BoardSize::Nine);
+    let mut history = GameHistory::default();
+    let mut color = StoneColor::Black;
+    for position in [
+        Some((4, 4)),
+        Some((3, 4)),
+        Some((6, 4)),
+        Some((4, 3)),
+        Some((5, 3)),
+        Some((4, 5)),
+        Some((5, 5)),
+        Some((5, 4)),
+        None,
+        None,
+    ] {
+        let captured = match position {
+            Some((x, y)) => GoBoardRules::play_move(&mut board_state, x, y, color).unwrap(),
+            None => {
+                GoBoardRules::pass(&mut board_state);
+                Vec::new()
+            }
+        };
+        history.record_after(&board_state, position, color, captured);
+        color = color.opposite();
+    }
+
+    assert_eq!(
+        GoBoardRules::check_move_with_history(&board_state, &history, 4, 4, StoneColor::Black),
+        Err(IllegalMove::Superko)
+    );
+    assert!(
+        !GoBoardRules::legal_moves_with_history(&board_state, &history, StoneColor::Black)
+            .contains(&(4, 4))
+    );
+
+    let mut bot = HeuristicBot::new(BotLevel::GreedyCapture, 1);
+    let generated = bot.generate_move(
+        &board_state,
+        &GameHistory::default(),
+        StoneColor::Black,
+        7.5,
+    );
+    assert_eq!(generated, GeneratedMove::Play(4, 4));
+    let generated = bot.generate_move(&board_state, &history, StoneColor::Black, 7.5);
+    assert_ne!(generated, GeneratedMove::Play(4, 4));
+}
@@ -0,0 +1,139 @@
+// 悬停提示测试：直接发送 bevy_picking 的指针事件，检查 BoardHover 和悬停指示器
+// Hover tests: feed bevy_picking pointer events directly and check BoardHover and the hover indicator
+
+mod common;
+
+use bevy::prelude::*;
+use black_white_legends::go_board_component::components::{HoverCross, HoverTooltip};
+use black_white_legends::go_board_component::hover::{
+    spawn_hover_indicator, sync_hover_indicator, update_board_hover,
+};
+use black_white_legends::go_board_component::picking::pick_board_intersections;
+use black_white_legends::go_board_component::{BoardHover, GoBoardSystems};
+use black_white_legends::go_core::IllegalMove;
+use black_white_legends::prelude::*;
+use common::{hit, play, send_pointer};
+
+fn headless_app(config: GoBoardConfig) -> (App, Entity) {
+    let mut app = common::render_app();
+    common::add_pointer_events(&mut app)
+        .init_resource::<BoardHover>()
+        .add_systems(Startup, spawn_hover_indicator)
+        .add_systems(
+            Update,
+            (
+                pick_board_intersections.before(GoBoardSystems::Logic),
+                (update_board_hover, sync_hover_indicator)
+                    .chain()
+                    .in_set(GoBoardSystems::Render),
+            ),
+        );
+
+    let board = common::spawn_board(&mut app, config);
+    (app, board)
+}
+
+/// 让鼠标指针悬停在某个交叉点上
+fn hover(app: &mut App, board: Entity, position: (i32, i32)) {
+    let hit = hit(app, board, position);
+    send_pointer(app, board, Over { hit });
+    app.update();
+}
+
+/// 红叉是否显示，以及提示文字
+fn indicator(app: &mut App) -> (bool, String) {
+    let world = app.world_mut();
+    let cross = *world
+        .query_filtered::<&Visibility, With<HoverCross>>()
+        .single(world)
+        .unwrap();
+    let tooltip = world
+        .query_filtered::<&Text2d, With<HoverTooltip>>()
+        .single(world)
+        .unwrap()
+        .0
+        .clone();
+    (cross != Visibility::Hidden, tooltip)
+}
+
+/// 中腹的劫：白棋 (5,4) 刚提掉黑棋 (4,4)，轮到黑棋
+const KO_FIGHT: [(i32, i32); 8] = [
+    (4, 4),
+    (3, 4),
+    (6, 4),
+    (4, 3),
+    (5, 3),
+    (4, 5),
+    (5, 5),
+    (5, 4),
+];
+
+fn superko_config(enable_superko_rule: bool) -> GoBoardConfig {
+    GoBoardConfig {
+        board_size: BoardSize::Nine,
+        enable_ko_rule: false,
+        enable_superko_rule,
+        ..default()
+    }
+}
+
+#[test]
+fn hover_reports_superko() {
+    let (mut app, board) = headless_app(superko_config(true));
+    play(&mut app, board, &KO_FIGHT);
+    assert_eq!(
+        app.world()
+            .get::<BoardState>(board)
+            .unwrap()
+            .get_stone(4, 4),
+        None
+    );
+
+    // 提回会重复白棋提劫之前的局面
+    hover(&mut app, board, (4, 4));
+    let point = app.world().resource::<BoardHover>().0.unwrap();
+    assert_eq!(point.position, (4, 4));
+    assert_eq!(point.color, StoneColor::Black);
+    assert_eq!(point.illegal, Some(IllegalMove::Superko));
+    assert_eq!(indicator(&mut app), (true, "E5: superko".to_string()));
+
+    // 落子同样被拒绝
+    play(&mut app, board, &[(4, 4)]);
+    let board_state = app.world().get::<BoardState>(board).unwrap();
+    assert_eq!(board_state.get_stone(4, 4), None);
+    assert_eq!(board_state.get_stone(5, 4), Some(StoneColor::White));
+
+    // 别处的空点照常可下
+    hover(&mut app, board, (0, 0));
+    let point = app.world().resource::<BoardHover>().0.unwrap();
+    assert_eq!(point.illegal, None);
+    assert_eq!(indicator(&mut app), (false, "A9".to_string()));
+}
+
+#[test]
+fn superko_follows_the_rule_config() {
+    let (mut app, board) = headless_app(superko_config(false));
+    play(&mut app, board, &KO_FIGHT);
+
+    // 打劫和全局同形都关闭时可以立即提回
+    hover(&mut app, board, (4, 4));
+    let point = app.world().resource::<BoardHover>().0.unwrap();
+    assert_eq!(point.illegal, None);
+    assert_eq!(indicator(&mut app), (false, "E5".to_string()));
+
+    play(&mut app, board, &[(4, 4)]);
+    let board_state = app.world().get::<BoardState>(board).unwrap();
+    assert_eq!(board_state.get_stone(4, 4), Some(StoneColor::Black));
+    assert_eq!(board_state.get_stone(5, 4), None);
+
+    // 只开打劫规则时提示为普通的劫
+    let (mut app, board) = headless_app(GoBoardConfig {
+        enable_ko_rule: true,
+        ..superko_config(true)
+    });
+    play(&mut app, board, &KO_FIGHT);
+    hover(&mut app, board, (4, 4));
+    let point = app.world().resource::<BoardHover>().0.unwrap();
+    assert_eq!(point.illegal, Some(IllegalMove::Ko));
+    assert_eq!(indicator(&mut app), (true, "E5: ko".to_string()));
+}
@@ -0,0 +1,70 @@
+// 影响图和形势判断测试，只依赖 go_core
+// Influence map and score estimate tests, go_core only
+
+use black_white_legends::go_core::{BoardSize, BoardState, GoBoardRules, StoneColor};
+
+fn board_with(black: &[(i32, i32)], white: &[(i32, i32)]) -> BoardState {
+    let mut board_state = BoardState::new(BoardSize::Nine);
+    for &(x, y) in black {
+        board_state.place_stone(x, y, StoneColor::Black);
+    }
+    for &(x, y) in white {
+        board_state.place_stone(x, y, StoneColor::White);
+    }
+    board_state
+}
+
+/// 黑棋第 4 列、白棋第 5 列各一道墙，白棋 (1,1) 落在黑地里
+fn divided_board() -> BoardState {
+    let black: Vec<(i32, i32)> = (0..9).map(|y| (4, y)).collect();
+    let mut white: Vec<(i32, i32)> = (0..9).map(|y| (5, y)).collect();
+    white.push((1, 1));
+    board_with(&black, &white)
+}
+
+#[test]
+fn walls_claim_their_side() {
+    let board_state = divided_board();
+    // 按 y * size + x 排列：(3,6) 靠近黑墙，(8,8) 在白地里
+    let influence = GoBoardRules::influence_map(&board_state);
+    assert!(influence[6 * 9 + 3] > 0);
+    assert!(influence[8 * 9 + 8] < 0);
+
+    let estimate = GoBoardRules::estimate_score(&board_state, 7.5);
+    assert_eq!((estimate.black_area, estimate.white_area), (45, 36));
+    assert_eq!(estimate.margin, 1.5);
+    assert_eq!(estimate.leader(), Some(StoneColor::Black));
+    // 黑地里的白子按死子算作黑方
+    assert_eq!(estimate.ownership_at(1, 1), Some(1.0));
+    assert_eq!(estimate.ownership_at(8, 8), Some(-1.0));
+    assert_eq!(estimate.ownership_at(9, 0), None);
+}
+
+#[test]
+fn symmetric_positions_are_even_before_komi() {
+    let board_state = board_with(&[(2, 2), (2, 6)], &[(6, 6), (6, 2)]);
+    let estimate = GoBoardRules::estimate_score(&board_state, 0.0);
+
+    assert_eq!(estimate.black_area, estimate.white_area);
+    assert_eq!(estimate.leader(), None);
+    for x in 0..9 {
+        for y in 0..9 {
+            assert_eq!(
+                estimate.ownership_at(x, y),
+                estimate.ownership_at(8 - x, y).map(|owner| -owner)
+            );
+        }
+    }
+    assert_eq!(estimate.ownership_at(2, 2), Some(1.0));
+
+    let estimate = GoBoardRules::estimate_score(&board_state, 6.5);
+    assert_eq!(estimate.margin, -6.5);
+    assert_eq!(estimate.leader(), Some(StoneColor::White));
+}
+
+#[test]
+fn empty_board_is_neutral() {
+    let estimate = GoBoardRules::estimate_score(&BoardState::new(BoardSize::Nine), 0.0);
+    assert!(estimate.ownership.iter().all(|&owner| owner == 0.0));
+    assert_eq!(estimate.ownership.len(), 81);
+}
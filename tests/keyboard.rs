@@ -0,0 +1,144 @@
+// 键盘下棋测试：直接发送键盘事件，不需要窗口
+// Keyboard play tests: feed keyboard events directly, no window needed
+
+mod common;
+
+use bevy::input::ButtonState;
+use bevy::input::InputPlugin;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::prelude::*;
+use black_white_legends::go_board_component::KeyboardPlayPlugin;
+use black_white_legends::prelude::*;
+use common::{stone, turn};
+
+fn headless_app() -> (App, Entity) {
+    let mut app = common::headless_app((InputPlugin, KeyboardPlayPlugin));
+    let board = common::spawn_board(
+        &mut app,
+        GoBoardConfig {
+            board_size: BoardSize::Nine,
+            keyboard_cursor: true,
+            ..default()
+        },
+    );
+    (app, board)
+}
+
+fn press(app: &mut App, key_code: KeyCode, logical_key: Key) {
+    app.world_mut().send_event(KeyboardInput {
+        key_code,
+        logical_key,
+        state: ButtonState::Pressed,
+        text: None,
+        repeat: false,
+        window: Entity::PLACEHOLDER,
+    });
+    app.update();
+}
+
+fn type_text(app: &mut App, text: &str) {
+    for c in text.chars() {
+        press(app, KeyCode::KeyA, Key::Character(c.to_string().into()));
+    }
+}
+
+fn cursor(app: &App, board: Entity) -> BoardCursor {
+    app.world().get::<BoardCursor>(board).unwrap().clone()
+}
+
+#[test]
+fn cursor_moves_and_places_stones() {
+    let (mut app, board) = headless_app();
+    assert_eq!(cursor(&app, board).position, (4, 4));
+
+    press(&mut app, KeyCode::ArrowUp, Key::ArrowUp);
+    press(&mut app, KeyCode::KeyH, Key::Character("h".into()));
+    assert_eq!(cursor(&app, board).position, (3, 3));
+
+    press(&mut app, KeyCode::Enter, Key::Enter);
+    assert_eq!(stone(&app, board, (3, 3)), Some(StoneColor::Black));
+
+    // 光标停在棋盘边上
+    for _ in 0..12 {
+        press(&mut app, KeyCode::KeyL, Key::Character("l".into()));
+    }
+    assert_eq!(cursor(&app, board).position, (8, 3));
+
+    press(&mut app, KeyCode::Home, Key::Home);
+    assert_eq!(stone(&app, board, (3, 3)), None);
+    press(&mut app, KeyCode::End, Key::End);
+    assert_eq!(stone(&app, board, (3, 3)), Some(StoneColor::Black));
+}
+
+#[test]
+fn typed_coordinates_use_the_human_format() {
+    let (mut app, board) = headless_app();
+
+    press(&mut app, KeyCode::Slash, Key::Character("/".into()));
+    type_text(&mut app, "c7");
+    assert_eq!(cursor(&app, board).typed.as_deref(), Some("c7"));
+    // 输入期间字母不触发绑定的操作
+    assert_eq!(cursor(&app, board).position, (4, 4));
+
+    press(&mut app, KeyCode::Enter, Key::Enter);
+    assert_eq!(cursor(&app, board).typed, None);
+    assert_eq!(cursor(&app, board).position, (2, 2));
+    assert_eq!(stone(&app, board, (2, 2)), Some(StoneColor::Black));
+
+    // 无效的坐标不落子
+    press(&mut app, KeyCode::Slash, Key::Character("/".into()));
+    type_text(&mut app, "z9");
+    press(&mut app, KeyCode::Enter, Key::Enter);
+    assert_eq!(cursor(&app, board).position, (2, 2));
+    assert_eq!(turn(&app, board), StoneColor::White);
+}
+
+#[test]
+fn typed_rows_count_from_the_bottom() {
+    let (mut app, board) = headless_app();
+
+    // 与棋盘左侧的行号一致：A1 在左下角，A9 在左上角
+    for (text, position) in [("a1", (0, 8)), ("a9", (0, 0)), ("j1", (8, 8))] {
+        press(&mut app, KeyCode::Slash, Key::Character("/".into()));
+        type_text(&mut app, text);
+        press(&mut app, KeyCode::Enter, Key::Enter);
+        assert_eq!(cursor(&app, board).position, position, "{}", text);
+        assert!(stone(&app, board, position).is_some(), "{}", text);
+    }
+}
+
+#[test]
+fn bindings_can_be_remapped() {
+    let (mut app, board) = headless_app();
+    app.world_mut()
+        .resource_mut::<KeyBindings>()
+        .set(BoardAction::Pass, [KeyCode::KeyQ]);
+
+    press(&mut app, KeyCode::KeyP, Key::Character("p".into()));
+    assert_eq!(turn(&app, board), StoneColor::Black);
+
+    press(&mut app, KeyCode::KeyQ, Key::Character("q".into()));
+    assert_eq!(turn(&app, board), StoneColor::White);
+}
+
+#[test]
+fn malformed_typed_coordinates_are_rejected() {
+    let (mut app, board) = headless_app();
+
+    // I 列不存在，列超出 9 路棋盘，行号为 0、补零或带符号（符号输入时已被过滤）
+    for text in ["i5", "z1", "k1", "a0", "a05", "a10", "+5", "5a", "a"] {
+        press(&mut app, KeyCode::Slash, Key::Character("/".into()));
+        type_text(&mut app, text);
+        press(&mut app, KeyCode::Enter, Key::Enter);
+        assert_eq!(cursor(&app, board).typed, None, "{}", text);
+        assert_eq!(cursor(&app, board).position, (4, 4), "{}", text);
+    }
+    assert_eq!(common::move_count(&app, board), 0);
+    assert_eq!(turn(&app, board), StoneColor::Black);
+
+    // J 紧接 H，是第 9 列
+    press(&mut app, KeyCode::Slash, Key::Character("/".into()));
+    type_text(&mut app, "j9");
+    press(&mut app, KeyCode::Enter, Key::Enter);
+    assert_eq!(stone(&app, board, (8, 0)), Some(StoneColor::Black));
+}
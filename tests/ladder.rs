@@ -0,0 +1,99 @@
+// 征子和枷吃计算测试，只依赖 go_core
+// Ladder and net reader tests, go_core only
+
+use black_white_legends::go_core::{
+    BoardSize, BoardState, CaptureMethod, GoBoardRules, StoneColor, TacticalReader,
+};
+
+fn board_with(black: &[(i32, i32)], white: &[(i32, i32)]) -> BoardState {
+    let mut board_state = BoardState::new(BoardSize::Nineteen);
+    for &(x, y) in black {
+        board_state.place_stone(x, y, StoneColor::Black);
+    }
+    for &(x, y) in white {
+        board_state.place_stone(x, y, StoneColor::White);
+    }
+    board_state
+}
+
+/// 天元附近的白子被叫吃，黑棋斜下方有一子，可以征子
+const LADDER_BLACK: [(i32, i32); 3] = [(8, 9), (9, 8), (10, 10)];
+
+#[test]
+fn ladder_runs_to_the_edge() {
+    let board_state = board_with(&LADDER_BLACK, &[(9, 9)]);
+    let reading = TacticalReader::new()
+        .read(&board_state, (9, 9), StoneColor::Black)
+        .unwrap();
+    assert_eq!(reading.method, CaptureMethod::Ladder);
+
+    // 双方交替、每一手都合法，最后一手提掉目标
+    let mut board = board_state.clone();
+    for (index, &(color, (x, y))) in reading.moves.iter().enumerate() {
+        let expected = if index % 2 == 0 {
+            StoneColor::Black
+        } else {
+            StoneColor::White
+        };
+        assert_eq!(color, expected);
+        GoBoardRules::play_move(&mut board, x, y, color).unwrap();
+    }
+    assert_eq!(board.get_stone(9, 9), None);
+    assert!(reading.moves.len() > 20);
+
+    // 白棋先走也逃不掉
+    let mut in_atari = board_state;
+    GoBoardRules::play_move(&mut in_atari, 10, 9, StoneColor::Black).unwrap();
+    let reading = TacticalReader::new()
+        .read(&in_atari, (9, 9), StoneColor::White)
+        .unwrap();
+    assert_eq!(reading.moves[0], (StoneColor::White, (9, 10)));
+}
+
+#[test]
+fn breakers_on_both_diagonals_stop_the_ladder() {
+    let board_state = board_with(&LADDER_BLACK, &[(9, 9), (3, 14), (14, 3)]);
+    let reader = TacticalReader::new();
+    assert_eq!(reader.read(&board_state, (9, 9), StoneColor::Black), None);
+
+    let path = reader
+        .ladder_path(&board_state, (9, 9), StoneColor::Black)
+        .unwrap();
+    assert!(!path.captured);
+    assert!(path.moves.len() > 10);
+    assert!(matches!(path.breaker, Some((3, 14)) | Some((14, 3))));
+
+    // 只挡住一边时，黑棋从另一边叫吃
+    let board_state = board_with(&LADDER_BLACK, &[(9, 9), (3, 14)]);
+    let path = reader
+        .ladder_path(&board_state, (9, 9), StoneColor::Black)
+        .unwrap();
+    assert!(path.captured);
+    assert_eq!(path.breaker, None);
+}
+
+#[test]
+fn net_captures_what_the_ladder_cannot() {
+    let board_state = board_with(&[(8, 9), (9, 8), (7, 10), (10, 7)], &[(9, 9)]);
+    let reading = TacticalReader::new()
+        .read(&board_state, (9, 9), StoneColor::Black)
+        .unwrap();
+    assert_eq!(reading.method, CaptureMethod::Net);
+    assert_eq!(reading.moves[0], (StoneColor::Black, (10, 10)));
+
+    // 不允许枷吃时吃不掉
+    let reader = TacticalReader::new().with_net_depth(0);
+    assert_eq!(reader.read(&board_state, (9, 9), StoneColor::Black), None);
+}
+
+#[test]
+fn only_short_of_liberties_groups_are_read() {
+    let reader = TacticalReader::new();
+    let board_state = board_with(&[(8, 9)], &[(9, 9)]);
+    assert_eq!(reader.read(&board_state, (9, 9), StoneColor::Black), None);
+    assert_eq!(
+        reader.ladder_path(&board_state, (9, 9), StoneColor::Black),
+        None
+    );
+    assert_eq!(reader.read(&board_state, (0, 0), StoneColor::Black), None);
+}
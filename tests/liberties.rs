@@ -0,0 +1,110 @@
+// 气数和叫吃警示叠加层测试：只注册网格和材质资源，不需要渲染器
+// Liberty and atari warning overlay tests: only mesh and material assets are registered, no renderer needed
+
+mod common;
+
+use bevy::prelude::*;
+use black_white_legends::go_board_component::components::LibertyMarker;
+use black_white_legends::go_board_component::utils::CoordinateUtils;
+use black_white_legends::prelude::*;
+
+fn headless_app() -> (App, Entity) {
+    let mut app = common::render_app();
+    let board = common::spawn_board(
+        &mut app,
+        GoBoardConfig {
+            board_size: BoardSize::Nine,
+            show_liberties: true,
+            show_atari_warnings: true,
+            ..default()
+        },
+    );
+    (app, board)
+}
+
+/// 黑白交替落子，再多跑一帧让叠加层跟上
+fn play(app: &mut App, board: Entity, moves: &[(i32, i32)]) {
+    common::play(app, board, moves);
+    app.update();
+}
+
+/// 气数圆牌上的气数（排序后）和叫吃警示圈的位置
+fn markers(app: &mut App, board: Entity) -> (Vec<usize>, Vec<(i32, i32)>) {
+    let config = app
+        .world()
+        .get::<CurrentGoBoardConfig>(board)
+        .unwrap()
+        .0
+        .clone();
+    let mut query = app
+        .world_mut()
+        .query::<(&LibertyMarker, &Transform, &ChildOf)>();
+    let mut badges = Vec::new();
+    let mut rings = Vec::new();
+    for (marker, transform, parent) in query.iter(app.world()) {
+        if parent.parent() != board {
+            continue;
+        }
+        if marker.atari {
+            assert_eq!(marker.liberties, 1);
+            let local = transform.translation.truncate();
+            rings.push(
+                CoordinateUtils::local_to_board(local, config.board_size, config.board_extent)
+                    .unwrap(),
+            );
+        } else {
+            badges.push(marker.liberties);
+        }
+    }
+    badges.sort();
+    rings.sort();
+    (badges, rings)
+}
+
+#[test]
+fn one_liberty_groups_get_the_atari_ring() {
+    let (mut app, board) = headless_app();
+
+    // 黑 (4,4) 被三面包围，只剩 (4,5) 一口气
+    play(
+        &mut app,
+        board,
+        &[(4, 4), (3, 4), (8, 8), (5, 4), (8, 7), (4, 3)],
+    );
+    let (badges, rings) = markers(&mut app, board);
+    assert_eq!(rings, vec![(4, 4)]);
+    assert_eq!(badges, vec![1, 3, 3, 3, 3]);
+
+    // 黑棋长出后有三口气，警示圈消失
+    play(&mut app, board, &[(4, 5)]);
+    let (badges, rings) = markers(&mut app, board);
+    assert!(rings.is_empty());
+    assert_eq!(badges, vec![3, 3, 3, 3, 3]);
+}
+
+#[test]
+fn liberty_counts_follow_captures() {
+    let (mut app, board) = headless_app();
+
+    play(
+        &mut app,
+        board,
+        &[(4, 4), (3, 4), (8, 8), (5, 4), (8, 7), (4, 3), (0, 0)],
+    );
+    let (badges, rings) = markers(&mut app, board);
+    assert_eq!(rings, vec![(4, 4)]);
+    assert_eq!(badges, vec![1, 2, 3, 3, 3, 3]);
+
+    // 白棋提掉 (4,4)，周围四颗白子各多出一口气
+    play(&mut app, board, &[(4, 5)]);
+    assert_eq!(
+        app.world()
+            .get::<BoardState>(board)
+            .unwrap()
+            .get_stone(4, 4),
+        None
+    );
+    let (badges, rings) = markers(&mut app, board);
+    assert!(rings.is_empty());
+    assert_eq!(badges, vec![2, 3, 4, 4, 4, 4]);
+}
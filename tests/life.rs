@@ -0,0 +1,115 @@
+// 棋块死活估计测试，只依赖 go_core
+// Group status estimation tests, go_core only
+
+use black_white_legends::go_core::{
+    BoardSize, BoardState, GoBoardRules, GroupLife, GroupStatus, StoneColor,
+};
+use std::collections::HashSet;
+
+fn board_with(black: &[(i32, i32)], white: &[(i32, i32)]) -> BoardState {
+    let mut board_state = BoardState::new(BoardSize::Nine);
+    for &(x, y) in black {
+        board_state.place_stone(x, y, StoneColor::Black);
+    }
+    for &(x, y) in white {
+        board_state.place_stone(x, y, StoneColor::White);
+    }
+    board_state
+}
+
+fn group_at(groups: &[GroupLife], point: (i32, i32)) -> &GroupLife {
+    groups
+        .iter()
+        .find(|group| group.stones.contains(&point))
+        .unwrap()
+}
+
+/// 黑棋第 4 列、白棋第 5 列各一道墙，白棋 (1,1) 落在黑地里
+fn divided_board() -> BoardState {
+    let black: Vec<(i32, i32)> = (0..9).map(|y| (4, y)).collect();
+    let mut white: Vec<(i32, i32)> = (0..9).map(|y| (5, y)).collect();
+    white.push((1, 1));
+    board_with(&black, &white)
+}
+
+#[test]
+fn two_vital_eyes_are_unconditionally_alive() {
+    // 黑棋在角上围出 (0,0) 和 (2,0) 两只眼
+    let board_state = board_with(&[(1, 0), (0, 1), (1, 1), (2, 1), (3, 1), (3, 0)], &[(6, 6)]);
+
+    let alive = GoBoardRules::unconditionally_alive(&board_state);
+    assert!(alive.contains(&(1, 0)) && alive.contains(&(3, 0)));
+    assert!(!alive.contains(&(6, 6)));
+
+    let groups = GoBoardRules::group_status(&board_state);
+    let black = group_at(&groups, (1, 0));
+    assert_eq!(black.status, GroupStatus::UnconditionallyAlive);
+    assert_eq!((black.eyes, black.eye_space), (2, 2));
+    assert_eq!(group_at(&groups, (6, 6)).status, GroupStatus::Weak);
+}
+
+#[test]
+fn stones_inside_territory_are_suggested_dead() {
+    let board_state = divided_board();
+
+    assert_eq!(
+        GoBoardRules::suggest_dead_stones(&board_state),
+        HashSet::from([(1, 1)])
+    );
+    let groups = GoBoardRules::group_status(&board_state);
+    assert_eq!(group_at(&groups, (1, 1)).status, GroupStatus::Dead);
+    // 提掉死子后黑棋围住的地算作眼位
+    let black = group_at(&groups, (4, 0));
+    assert!(black.is_alive());
+    assert_eq!(black.eye_space, 36);
+    assert!(group_at(&groups, (5, 0)).is_alive());
+}
+
+#[test]
+fn dead_stones_count_for_the_surrounding_side() {
+    let board_state = divided_board();
+    let dead = GoBoardRules::suggest_dead_stones(&board_state);
+
+    assert_eq!(GoBoardRules::area_score(&board_state, 0.0), (9.0, 37.0));
+    assert_eq!(
+        GoBoardRules::area_score_with_dead(&board_state, 0.0, &dead),
+        (45.0, 36.0)
+    );
+}
+
+#[test]
+fn seki_and_open_groups_are_not_dead() {
+    // 上边的有眼双活
+    let board_state = board_with(
+        &[
+            (3, 0),
+            (3, 1),
+            (4, 1),
+            (5, 1),
+            (5, 0),
+            (0, 2),
+            (1, 2),
+            (2, 2),
+        ],
+        &[
+            (1, 0),
+            (0, 1),
+            (1, 1),
+            (2, 1),
+            (3, 2),
+            (4, 2),
+            (5, 2),
+            (6, 1),
+            (6, 0),
+        ],
+    );
+    let groups = GoBoardRules::group_status(&board_state);
+    assert_eq!(group_at(&groups, (3, 0)).status, GroupStatus::Alive);
+    assert_eq!(group_at(&groups, (1, 0)).status, GroupStatus::Alive);
+    assert!(GoBoardRules::suggest_dead_stones(&board_state).is_empty());
+
+    let board_state = board_with(&[(2, 2)], &[(6, 6)]);
+    let groups = GoBoardRules::group_status(&board_state);
+    assert_eq!(groups.len(), 2);
+    assert!(groups.iter().all(|group| group.status == GroupStatus::Weak));
+}
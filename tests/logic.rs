@@ -0,0 +1,358 @@
+// 无窗口、无渲染器的对局逻辑测试
+// Headless game logic tests: MinimalPlugins + GoBoardLogicPlugin only
+
+mod common;
+
+use bevy::prelude::*;
+use black_white_legends::go_board_component::components::GameOver;
+use black_white_legends::go_board_component::events::{
+    ClearBoardEvent, ConfirmScoreEvent, GameEndEvent, RedoMoveEvent, ToggleDeadStonesEvent,
+    UndoMoveEvent, UpdateBoardConfigEvent,
+};
+use black_white_legends::go_board_component::resources::GameHistory;
+use black_white_legends::go_board_component::rules::IllegalMove;
+use black_white_legends::go_board_component::systems::check_move;
+use black_white_legends::prelude::*;
+use common::{pass, place, state, turn};
+use std::collections::HashSet;
+
+fn headless_app() -> App {
+    common::headless_app(())
+}
+
+fn spawn_board(app: &mut App, board_size: BoardSize) -> Entity {
+    common::spawn_board(
+        app,
+        GoBoardConfig {
+            board_size,
+            ..default()
+        },
+    )
+}
+
+#[test]
+fn placing_a_stone_records_history_and_switches_turn() {
+    let mut app = headless_app();
+    let board = spawn_board(&mut app, BoardSize::Nineteen);
+
+    place(&mut app, board, (3, 3), StoneColor::Black);
+
+    assert_eq!(state(&app, board).get_stone(3, 3), Some(StoneColor::Black));
+    assert_eq!(state(&app, board).move_count, 1);
+    assert_eq!(turn(&app, board), StoneColor::White);
+    assert_eq!(
+        app.world().get::<GameHistory>(board).unwrap().moves.len(),
+        1
+    );
+}
+
+#[test]
+fn occupied_point_is_rejected() {
+    let mut app = headless_app();
+    let board = spawn_board(&mut app, BoardSize::Nineteen);
+
+    place(&mut app, board, (3, 3), StoneColor::Black);
+    place(&mut app, board, (3, 3), StoneColor::White);
+
+    assert_eq!(state(&app, board).get_stone(3, 3), Some(StoneColor::Black));
+    assert_eq!(turn(&app, board), StoneColor::White);
+}
+
+#[test]
+fn surrounded_stone_is_captured() {
+    let mut app = headless_app();
+    let board = spawn_board(&mut app, BoardSize::Nine);
+
+    place(&mut app, board, (0, 1), StoneColor::Black);
+    place(&mut app, board, (0, 0), StoneColor::White);
+    place(&mut app, board, (1, 0), StoneColor::Black);
+
+    assert_eq!(state(&app, board).get_stone(0, 0), None);
+    assert_eq!(state(&app, board).captured_white, 1);
+}
+
+#[test]
+fn legality_follows_the_rule_config() {
+    let mut board_state = BoardState::new(BoardSize::Nine);
+    board_state.place_stone(1, 0, StoneColor::Black);
+    board_state.place_stone(0, 1, StoneColor::Black);
+    board_state.ko_position = Some((4, 4));
+    let history = GameHistory::default();
+    let config = GoBoardConfig::default();
+
+    assert_eq!(
+        check_move(&config, &board_state, &history, 0, 0, StoneColor::White),
+        Err(IllegalMove::Suicide)
+    );
+    assert_eq!(
+        check_move(&config, &board_state, &history, 4, 4, StoneColor::White),
+        Err(IllegalMove::Ko)
+    );
+    assert_eq!(
+        check_move(&config, &board_state, &history, 1, 0, StoneColor::White),
+        Err(IllegalMove::Occupied)
+    );
+
+    // 关闭对应规则后悬停和落子都不再拦这两种点
+    let relaxed = GoBoardConfig {
+        enable_captures: false,
+        enable_ko_rule: false,
+        ..default()
+    };
+    assert_eq!(
+        check_move(&relaxed, &board_state, &history, 0, 0, StoneColor::White),
+        Ok(())
+    );
+    assert_eq!(
+        check_move(&relaxed, &board_state, &history, 4, 4, StoneColor::White),
+        Ok(())
+    );
+}
+
+fn select(app: &mut App, board: Entity, position: (i32, i32)) {
+    app.world_mut()
+        .send_event(SelectPointEvent { board, position });
+    app.update();
+}
+
+fn provisional(app: &App, board: Entity) -> Option<ProvisionalStone> {
+    app.world().get::<ProvisionalStone>(board).copied()
+}
+
+#[test]
+fn confirm_moves_needs_a_second_click_on_the_same_point() {
+    let mut app = headless_app();
+    let board = spawn_board(&mut app, BoardSize::Nine);
+
+    // 默认直接落子
+    select(&mut app, board, (2, 2));
+    assert_eq!(state(&app, board).get_stone(2, 2), Some(StoneColor::Black));
+    assert_eq!(provisional(&app, board), None);
+
+    let config = GoBoardConfig {
+        board_size: BoardSize::Nine,
+        confirm_moves: true,
+        ..default()
+    };
+    app.world_mut()
+        .send_event(UpdateBoardConfigEvent { board, config });
+    app.update();
+
+    select(&mut app, board, (4, 4));
+    assert_eq!(state(&app, board).get_stone(4, 4), None);
+    assert_eq!(
+        provisional(&app, board),
+        Some(ProvisionalStone {
+            position: (4, 4),
+            color: StoneColor::White,
+        })
+    );
+
+    // 点别处只移动待确认的棋子
+    select(&mut app, board, (5, 5));
+    assert_eq!(state(&app, board).get_stone(4, 4), None);
+    assert_eq!(provisional(&app, board).unwrap().position, (5, 5));
+
+    select(&mut app, board, (5, 5));
+    assert_eq!(state(&app, board).get_stone(5, 5), Some(StoneColor::White));
+    assert_eq!(turn(&app, board), StoneColor::Black);
+    assert_eq!(provisional(&app, board), None);
+
+    // 对方落子后过时的待确认棋子被移除
+    select(&mut app, board, (6, 6));
+    place(&mut app, board, (0, 0), StoneColor::Black);
+    assert_eq!(provisional(&app, board), None);
+}
+
+#[test]
+fn undo_and_redo_replay_the_game() {
+    let mut app = headless_app();
+    let board = spawn_board(&mut app, BoardSize::Nine);
+
+    place(&mut app, board, (0, 1), StoneColor::Black);
+    place(&mut app, board, (0, 0), StoneColor::White);
+    place(&mut app, board, (1, 0), StoneColor::Black);
+
+    app.world_mut().send_event(UndoMoveEvent { board });
+    app.update();
+    assert_eq!(state(&app, board).get_stone(0, 0), Some(StoneColor::White));
+    assert_eq!(state(&app, board).get_stone(1, 0), None);
+    assert_eq!(turn(&app, board), StoneColor::Black);
+
+    app.world_mut().send_event(RedoMoveEvent { board });
+    app.update();
+    assert_eq!(state(&app, board).get_stone(0, 0), None);
+    assert_eq!(state(&app, board).get_stone(1, 0), Some(StoneColor::Black));
+    assert_eq!(turn(&app, board), StoneColor::White);
+}
+
+#[test]
+fn clear_and_resize_reset_the_game() {
+    let mut app = headless_app();
+    let board = spawn_board(&mut app, BoardSize::Nineteen);
+
+    place(&mut app, board, (3, 3), StoneColor::Black);
+    app.world_mut().send_event(ClearBoardEvent { board });
+    app.update();
+    assert_eq!(state(&app, board).move_count, 0);
+    assert_eq!(turn(&app, board), StoneColor::Black);
+
+    place(&mut app, board, (3, 3), StoneColor::Black);
+    app.world_mut().send_event(UpdateBoardConfigEvent {
+        board,
+        config: GoBoardConfig {
+            board_size: BoardSize::Thirteen,
+            ..default()
+        },
+    });
+    app.update();
+    assert_eq!(state(&app, board).board_size, BoardSize::Thirteen);
+    assert_eq!(state(&app, board).get_stone(3, 3), None);
+}
+
+#[test]
+fn boards_are_independent() {
+    let mut app = headless_app();
+    let main_board = spawn_board(&mut app, BoardSize::Nineteen);
+    let analysis_board = spawn_board(&mut app, BoardSize::Nine);
+
+    place(&mut app, main_board, (3, 3), StoneColor::Black);
+    place(&mut app, analysis_board, (4, 4), StoneColor::Black);
+    place(&mut app, analysis_board, (2, 2), StoneColor::White);
+
+    assert_eq!(state(&app, main_board).move_count, 1);
+    assert_eq!(state(&app, main_board).get_stone(4, 4), None);
+    assert_eq!(turn(&app, main_board), StoneColor::White);
+
+    assert_eq!(state(&app, analysis_board).move_count, 2);
+    assert_eq!(state(&app, analysis_board).get_stone(3, 3), None);
+    assert_eq!(turn(&app, analysis_board), StoneColor::Black);
+}
+
+fn dead_stones(app: &App, board: Entity) -> HashSet<(i32, i32)> {
+    app.world()
+        .get::<ScoringPhase>(board)
+        .unwrap()
+        .dead_stones
+        .clone()
+}
+
+#[test]
+fn scoring_phase_suggests_dead_stones_before_counting() {
+    let mut app = headless_app();
+    let config = GoBoardConfig {
+        board_size: BoardSize::Nine,
+        komi: 0.5,
+        scoring_phase: true,
+        ..default()
+    };
+    let board = app.world_mut().spawn(GoBoardBundle::new(config)).id();
+    app.update();
+
+    // 黑棋第 4 列、白棋第 5 列各一道墙，白棋 (1,1) 落在黑地里
+    {
+        let mut board_state = app.world_mut().get_mut::<BoardState>(board).unwrap();
+        for y in 0..9 {
+            board_state.place_stone(4, y, StoneColor::Black);
+            board_state.place_stone(5, y, StoneColor::White);
+        }
+        board_state.place_stone(1, 1, StoneColor::White);
+    }
+    pass(&mut app, board, StoneColor::Black);
+    pass(&mut app, board, StoneColor::White);
+
+    assert!(app.world().get::<GameOver>(board).is_none());
+    assert_eq!(dead_stones(&app, board), HashSet::from([(1, 1)]));
+
+    // 数子阶段不能落子，点一块棋切换整块棋的死活
+    place(&mut app, board, (7, 7), StoneColor::Black);
+    assert_eq!(state(&app, board).get_stone(7, 7), None);
+    app.world_mut().send_event(ToggleDeadStonesEvent {
+        board,
+        position: (5, 3),
+    });
+    app.update();
+    assert_eq!(dead_stones(&app, board).len(), 10);
+    app.world_mut().send_event(ToggleDeadStonesEvent {
+        board,
+        position: (5, 0),
+    });
+    app.update();
+    assert_eq!(dead_stones(&app, board), HashSet::from([(1, 1)]));
+
+    app.world_mut().send_event(ConfirmScoreEvent { board });
+    app.update();
+    assert!(app.world().get::<GameOver>(board).is_some());
+    let events = app.world().resource::<Events<GameEndEvent>>();
+    let result = events.iter_current_update_events().next().unwrap();
+    assert_eq!((result.black_score, result.white_score), (45.0, 36.5));
+    assert_eq!(result.winner, Some(StoneColor::Black));
+
+    // 清空棋盘后重新开始
+    app.world_mut().send_event(ClearBoardEvent { board });
+    app.update();
+    assert!(app.world().get::<ScoringPhase>(board).is_none());
+    assert!(app.world().get::<GameOver>(board).is_none());
+}
+
+fn undo(app: &mut App, board: Entity) {
+    app.world_mut().send_event(UndoMoveEvent { board });
+    app.update();
+}
+
+fn history_len(app: &App, board: Entity) -> (usize, usize) {
+    let history = app.world().get::<GameHistory>(board).unwrap();
+    (history.current_index, history.moves.len())
+}
+
+#[test]
+fn undo_steps_back_over_passes() {
+    let mut app = headless_app();
+    let board = spawn_board(&mut app, BoardSize::Nine);
+
+    place(&mut app, board, (2, 2), StoneColor::Black);
+    pass(&mut app, board, StoneColor::White);
+    assert_eq!(history_len(&app, board), (2, 2));
+    assert_eq!(state(&app, board).consecutive_passes, 1);
+
+    // 撤销只退回虚手，前一手棋还在
+    undo(&mut app, board);
+    assert_eq!(state(&app, board).get_stone(2, 2), Some(StoneColor::Black));
+    assert_eq!(state(&app, board).consecutive_passes, 0);
+    assert_eq!(turn(&app, board), StoneColor::White);
+    assert_eq!(history_len(&app, board), (1, 2));
+
+    // 撤销后再虚手不会因为旧的虚手提前结束
+    pass(&mut app, board, StoneColor::White);
+    assert_eq!(history_len(&app, board), (2, 2));
+    assert!(app.world().get::<GameOver>(board).is_none());
+    pass(&mut app, board, StoneColor::Black);
+    assert!(app.world().get::<GameOver>(board).is_some());
+
+    // 撤销第二次虚手回到对局中，连续虚手数也恢复
+    undo(&mut app, board);
+    assert!(app.world().get::<GameOver>(board).is_none());
+    assert_eq!(state(&app, board).consecutive_passes, 1);
+    assert_eq!(turn(&app, board), StoneColor::Black);
+
+    // 重做第二次虚手再次结束对局
+    app.world_mut().send_event(RedoMoveEvent { board });
+    app.update();
+    assert!(app.world().get::<GameOver>(board).is_some());
+    assert_eq!(state(&app, board).consecutive_passes, 2);
+}
+
+#[test]
+fn out_of_turn_stones_are_ignored() {
+    let mut app = headless_app();
+    let board = spawn_board(&mut app, BoardSize::Nine);
+
+    place(&mut app, board, (2, 2), StoneColor::White);
+    assert_eq!(state(&app, board).get_stone(2, 2), None);
+    assert_eq!(history_len(&app, board), (0, 0));
+
+    place(&mut app, board, (2, 2), StoneColor::Black);
+    place(&mut app, board, (3, 3), StoneColor::Black);
+    assert_eq!(state(&app, board).get_stone(3, 3), None);
+    assert_eq!(turn(&app, board), StoneColor::White);
+}
@@ -0,0 +1,181 @@
+// 内置 MCTS 棋手测试，只依赖 go_core
+// Built-in MCTS bot tests, go_core only
+
+use black_white_legends::go_core::{
+    BoardSize, BoardState, FeaturePlanes, GameHistory, GeneratedMove, GoBoardRules, MctsBot,
+    MctsConfig, MoveGenerator, PolicyError, PolicyEvaluator, PolicyOutput, RandomMoveGenerator,
+    SearchBudget, StoneColor,
+};
+use std::sync::{Arc, Mutex};
+
+fn bot(playouts: u32) -> MctsBot {
+    MctsBot::new(MctsConfig {
+        budget: SearchBudget::Playouts(playouts),
+        seed: Some(7),
+        ..Default::default()
+    })
+}
+
+/// 记下收到的特征平面，输出 `outputs` 个均匀分布的概率
+struct RecordingPolicy {
+    seen: Arc<Mutex<Vec<FeaturePlanes>>>,
+    outputs: usize,
+}
+
+impl PolicyEvaluator for RecordingPolicy {
+    fn evaluate(&mut self, planes: &FeaturePlanes) -> Result<PolicyOutput, PolicyError> {
+        self.seen.lock().unwrap().push(planes.clone());
+        Ok(PolicyOutput::from_logits(
+            planes.board_size,
+            &vec![0.0; self.outputs],
+            0.0,
+        ))
+    }
+}
+
+fn board_with(stones: &[((i32, i32), StoneColor)]) -> BoardState {
+    let mut board_state = BoardState::new(BoardSize::Nine);
+    for &((x, y), color) in stones {
+        GoBoardRules::play_move(&mut board_state, x, y, color).unwrap();
+    }
+    board_state
+}
+
+#[test]
+fn beats_random_player() {
+    let mut mcts = bot(300);
+    let mut random = RandomMoveGenerator::new(7);
+    let mut board_state = BoardState::new(BoardSize::Nine);
+    let mut history = GameHistory::default();
+    let mut color = StoneColor::Black;
+
+    while board_state.consecutive_passes < 2 {
+        let generated = match color {
+            StoneColor::Black => random.generate_move(&board_state, &history, color, 7.5),
+            StoneColor::White => mcts.generate_move(&board_state, &history, color, 7.5),
+        };
+        match generated {
+            GeneratedMove::Play(x, y) => {
+                let captured = GoBoardRules::play_move(&mut board_state, x, y, color).unwrap();
+                history.record_after(&board_state, Some((x, y)), color, captured);
+            }
+            GeneratedMove::Pass => {
+                GoBoardRules::pass(&mut board_state);
+                history.record_after(&board_state, None, color, Vec::new());
+            }
+            GeneratedMove::Resign => panic!("{:?} resigned", color),
+        }
+        color = color.opposite();
+    }
+
+    let (black_score, white_score) = GoBoardRules::area_score(&board_state, 7.5);
+    assert!(white_score > black_score);
+}
+
+#[test]
+fn same_seed_gives_same_move() {
+    let board_state = board_with(&[((2, 2), StoneColor::Black)]);
+
+    let first = bot(500).generate_move(
+        &board_state,
+        &GameHistory::default(),
+        StoneColor::White,
+        7.5,
+    );
+    let second = bot(500).generate_move(
+        &board_state,
+        &GameHistory::default(),
+        StoneColor::White,
+        7.5,
+    );
+    assert_eq!(first, second);
+    assert!(matches!(first, GeneratedMove::Play(..)));
+}
+
+#[test]
+fn passes_after_opponent_pass_when_ahead() {
+    // 白棋占据右侧四路，左侧全空，按数子白方领先
+    let mut stones = Vec::new();
+    for y in 0..9 {
+        stones.push(((4, y), StoneColor::White));
+    }
+    let mut board_state = board_with(&stones);
+    GoBoardRules::pass(&mut board_state);
+
+    let generated = bot(500).generate_move(
+        &board_state,
+        &GameHistory::default(),
+        StoneColor::White,
+        7.5,
+    );
+    assert_eq!(generated, GeneratedMove::Pass);
+}
+
+#[test]
+fn resigns_hopeless_game() {
+    // 贴目超过棋盘点数，黑方不可能获胜
+    let board_state = board_with(&[((4, 4), StoneColor::White)]);
+
+    let generated = bot(1000).generate_move(
+        &board_state,
+        &GameHistory::default(),
+        StoneColor::Black,
+        100.0,
+    );
+    assert_eq!(generated, GeneratedMove::Resign);
+}
+
+#[test]
+fn policy_priors_see_the_move_history() {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let policy = RecordingPolicy {
+        seen: seen.clone(),
+        outputs: 82,
+    };
+    let mut mcts = bot(50).with_policy(Box::new(policy));
+
+    let mut board_state = BoardState::new(BoardSize::Nine);
+    let mut history = GameHistory::default();
+    for ((x, y), color) in [((2, 2), StoneColor::Black), ((6, 6), StoneColor::White)] {
+        let captured = GoBoardRules::play_move(&mut board_state, x, y, color).unwrap();
+        history.record_after(&board_state, Some((x, y)), color, captured);
+    }
+    mcts.generate_move(&board_state, &history, StoneColor::Black, 7.5);
+
+    // 一手之前的局面里白子还没下
+    let planes = seen.lock().unwrap().pop().unwrap();
+    assert_eq!(planes.get(1, 6, 6), 1.0);
+    assert_eq!(planes.get(2, 2, 2), 1.0);
+    assert_eq!(planes.get(3, 6, 6), 0.0);
+
+    // 历史与局面对不上时只编码当前局面
+    mcts.generate_move(
+        &board_state,
+        &GameHistory::default(),
+        StoneColor::Black,
+        7.5,
+    );
+    let planes = seen.lock().unwrap().pop().unwrap();
+    assert_eq!(planes.get(1, 6, 6), 1.0);
+    assert_eq!(planes.get(2, 2, 2), 0.0);
+}
+
+#[test]
+fn malformed_policy_output_falls_back_to_plain_search() {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let policy = RecordingPolicy {
+        seen: seen.clone(),
+        outputs: 81,
+    };
+    let mut mcts = bot(200).with_policy(Box::new(policy));
+
+    let board_state = board_with(&[((2, 2), StoneColor::Black)]);
+    let generated = mcts.generate_move(
+        &board_state,
+        &GameHistory::default(),
+        StoneColor::White,
+        7.5,
+    );
+    assert_eq!(seen.lock().unwrap().len(), 1);
+    assert!(matches!(generated, GeneratedMove::Play(..)));
+}
@@ -0,0 +1,260 @@
+// 交叉点拾取测试：直接发送 bevy_picking 的指针事件，不需要窗口、相机和拾取后端
+// Intersection picking tests: feed bevy_picking pointer events directly, no window, camera or backend
+
+mod common;
+
+use bevy::picking::pointer::PointerId;
+use bevy::prelude::*;
+use black_white_legends::go_board_component::picking::{BoardPointers, pick_board_intersections};
+use black_white_legends::prelude::*;
+use common::{drain, hit, send_pointer};
+
+fn headless_app() -> (App, Entity, Entity) {
+    let mut app = common::headless_app(());
+    common::add_pointer_events(&mut app).add_systems(Update, pick_board_intersections);
+
+    let board = common::spawn_board(
+        &mut app,
+        GoBoardConfig {
+            board_size: BoardSize::Nine,
+            ..default()
+        },
+    );
+    // 命中棋子等子实体时沿父实体找到棋盘
+    let stone = app.world_mut().spawn(ChildOf(board)).id();
+    app.update();
+    (app, board, stone)
+}
+
+#[test]
+fn hover_and_click_carry_the_board_coordinate() {
+    let (mut app, board, stone) = headless_app();
+
+    let over = hit(&app, board, (2, 3));
+    send_pointer(&mut app, stone, Over { hit: over });
+    app.update();
+
+    let hovers = drain::<IntersectionHoverEvent>(&mut app);
+    assert_eq!(hovers.len(), 1);
+    assert_eq!((hovers[0].board, hovers[0].position), (board, Some((2, 3))));
+    assert_eq!(
+        app.world()
+            .resource::<BoardPointers>()
+            .hovered(PointerId::Mouse),
+        Some((board, (2, 3)))
+    );
+
+    let click = hit(&app, board, (2, 3));
+    send_pointer(
+        &mut app,
+        stone,
+        Click {
+            button: PointerButton::Primary,
+            hit: click,
+            duration: Default::default(),
+        },
+    );
+    app.update();
+
+    let clicks = drain::<IntersectionClickEvent>(&mut app);
+    assert_eq!(clicks.len(), 1);
+    assert_eq!((clicks[0].board, clicks[0].position), (board, (2, 3)));
+
+    let out = hit(&app, board, (2, 3));
+    send_pointer(&mut app, stone, Out { hit: out });
+    app.update();
+
+    let hovers = drain::<IntersectionHoverEvent>(&mut app);
+    assert_eq!(hovers.len(), 1);
+    assert_eq!(hovers[0].position, None);
+}
+
+#[test]
+fn dragging_across_intersections_is_not_a_click() {
+    let (mut app, board, stone) = headless_app();
+
+    let start = hit(&app, board, (1, 1));
+    send_pointer(
+        &mut app,
+        stone,
+        DragStart {
+            button: PointerButton::Primary,
+            hit: start,
+        },
+    );
+    app.update();
+
+    let moved = hit(&app, board, (4, 6));
+    send_pointer(
+        &mut app,
+        stone,
+        Move {
+            hit: moved,
+            delta: Vec2::ONE,
+        },
+    );
+    app.update();
+
+    let release = hit(&app, board, (4, 6));
+    send_pointer(
+        &mut app,
+        stone,
+        Click {
+            button: PointerButton::Primary,
+            hit: release,
+            duration: Default::default(),
+        },
+    );
+    send_pointer(
+        &mut app,
+        stone,
+        DragEnd {
+            button: PointerButton::Primary,
+            distance: Vec2::ONE,
+        },
+    );
+    app.update();
+
+    assert!(drain::<IntersectionClickEvent>(&mut app).is_empty());
+    let drags: Vec<_> = drain::<IntersectionDragEvent>(&mut app)
+        .into_iter()
+        .map(|drag| (drag.start, drag.position, drag.finished))
+        .collect();
+    assert_eq!(
+        drags,
+        vec![
+            ((1, 1), (1, 1), false),
+            ((1, 1), (4, 6), false),
+            ((1, 1), (4, 6), true),
+        ]
+    );
+}
+
+#[test]
+fn releasing_off_the_board_ends_the_drag_at_the_last_intersection() {
+    let (mut app, board, stone) = headless_app();
+
+    let start = hit(&app, board, (2, 2));
+    send_pointer(
+        &mut app,
+        stone,
+        DragStart {
+            button: PointerButton::Primary,
+            hit: start,
+        },
+    );
+    let moved = hit(&app, board, (3, 2));
+    send_pointer(
+        &mut app,
+        stone,
+        Move {
+            hit: moved,
+            delta: Vec2::ONE,
+        },
+    );
+    app.update();
+
+    // 拖出棋盘后在外面松开，点击落在网格外不换算成交叉点
+    let left = hit(&app, board, (3, 2));
+    send_pointer(&mut app, stone, Out { hit: left });
+    app.update();
+    let outside = hit(&app, board, (20, 20));
+    send_pointer(
+        &mut app,
+        stone,
+        Click {
+            button: PointerButton::Primary,
+            hit: outside,
+            duration: Default::default(),
+        },
+    );
+    send_pointer(
+        &mut app,
+        stone,
+        DragEnd {
+            button: PointerButton::Primary,
+            distance: Vec2::ONE,
+        },
+    );
+    app.update();
+
+    assert!(drain::<IntersectionClickEvent>(&mut app).is_empty());
+    let finished: Vec<_> = drain::<IntersectionDragEvent>(&mut app)
+        .into_iter()
+        .filter(|drag| drag.finished)
+        .map(|drag| (drag.start, drag.position, drag.cancelled))
+        .collect();
+    assert_eq!(finished, vec![((2, 2), (3, 2), false)]);
+    assert_eq!(
+        app.world()
+            .resource::<BoardPointers>()
+            .hovered(PointerId::Mouse),
+        None
+    );
+}
+
+#[test]
+fn cancelled_pointers_end_their_drags() {
+    let (mut app, board, stone) = headless_app();
+
+    let start = hit(&app, board, (5, 5));
+    send_pointer(&mut app, stone, Over { hit: start.clone() });
+    send_pointer(
+        &mut app,
+        stone,
+        DragStart {
+            button: PointerButton::Primary,
+            hit: start,
+        },
+    );
+    app.update();
+    let moved = hit(&app, board, (6, 6));
+    send_pointer(
+        &mut app,
+        stone,
+        Move {
+            hit: moved,
+            delta: Vec2::ONE,
+        },
+    );
+    app.update();
+    drain::<IntersectionHoverEvent>(&mut app);
+
+    // 取消后不会再有 Out 和 DragEnd，拖动和悬停都要清除
+    let cancel = hit(&app, board, (6, 6));
+    send_pointer(&mut app, stone, Cancel { hit: cancel });
+    app.update();
+
+    let drags: Vec<_> = drain::<IntersectionDragEvent>(&mut app)
+        .into_iter()
+        .map(|drag| (drag.position, drag.finished, drag.cancelled))
+        .collect();
+    assert_eq!(
+        drags,
+        vec![
+            ((5, 5), false, false),
+            ((6, 6), false, false),
+            ((6, 6), true, true),
+        ]
+    );
+    let hovers = drain::<IntersectionHoverEvent>(&mut app);
+    assert_eq!(hovers.len(), 1);
+    assert_eq!(hovers[0].position, None);
+
+    // 之后在拖到的点上点击不再被当成拖动的结尾
+    let click = hit(&app, board, (6, 6));
+    send_pointer(
+        &mut app,
+        stone,
+        Click {
+            button: PointerButton::Primary,
+            hit: click,
+            duration: Default::default(),
+        },
+    );
+    app.update();
+    let clicks = drain::<IntersectionClickEvent>(&mut app);
+    assert_eq!(clicks.len(), 1);
+    assert_eq!(clicks[0].position, (6, 6));
+    assert!(drain::<IntersectionDragEvent>(&mut app).is_empty());
+}
@@ -0,0 +1,99 @@
+// 策略网络输入输出测试，只依赖 go_core
+// Policy network input/output tests, go_core only
+
+use black_white_legends::go_core::{
+    BoardSize, BoardState, FEATURE_PLANES, FeaturePlanes, GoBoardRules, Move, PolicyError,
+    PolicyOutput, StoneColor,
+};
+
+fn moves(played: &[((i32, i32), StoneColor)]) -> Vec<Move> {
+    played
+        .iter()
+        .enumerate()
+        .map(|(index, &(position, color))| Move {
+            position: Some(position),
+            color,
+            captured_stones: Vec::new(),
+            move_number: index + 1,
+            position_hash: 0,
+        })
+        .collect()
+}
+
+#[test]
+fn planes_are_relative_to_the_player_to_move() {
+    let history = moves(&[((2, 2), StoneColor::Black), ((6, 6), StoneColor::White)]);
+    let planes = FeaturePlanes::from_moves(BoardSize::Nine, &history, StoneColor::Black);
+
+    assert_eq!(planes.shape(), [1, FEATURE_PLANES, 9, 9]);
+    assert_eq!(planes.get(0, 2, 2), 1.0);
+    assert_eq!(planes.get(1, 6, 6), 1.0);
+    assert_eq!(planes.get(FEATURE_PLANES - 1, 0, 0), 1.0);
+
+    let planes = FeaturePlanes::from_moves(BoardSize::Nine, &history, StoneColor::White);
+    assert_eq!(planes.get(0, 6, 6), 1.0);
+    assert_eq!(planes.get(1, 2, 2), 1.0);
+    assert_eq!(planes.get(FEATURE_PLANES - 1, 0, 0), 0.0);
+}
+
+#[test]
+fn history_planes_hold_earlier_positions() {
+    let history = moves(&[((2, 2), StoneColor::Black), ((6, 6), StoneColor::White)]);
+    let planes = FeaturePlanes::from_moves(BoardSize::Nine, &history, StoneColor::Black);
+
+    // 一手之前白子还没下
+    assert_eq!(planes.get(2, 2, 2), 1.0);
+    assert_eq!(planes.get(3, 6, 6), 0.0);
+    // 两手之前是空棋盘
+    assert_eq!(planes.get(4, 2, 2), 0.0);
+
+    let mut board_state = BoardState::new(BoardSize::Nine);
+    GoBoardRules::play_move(&mut board_state, 2, 2, StoneColor::Black).unwrap();
+    let planes = FeaturePlanes::from_board(&board_state, StoneColor::White);
+    assert_eq!(planes.get(1, 2, 2), 1.0);
+    assert_eq!(planes.get(3, 2, 2), 0.0);
+}
+
+#[test]
+fn output_masks_occupied_points() {
+    let mut logits = vec![0.0; 82];
+    logits[2 * 9 + 2] = 5.0;
+    logits[4 * 9 + 4] = 3.0;
+    let mut output = PolicyOutput::from_logits(BoardSize::Nine, &logits, 0.5);
+
+    assert!((output.winrate - 0.75).abs() < 1e-6);
+    assert_eq!(output.top_moves(1)[0].0, (2, 2));
+
+    let mut board_state = BoardState::new(BoardSize::Nine);
+    GoBoardRules::play_move(&mut board_state, 2, 2, StoneColor::White).unwrap();
+    output
+        .mask_illegal(&board_state, StoneColor::Black, None)
+        .unwrap();
+
+    assert_eq!(output.probability(2, 2), 0.0);
+    assert_eq!(output.top_moves(1)[0].0, (4, 4));
+    let total: f32 = output.probabilities.iter().sum();
+    assert!((total - 1.0).abs() < 1e-4);
+}
+
+#[test]
+fn masking_checks_the_output_shape() {
+    let board_state = BoardState::new(BoardSize::Nine);
+
+    // 缺少虚手一项
+    let mut output = PolicyOutput::from_logits(BoardSize::Nine, &[0.0; 81], 0.0);
+    let before = output.clone();
+    assert!(matches!(
+        output.mask_illegal(&board_state, StoneColor::Black, None),
+        Err(PolicyError::Shape(_))
+    ));
+    assert_eq!(output, before);
+
+    // 网络的棋盘大小与局面不符
+    let mut output = PolicyOutput::from_logits(BoardSize::Thirteen, &[0.0; 170], 0.0);
+    assert!(
+        output
+            .mask_illegal(&board_state, StoneColor::Black, None)
+            .is_err()
+    );
+}
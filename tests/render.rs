@@ -0,0 +1,209 @@
+// 无窗口的渲染缓存测试：只注册网格和材质资源，不需要渲染器
+// Headless render cache tests: only mesh and material assets are registered, no renderer needed
+
+mod common;
+
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use black_white_legends::go_board_component::assets::GoBoardAssets;
+use black_white_legends::go_board_component::components::CoordinateLabel;
+use black_white_legends::go_board_component::events::{RedrawBoardEvent, UpdateBoardConfigEvent};
+use black_white_legends::go_board_component::utils::RenderUtils;
+use black_white_legends::go_core::BoardCoordinates;
+use black_white_legends::prelude::*;
+
+fn headless_app() -> (App, Entity) {
+    let mut app = common::render_app();
+    let board = common::spawn_board(
+        &mut app,
+        GoBoardConfig {
+            board_size: BoardSize::Nineteen,
+            ..default()
+        },
+    );
+    common::place(&mut app, board, (3, 3), StoneColor::Black);
+    common::place(&mut app, board, (15, 15), StoneColor::White);
+    settle(&mut app);
+    (app, board)
+}
+
+/// 多跑几帧，让被丢弃的句柄从 `Assets` 中释放
+fn settle(app: &mut App) {
+    for _ in 0..3 {
+        app.update();
+    }
+}
+
+/// 缓存中的网格和材质数量，以及 `Assets` 中的网格和材质数量
+fn asset_counts(app: &App) -> (usize, usize, usize, usize) {
+    let world = app.world();
+    let cache = world.resource::<GoBoardAssets>();
+    (
+        cache.mesh_count(),
+        cache.material_count(),
+        world.resource::<Assets<Mesh>>().len(),
+        world.resource::<Assets<ColorMaterial>>().len(),
+    )
+}
+
+fn update_config(app: &mut App, board: Entity, change: impl FnOnce(&mut GoBoardConfig)) {
+    let mut config = app
+        .world()
+        .get::<CurrentGoBoardConfig>(board)
+        .unwrap()
+        .0
+        .clone();
+    change(&mut config);
+    app.world_mut()
+        .get_mut::<CurrentGoBoardConfig>(board)
+        .unwrap()
+        .0 = config;
+    settle(app);
+}
+
+#[test]
+fn asset_counts_stay_flat_across_redraws() {
+    let (mut app, board) = headless_app();
+    let baseline = asset_counts(&app);
+    assert!(baseline.0 > 0 && baseline.1 > 0);
+    assert_eq!(baseline.0, baseline.2);
+    assert_eq!(baseline.1, baseline.3);
+
+    for _ in 0..10 {
+        app.world_mut().send_event(RedrawBoardEvent { board });
+        app.update();
+    }
+    settle(&mut app);
+    assert_eq!(asset_counts(&app), baseline);
+}
+
+#[test]
+fn size_and_style_changes_release_old_assets() {
+    let (mut app, board) = headless_app();
+    let baseline = asset_counts(&app);
+
+    for _ in 0..3 {
+        update_config(&mut app, board, |config| {
+            config.board_extent = 900.0;
+            config.board_color = Color::srgb(0.6, 0.2, 0.2);
+            config.line_color = Color::srgb(0.1, 0.1, 0.6);
+        });
+        let styled = asset_counts(&app);
+        assert_eq!(styled.0, styled.2);
+        assert_eq!(styled.1, styled.3);
+
+        update_config(&mut app, board, |config| {
+            config.board_extent = GoBoardConfig::default().board_extent;
+            config.board_color = GoBoardConfig::default().board_color;
+            config.line_color = GoBoardConfig::default().line_color;
+        });
+        assert_eq!(asset_counts(&app), baseline);
+    }
+
+    // 换成 9 路棋盘再换回来，盘面清空，只剩棋盘本身的资源
+    app.world_mut().send_event(UpdateBoardConfigEvent {
+        board,
+        config: GoBoardConfig {
+            board_size: BoardSize::Nine,
+            ..default()
+        },
+    });
+    settle(&mut app);
+    app.world_mut().send_event(UpdateBoardConfigEvent {
+        board,
+        config: GoBoardConfig::default(),
+    });
+    settle(&mut app);
+    let empty = asset_counts(&app);
+    for _ in 0..3 {
+        for board_size in [BoardSize::Nine, BoardSize::Nineteen] {
+            app.world_mut().send_event(UpdateBoardConfigEvent {
+                board,
+                config: GoBoardConfig {
+                    board_size,
+                    ..default()
+                },
+            });
+            settle(&mut app);
+        }
+        assert_eq!(asset_counts(&app), empty);
+    }
+}
+
+#[test]
+fn fit_to_window_scales_boards_to_the_primary_window() {
+    let mut app = common::render_app();
+    let window = app
+        .world_mut()
+        .spawn((
+            Window {
+                resolution: (1600.0, 1500.0).into(),
+                ..default()
+            },
+            PrimaryWindow,
+        ))
+        .id();
+    let fitted = app
+        .world_mut()
+        .spawn(GoBoardBundle::new(GoBoardConfig {
+            fit_to_window: true,
+            ..default()
+        }))
+        .id();
+    let fixed = app
+        .world_mut()
+        .spawn(GoBoardBundle::new(GoBoardConfig {
+            fit_to_window: true,
+            adaptive_padding: false,
+            ..default()
+        }))
+        .id();
+    let manual = app
+        .world_mut()
+        .spawn(GoBoardBundle::new(GoBoardConfig::default()))
+        .id();
+    app.update();
+
+    let scale = |app: &App, board| app.world().get::<Transform>(board).unwrap().scale.x;
+    let extent = GoBoardConfig::default().board_extent;
+    assert_eq!(scale(&app, fitted), (1500.0 - 50.0) / extent);
+    assert_eq!(scale(&app, fixed), (1500.0 - 100.0) / extent);
+    assert_eq!(scale(&app, manual), 1.0);
+
+    app.world_mut()
+        .get_mut::<Window>(window)
+        .unwrap()
+        .resolution
+        .set(800.0, 900.0);
+    app.update();
+    assert_eq!(scale(&app, fitted), (800.0 - 100.0) / extent);
+}
+
+#[test]
+fn coordinate_labels_match_human_coordinates() {
+    let (mut app, board) = headless_app();
+    let config = app
+        .world()
+        .get::<CurrentGoBoardConfig>(board)
+        .unwrap()
+        .0
+        .clone();
+    let metrics = RenderUtils::calculate_board_metrics(config.board_extent, config.board_size);
+
+    // 左侧的行号按所在行换算成棋盘坐标，应与人类可读坐标的行号相同
+    let mut rows = 0;
+    let mut labels = app
+        .world_mut()
+        .query_filtered::<(&Text2d, &Transform), With<CoordinateLabel>>();
+    for (text, transform) in labels.iter(app.world()) {
+        let position = transform.translation;
+        if position.x >= -metrics.half_board {
+            continue;
+        }
+        let y = ((metrics.half_board - position.y) / metrics.cell_size).round() as i32;
+        let human = BoardCoordinates::board_to_human(0, y, config.board_size);
+        assert_eq!(text.0, human[1..], "row label at y = {}", y);
+        rows += 1;
+    }
+    assert_eq!(rows, 19);
+}
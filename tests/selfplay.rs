@@ -0,0 +1,74 @@
+// 自对弈、SGF 与训练样本导出测试，只依赖 go_core
+// Self-play, SGF and training sample export tests, go_core only
+
+use black_white_legends::go_core::{
+    BoardSize, BotLevel, GameResult, HeuristicBot, SelfPlayConfig, SelfPlayGame, SgfRecord,
+    StoneColor, TrainingSamples,
+};
+
+fn play(seed: u64) -> SelfPlayGame {
+    let mut black = HeuristicBot::new(BotLevel::AtariAware, seed);
+    let mut white = HeuristicBot::new(BotLevel::GreedyCapture, seed + 1);
+    SelfPlayGame::play(&mut black, &mut white, &SelfPlayConfig::default())
+}
+
+#[test]
+fn sgf_lists_moves_and_result() {
+    let mut record = SgfRecord::new(BoardSize::Nine, 7.5);
+    record.black_player = "shape".to_string();
+    record.white_player = "a]b".to_string();
+    record.result = Some("W+R".to_string());
+    record.push(StoneColor::Black, Some((2, 6)));
+    record.push(StoneColor::White, None);
+
+    let sgf = record.to_sgf();
+    assert!(sgf.starts_with("(;GM[1]FF[4]"));
+    assert!(sgf.contains("SZ[9]KM[7.5]"));
+    assert!(sgf.contains(r"PW[a\]b]"));
+    assert!(sgf.contains("RE[W+R]"));
+    assert!(sgf.trim_end().ends_with(";B[cg];W[])"));
+}
+
+#[test]
+fn result_uses_sgf_notation() {
+    let scored = GameResult {
+        winner: Some(StoneColor::Black),
+        margin: Some(3.5),
+    };
+    let resigned = GameResult {
+        winner: Some(StoneColor::White),
+        margin: None,
+    };
+    assert_eq!(scored.to_sgf(), "B+3.5");
+    assert_eq!(resigned.to_sgf(), "W+R");
+    assert_eq!(scored.value_for(StoneColor::White), -1);
+}
+
+#[test]
+fn same_seed_replays_the_same_game() {
+    let game = play(11);
+    assert_eq!(game, play(11));
+    assert!(game.result.winner.is_some());
+    assert!(game.moves.len() <= SelfPlayConfig::default().max_moves);
+}
+
+#[test]
+fn every_move_becomes_a_sample() {
+    let game = play(3);
+    let mut samples = TrainingSamples::new(BoardSize::Nine);
+    game.export_samples(&mut samples);
+    assert_eq!(samples.len(), game.moves.len());
+
+    let (color, played) = game.moves[1];
+    let (policy, value) = samples.target(1);
+    let expected = played.map_or(81, |(x, y)| y * 9 + x);
+    assert_eq!(policy as i32, expected);
+    assert_eq!(value, game.result.value_for(color));
+
+    let mut npz = Vec::new();
+    samples.write_npz(&mut npz).unwrap();
+    assert!(npz.starts_with(b"PK\x03\x04"));
+    let end = &npz[npz.len() - 22..];
+    assert!(end.starts_with(b"PK\x05\x06"));
+    assert_eq!(u16::from_le_bytes([end[10], end[11]]), 3);
+}
@@ -0,0 +1,144 @@
+// 对杀与双活分析测试，只依赖 go_core
+// Semeai and seki analysis tests, go_core only
+
+use black_white_legends::go_core::{BoardSize, BoardState, GoBoardRules, RaceOutcome, StoneColor};
+use std::collections::HashSet;
+
+fn board_with(black: &[(i32, i32)], white: &[(i32, i32)]) -> BoardState {
+    let mut board_state = BoardState::new(BoardSize::Nine);
+    for &(x, y) in black {
+        board_state.place_stone(x, y, StoneColor::Black);
+    }
+    for &(x, y) in white {
+        board_state.place_stone(x, y, StoneColor::White);
+    }
+    board_state
+}
+
+/// 上边的有眼双活：白棋 (0,0)、黑棋 (4,0) 各一只眼，(2,0) 是公气
+fn eye_against_eye() -> BoardState {
+    board_with(
+        &[
+            (3, 0),
+            (3, 1),
+            (4, 1),
+            (5, 1),
+            (5, 0),
+            (0, 2),
+            (1, 2),
+            (2, 2),
+        ],
+        &[
+            (1, 0),
+            (0, 1),
+            (1, 1),
+            (2, 1),
+            (3, 2),
+            (4, 2),
+            (5, 2),
+            (6, 1),
+            (6, 0),
+        ],
+    )
+}
+
+/// 左上角两块没有眼的棋对杀，黑棋 (2,0)-(2,2)、白棋 (1,0)-(1,2) 各两口外气
+fn race_without_eyes(extra_black_liberty: bool) -> BoardState {
+    let mut white = vec![(1, 0), (1, 1), (1, 2), (3, 2), (3, 3)];
+    if !extra_black_liberty {
+        white.push((3, 1));
+    }
+    board_with(&[(2, 0), (2, 1), (2, 2), (0, 1), (0, 2), (0, 3)], &white)
+}
+
+#[test]
+fn eye_against_eye_is_seki() {
+    let board_state = eye_against_eye();
+    let race = GoBoardRules::analyze_semeai(&board_state, (3, 0), (1, 0)).unwrap();
+
+    assert_eq!(race.shared, 1);
+    assert_eq!(
+        (race.black.outside, race.black.eye, race.black.eyes),
+        (0, 1, 1)
+    );
+    assert_eq!(
+        (race.white.outside, race.white.eye, race.white.eyes),
+        (0, 1, 1)
+    );
+    assert!(race.is_seki());
+    assert_eq!(race.winner(), None);
+    assert!(!race.needs_first_move(StoneColor::Black));
+
+    // 公气不算地，双方的眼照常算
+    assert_eq!(
+        GoBoardRules::seki_liberties(&board_state),
+        HashSet::from([(2, 0)])
+    );
+    let regions = GoBoardRules::empty_regions(&board_state);
+    let shared = regions
+        .iter()
+        .find(|region| region.points.contains(&(2, 0)))
+        .unwrap();
+    assert_eq!(shared.owner, None);
+    let eye = regions
+        .iter()
+        .find(|region| region.points.contains(&(4, 0)))
+        .unwrap();
+    assert_eq!(eye.owner, Some(StoneColor::Black));
+
+    // 公气与双方都相邻，数子时不需要另外排除：黑 8 子 1 眼，白 9 子 1 眼
+    assert_eq!(GoBoardRules::area_score(&board_state, 0.0), (9.0, 10.0));
+}
+
+#[test]
+fn race_without_eyes_goes_to_the_first_mover() {
+    let board_state = race_without_eyes(false);
+    let race = GoBoardRules::analyze_semeai(&board_state, (2, 0), (1, 0)).unwrap();
+
+    assert_eq!(
+        (race.black.outside, race.white.outside, race.shared),
+        (2, 2, 0)
+    );
+    assert_eq!(race.black_first, RaceOutcome::Wins(StoneColor::Black));
+    assert_eq!(race.white_first, RaceOutcome::Wins(StoneColor::White));
+    assert!(race.needs_first_move(StoneColor::Black));
+    assert!(race.needs_first_move(StoneColor::White));
+    assert!(GoBoardRules::seki_liberties(&board_state).is_empty());
+
+    // 多一口气的一方后走也能赢
+    let board_state = race_without_eyes(true);
+    let race = GoBoardRules::analyze_semeai(&board_state, (2, 0), (1, 0)).unwrap();
+    assert_eq!(race.black.outside, 3);
+    assert_eq!(race.winner(), Some(StoneColor::Black));
+    assert!(!race.needs_first_move(StoneColor::Black));
+}
+
+#[test]
+fn eye_beats_no_eye() {
+    // 白棋 (1,0)-(1,1)-(0,1) 有 (0,0) 一只眼和 (0,2) 一口外气，黑棋 (2,0)-(2,1) 只有 (2,2) 一口外气
+    let board_state = board_with(
+        &[(2, 0), (2, 1), (1, 2)],
+        &[(1, 0), (1, 1), (0, 1), (3, 0), (3, 1)],
+    );
+    let race = GoBoardRules::analyze_semeai(&board_state, (2, 0), (1, 0)).unwrap();
+    assert_eq!(race.white.eyes, 1);
+    assert_eq!(race.black.eyes, 0);
+    assert_eq!(race.winner(), Some(StoneColor::White));
+}
+
+#[test]
+fn unrelated_groups_are_not_a_race() {
+    let board_state = board_with(&[(0, 0), (1, 0)], &[(5, 5)]);
+    assert_eq!(
+        GoBoardRules::analyze_semeai(&board_state, (0, 0), (5, 5)),
+        None
+    );
+    assert_eq!(
+        GoBoardRules::analyze_semeai(&board_state, (0, 0), (1, 0)),
+        None
+    );
+    assert_eq!(
+        GoBoardRules::analyze_semeai(&board_state, (0, 0), (4, 4)),
+        None
+    );
+}
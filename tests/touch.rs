@@ -0,0 +1,201 @@
+// 触控测试：直接发送世界坐标的触摸事件，不需要窗口和相机
+// Touch tests: feed world-space touch events directly, no window or camera needed
+
+mod common;
+
+use bevy::input::InputPlugin;
+use bevy::input::touch::TouchPhase;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use black_white_legends::go_board_component::TouchPlayPlugin;
+use black_white_legends::prelude::*;
+use common::{stone, wait};
+use std::time::Duration;
+
+fn headless_app() -> (App, Entity) {
+    let mut app = common::headless_app((InputPlugin, TransformPlugin, TouchPlayPlugin));
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    let board = common::spawn_board(
+        &mut app,
+        GoBoardConfig {
+            board_size: BoardSize::Nine,
+            touch_controls: true,
+            ..default()
+        },
+    );
+    (app, board)
+}
+
+/// 交叉点在当前视图下的世界坐标
+fn world(app: &App, board: Entity, position: (i32, i32)) -> Vec2 {
+    common::world_point(app, board, position).truncate()
+}
+
+fn touch(app: &mut App, id: u64, phase: TouchPhase, position: Vec2) {
+    app.world_mut().send_event(BoardTouchEvent {
+        id,
+        phase,
+        position,
+    });
+    app.update();
+}
+
+fn preview(app: &App, board: Entity) -> Option<(i32, i32)> {
+    app.world()
+        .get::<TouchPreview>(board)
+        .map(|preview| preview.position)
+}
+
+fn view(app: &App, board: Entity) -> BoardView {
+    *app.world().get::<BoardView>(board).unwrap()
+}
+
+#[test]
+fn tap_previews_and_places_where_the_finger_is_lifted() {
+    let (mut app, board) = headless_app();
+
+    let start = world(&app, board, (2, 2));
+    touch(&mut app, 0, TouchPhase::Started, start);
+    assert_eq!(preview(&app, board), Some((2, 2)));
+
+    // 按住时移动手指调整落点
+    let moved = world(&app, board, (3, 2));
+    touch(&mut app, 0, TouchPhase::Moved, moved);
+    assert_eq!(preview(&app, board), Some((3, 2)));
+
+    // 松开后等双击时间过去才落子
+    touch(&mut app, 0, TouchPhase::Ended, moved);
+    assert_eq!(stone(&app, board, (3, 2)), None);
+    wait(&mut app, 4);
+    assert_eq!(stone(&app, board, (3, 2)), Some(StoneColor::Black));
+    assert_eq!(stone(&app, board, (2, 2)), None);
+    assert_eq!(preview(&app, board), None);
+}
+
+#[test]
+fn pinch_zooms_and_pans_and_taps_still_hit_the_right_point() {
+    let (mut app, board) = headless_app();
+    let home = *app.world().get::<Transform>(board).unwrap();
+
+    let (left, right) = (Vec2::new(-100.0, 0.0), Vec2::new(100.0, 0.0));
+    touch(&mut app, 0, TouchPhase::Started, left);
+    touch(&mut app, 1, TouchPhase::Started, right);
+    assert_eq!(preview(&app, board), None);
+
+    // 两指张开一倍并一起右移
+    touch(&mut app, 1, TouchPhase::Moved, Vec2::new(300.0, 0.0));
+    assert!((view(&app, board).zoom - 2.0).abs() < 1e-4);
+    touch(&mut app, 0, TouchPhase::Moved, Vec2::new(-50.0, 0.0));
+    touch(&mut app, 1, TouchPhase::Moved, Vec2::new(350.0, 0.0));
+    let zoomed = view(&app, board);
+    assert!(zoomed.pan.x > 0.0);
+    assert_eq!(
+        *app.world().get::<Transform>(board).unwrap(),
+        zoomed.transform()
+    );
+
+    // 放大倍数有上限
+    touch(&mut app, 1, TouchPhase::Moved, Vec2::new(5000.0, 0.0));
+    assert!((view(&app, board).zoom - 4.0).abs() < 1e-4);
+    touch(&mut app, 0, TouchPhase::Ended, Vec2::new(-50.0, 0.0));
+    touch(&mut app, 1, TouchPhase::Ended, Vec2::new(5000.0, 0.0));
+    wait(&mut app, 4);
+    assert_eq!(stone(&app, board, (4, 4)), None);
+
+    // 放大后按当前变换换算交叉点
+    let point = world(&app, board, (5, 4));
+    touch(&mut app, 2, TouchPhase::Started, point);
+    touch(&mut app, 2, TouchPhase::Ended, point);
+    wait(&mut app, 4);
+    assert_eq!(stone(&app, board, (5, 4)), Some(StoneColor::Black));
+
+    // 双击恢复视图，不落子
+    let point = world(&app, board, (6, 6));
+    for id in [3, 4] {
+        touch(&mut app, id, TouchPhase::Started, point);
+        touch(&mut app, id, TouchPhase::Ended, point);
+    }
+    wait(&mut app, 4);
+    assert_eq!(view(&app, board), BoardView::new(home));
+    assert_eq!(*app.world().get::<Transform>(board).unwrap(), home);
+    assert_eq!(stone(&app, board, (6, 6)), None);
+}
+
+#[test]
+fn pinch_clamps_zoom_and_pan() {
+    let (mut app, board) = headless_app();
+    let home = view(&app, board);
+
+    // 捏合不会缩到原大小以下，视图停在原位
+    touch(&mut app, 0, TouchPhase::Started, Vec2::new(-100.0, 0.0));
+    touch(&mut app, 1, TouchPhase::Started, Vec2::new(100.0, 0.0));
+    touch(&mut app, 1, TouchPhase::Moved, Vec2::new(20.0, 0.0));
+    assert_eq!(view(&app, board), home);
+    touch(&mut app, 0, TouchPhase::Ended, Vec2::new(-100.0, 0.0));
+    touch(&mut app, 1, TouchPhase::Ended, Vec2::new(20.0, 0.0));
+
+    // 放大一倍后两指交替向右跨过对方，间距不变，只平移
+    touch(&mut app, 2, TouchPhase::Started, Vec2::new(-100.0, 0.0));
+    touch(&mut app, 3, TouchPhase::Started, Vec2::new(100.0, 0.0));
+    touch(&mut app, 3, TouchPhase::Moved, Vec2::new(300.0, 0.0));
+    assert!((view(&app, board).zoom - 2.0).abs() < 1e-4);
+    touch(&mut app, 2, TouchPhase::Moved, Vec2::new(700.0, 0.0));
+    touch(&mut app, 3, TouchPhase::Moved, Vec2::new(1100.0, 0.0));
+    touch(&mut app, 2, TouchPhase::Moved, Vec2::new(1500.0, 0.0));
+
+    // 平移停在放大后的棋盘仍然盖住原来位置的边界
+    let slack = GoBoardConfig::default().board_extent / 2.0;
+    let panned = view(&app, board);
+    assert!((panned.zoom - 2.0).abs() < 1e-4);
+    assert!((panned.pan.x - slack).abs() < 1e-3, "{:?}", panned.pan);
+    assert_eq!(panned.pan.y, 0.0);
+    assert_eq!(
+        *app.world().get::<Transform>(board).unwrap(),
+        panned.transform()
+    );
+    assert_eq!(stone(&app, board, (4, 4)), None);
+}
+
+#[test]
+fn double_tap_needs_two_quick_taps_next_to_each_other() {
+    let (mut app, board) = headless_app();
+
+    // 第二次轻点离得远，第一次立即落子，不算双击
+    for position in [(2, 2), (6, 6)] {
+        let point = world(&app, board, position);
+        touch(&mut app, 0, TouchPhase::Started, point);
+        touch(&mut app, 0, TouchPhase::Ended, point);
+    }
+    assert_eq!(stone(&app, board, (2, 2)), Some(StoneColor::Black));
+    assert_eq!(stone(&app, board, (6, 6)), None);
+    wait(&mut app, 4);
+    assert_eq!(stone(&app, board, (6, 6)), Some(StoneColor::White));
+
+    // 双击时间过后在旁边轻点，各自落子
+    let point = world(&app, board, (4, 4));
+    touch(&mut app, 0, TouchPhase::Started, point);
+    touch(&mut app, 0, TouchPhase::Ended, point);
+    wait(&mut app, 4);
+    assert_eq!(stone(&app, board, (4, 4)), Some(StoneColor::Black));
+    let point = world(&app, board, (4, 5));
+    touch(&mut app, 0, TouchPhase::Started, point);
+    touch(&mut app, 0, TouchPhase::Ended, point);
+    wait(&mut app, 4);
+    assert_eq!(stone(&app, board, (4, 5)), Some(StoneColor::White));
+
+    // 双击时间内在相邻的交叉点再点一次是双击，两次都不落子
+    let first = world(&app, board, (0, 0));
+    touch(&mut app, 0, TouchPhase::Started, first);
+    touch(&mut app, 0, TouchPhase::Ended, first);
+    wait(&mut app, 1);
+    let second = world(&app, board, (1, 1));
+    touch(&mut app, 0, TouchPhase::Started, second);
+    touch(&mut app, 0, TouchPhase::Ended, second);
+    wait(&mut app, 4);
+    assert_eq!(stone(&app, board, (0, 0)), None);
+    assert_eq!(stone(&app, board, (1, 1)), None);
+    assert_eq!(common::move_count(&app, board), 4);
+    assert_eq!(preview(&app, board), None);
+}
@@ -0,0 +1,122 @@
+// 比赛测试：对阵编排、交叉表与 Elo，以及无窗口运行的小型循环赛
+// Tournament tests: pairings, crosstable and Elo, plus a small headless round robin
+
+mod common;
+
+use bevy::prelude::*;
+use black_white_legends::go_board_component::{EndReason, Entrant, TournamentConfig};
+use black_white_legends::go_core::{BotLevel, Crosstable, TournamentFormat};
+use black_white_legends::prelude::*;
+use common::update_until;
+
+#[test]
+fn pairings_alternate_colours() {
+    let round_robin = TournamentFormat::RoundRobin.pairings(3, 2);
+    assert_eq!(round_robin.len(), 6);
+    assert!(round_robin.contains(&(0, 2)) && round_robin.contains(&(2, 0)));
+
+    let gauntlet = TournamentFormat::Gauntlet.pairings(3, 2);
+    assert_eq!(gauntlet, vec![(0, 1), (0, 2), (1, 0), (2, 0)]);
+}
+
+#[test]
+fn elo_follows_results() {
+    let mut crosstable = Crosstable::new(vec!["a".into(), "b".into(), "c".into()]);
+    for _ in 0..4 {
+        crosstable.record(0, 1, Some(StoneColor::Black));
+        crosstable.record(1, 2, Some(StoneColor::Black));
+        crosstable.record(2, 0, Some(StoneColor::White));
+    }
+    crosstable.record(1, 0, None);
+
+    let elo = crosstable.elo();
+    assert!(elo[0] > elo[1] && elo[1] > elo[2]);
+    assert!(elo.iter().sum::<f32>().abs() < 1e-3);
+    assert_eq!(crosstable.head_to_head(0, 1), (4.5, 5));
+    assert!(
+        crosstable
+            .to_table()
+            .lines()
+            .nth(1)
+            .unwrap()
+            .contains(" a ")
+    );
+}
+
+#[test]
+fn round_robin_plays_every_game() {
+    let entrants = BotLevel::ALL[1..]
+        .iter()
+        .map(|&level| Entrant {
+            name: level.name().to_string(),
+            backend: PlayerBackend::Heuristic { level, seed: 1 },
+        })
+        .collect();
+    let config = TournamentConfig {
+        games_per_pairing: 2,
+        move_time: None,
+        concurrent_games: 3,
+        ..default()
+    };
+
+    let mut app = common::headless_app((ComputerPlayerPlugin, TournamentPlugin));
+    app.insert_resource(Tournament::new(entrants, config));
+
+    update_until(&mut app, |app| {
+        app.world().resource::<Tournament>().is_finished()
+    });
+
+    let tournament = app.world().resource::<Tournament>();
+    assert_eq!(tournament.finished.len(), 6);
+    for game in &tournament.finished {
+        assert_ne!(game.reason, EndReason::Time);
+        let sgf = game.record.to_sgf();
+        assert!(sgf.contains("RE["));
+        assert!(game.record.moves.len() > 10);
+    }
+    let games: u32 = (0..3)
+        .flat_map(|a| (0..3).map(move |b| (a, b)))
+        .map(|(a, b)| tournament.crosstable.head_to_head(a, b).1)
+        .sum();
+    assert_eq!(games, 12);
+
+    // 所有棋盘和棋手都已移除
+    let mut boards = app.world_mut().query::<&GoBoardRoot>();
+    assert_eq!(boards.iter(app.world()).count(), 0);
+}
+
+#[test]
+fn move_limit_ends_games_with_two_passes() {
+    let entrants = BotLevel::ALL[1..3]
+        .iter()
+        .map(|&level| Entrant {
+            name: level.name().to_string(),
+            backend: PlayerBackend::Heuristic { level, seed: 3 },
+        })
+        .collect();
+    let config = TournamentConfig {
+        games_per_pairing: 1,
+        move_time: None,
+        max_moves: 6,
+        ..default()
+    };
+
+    let mut app = common::headless_app((ComputerPlayerPlugin, TournamentPlugin));
+    app.insert_resource(Tournament::new(entrants, config));
+
+    update_until(&mut app, |app| {
+        app.world().resource::<Tournament>().is_finished()
+    });
+
+    // 达到手数后只补两手虚手，棋谱中的虚手来自对局历史
+    let tournament = app.world().resource::<Tournament>();
+    assert_eq!(tournament.finished.len(), 1);
+    let game = &tournament.finished[0];
+    assert_eq!(game.reason, EndReason::Score);
+    let moves = &game.record.moves;
+    assert_eq!(moves.len(), 8, "{:?}", moves);
+    assert!(moves[..6].iter().all(|(_, position)| position.is_some()));
+    assert_eq!(moves[6].1, None);
+    assert_eq!(moves[7].1, None);
+    assert_ne!(moves[6].0, moves[7].0);
+}
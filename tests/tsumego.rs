@@ -0,0 +1,117 @@
+// 死活题求解测试，只依赖 go_core
+// Tsumego solver tests, go_core only
+
+use black_white_legends::go_core::{
+    BoardSize, BoardState, GeneratedMove, LifeStatus, StoneColor, TsumegoError, TsumegoGoal,
+    TsumegoProblem, TsumegoSolver,
+};
+
+fn board_with(black: &[(i32, i32)], white: &[(i32, i32)]) -> BoardState {
+    let mut board_state = BoardState::new(BoardSize::Nine);
+    for &(x, y) in black {
+        board_state.place_stone(x, y, StoneColor::Black);
+    }
+    for &(x, y) in white {
+        board_state.place_stone(x, y, StoneColor::White);
+    }
+    board_state
+}
+
+/// 上边的直三：白棋眼位 (0,0)-(2,0)，外面被黑棋围住
+fn straight_three(to_play: StoneColor, goal: TsumegoGoal) -> TsumegoProblem {
+    TsumegoProblem {
+        board_state: board_with(
+            &[(0, 2), (1, 2), (2, 2), (3, 2), (4, 2), (4, 1), (4, 0)],
+            &[(0, 1), (1, 1), (2, 1), (3, 1), (3, 0)],
+        ),
+        region: vec![(0, 0), (1, 0), (2, 0)],
+        to_play,
+        target: (0, 1),
+        goal,
+    }
+}
+
+#[test]
+fn vital_point_kills_straight_three() {
+    let problem = straight_three(StoneColor::Black, TsumegoGoal::Kill);
+    let solution = TsumegoSolver::new().solve(&problem).unwrap();
+
+    assert_eq!(solution.status, LifeStatus::Dead);
+    assert!(solution.solved);
+    assert_eq!(solution.variations.len(), 1);
+    assert_eq!(solution.variations[0][0], GeneratedMove::Play(1, 0));
+}
+
+#[test]
+fn vital_point_lives_straight_three() {
+    let problem = straight_three(StoneColor::White, TsumegoGoal::Live);
+    let solution = TsumegoSolver::new().solve(&problem).unwrap();
+
+    assert_eq!(solution.status, LifeStatus::Alive);
+    assert!(solution.solved);
+    assert_eq!(solution.variations, vec![vec![GeneratedMove::Play(1, 0)]]);
+}
+
+#[test]
+fn eye_against_eye_is_seki() {
+    // 白棋 (0,0) 一只眼，黑棋 (4,0) 一只眼，(2,0) 是公气，外围各自有气
+    let problem = TsumegoProblem {
+        board_state: board_with(
+            &[
+                (3, 0),
+                (3, 1),
+                (4, 1),
+                (5, 1),
+                (5, 0),
+                (0, 2),
+                (1, 2),
+                (2, 2),
+            ],
+            &[
+                (1, 0),
+                (0, 1),
+                (1, 1),
+                (2, 1),
+                (3, 2),
+                (4, 2),
+                (5, 2),
+                (6, 1),
+                (6, 0),
+            ],
+        ),
+        region: vec![(0, 0), (2, 0), (4, 0)],
+        to_play: StoneColor::Black,
+        target: (1, 0),
+        goal: TsumegoGoal::Kill,
+    };
+    let solution = TsumegoSolver::new().solve(&problem).unwrap();
+
+    assert_eq!(solution.status, LifeStatus::Seki);
+    assert!(!solution.solved);
+}
+
+#[test]
+fn capture_and_setup_errors() {
+    let board_state = board_with(&[(3, 4), (5, 4), (4, 3)], &[(4, 4)]);
+    let mut problem = TsumegoProblem {
+        board_state,
+        region: vec![(4, 5), (3, 5), (5, 5)],
+        to_play: StoneColor::Black,
+        target: (4, 4),
+        goal: TsumegoGoal::Capture,
+    };
+    let solution = TsumegoSolver::new().solve(&problem).unwrap();
+    assert_eq!(solution.status, LifeStatus::Dead);
+    assert_eq!(solution.variations[0], vec![GeneratedMove::Play(4, 5)]);
+
+    problem.goal = TsumegoGoal::Live;
+    assert_eq!(
+        TsumegoSolver::new().solve(&problem).unwrap_err(),
+        TsumegoError::WrongSide
+    );
+    problem.target = (0, 0);
+    assert_eq!(
+        TsumegoSolver::new().solve(&problem).unwrap_err(),
+        TsumegoError::NoTarget
+    );
+}